@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod variant;
+pub use variant::*;
+
+use crate::{Identifier, IntegerType, Member, Mode, Node, NodeBuilder, NodeID, Struct, Type};
+use leo_span::{sym, Span, Symbol};
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// An enum type definition, e.g., `enum Phase { Open, Closed, Settled(u64) }`.
+///
+/// Each variant optionally carries a payload of a single type.
+/// Like structs, enums are nominal types.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Enum {
+    /// The name of the type in the type system in this module.
+    pub identifier: Identifier,
+    /// The variants of the enum, in declaration order.
+    pub variants: Vec<EnumVariant>,
+    /// The entire span of the enum definition.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl PartialEq for Enum {
+    fn eq(&self, other: &Self) -> bool {
+        self.identifier == other.identifier
+    }
+}
+
+impl Eq for Enum {}
+
+impl Enum {
+    /// Returns the enum name as a Symbol.
+    pub fn name(&self) -> Symbol {
+        self.identifier.name
+    }
+
+    /// Returns the variant with the given name, along with its tag, i.e. its index in the declaration.
+    pub fn variant(&self, name: Symbol) -> Option<(usize, &EnumVariant)> {
+        self.variants.iter().enumerate().find(|(_, variant)| variant.name() == name)
+    }
+
+    /// Returns the struct that represents the enum in Aleo instructions.
+    /// The struct has a `tag: u8` member holding the tag of the variant,
+    /// followed by a member named after each variant that carries a payload.
+    /// The payloads of the other variants hold default values.
+    pub fn to_struct(&self, node_builder: &NodeBuilder) -> Struct {
+        let member = |identifier: Identifier, type_: Type| Member {
+            mode: Mode::None,
+            identifier,
+            type_,
+            span: self.span,
+            id: node_builder.next_id(),
+        };
+        let tag = Identifier { name: sym::tag, span: self.span, id: node_builder.next_id() };
+        let mut members = vec![member(tag, Type::Integer(IntegerType::U8))];
+        members.extend(
+            self.variants.iter().filter_map(|variant| {
+                variant.payload.as_ref().map(|payload| member(variant.identifier, payload.clone()))
+            }),
+        );
+        Struct { identifier: self.identifier, members, is_record: false, span: self.span, id: node_builder.next_id() }
+    }
+}
+
+impl fmt::Debug for Enum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        <Self as fmt::Display>::fmt(self, f)
+    }
+}
+
+impl fmt::Display for Enum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "enum {} {{ ", self.identifier)?;
+        for variant in self.variants.iter() {
+            writeln!(f, "    {variant},")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+crate::simple_node_impl!(Enum);
@@ -20,7 +20,7 @@ use leo_span::{sym, Symbol};
 /// A binary operator.
 ///
 /// Precedence is defined in the parser.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum BinaryOperation {
     /// Addition, i.e. `+`, `.add()`.
     Add,
@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A pattern in an arm of a `match`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MatchPattern {
+    /// A literal or a constant, e.g., `0u8`, `true`, or `MAX`.
+    Constant(Expression),
+    /// Alternative patterns, any of which selects the arm, e.g., `1u8 | 2u8`.
+    Or(Vec<MatchPattern>, Span),
+    /// A variant of an enum, e.g., `Phase::Open` or `Phase::Settled(amount)`.
+    Variant(VariantPattern),
+    /// The wildcard pattern `_`, which matches any value.
+    Wildcard(Span),
+}
+
+impl MatchPattern {
+    /// Returns the span of the pattern.
+    pub fn span(&self) -> Span {
+        match self {
+            MatchPattern::Constant(expression) => expression.span(),
+            MatchPattern::Or(_, span) => *span,
+            MatchPattern::Variant(pattern) => pattern.span,
+            MatchPattern::Wildcard(span) => *span,
+        }
+    }
+}
+
+impl fmt::Display for MatchPattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MatchPattern::Constant(expression) => expression.fmt(f),
+            MatchPattern::Or(patterns, _) => {
+                write!(f, "{}", patterns.iter().map(|pattern| pattern.to_string()).collect::<Vec<_>>().join(" | "))
+            }
+            MatchPattern::Variant(pattern) => pattern.fmt(f),
+            MatchPattern::Wildcard(_) => write!(f, "_"),
+        }
+    }
+}
+
+/// A pattern matching a variant of an enum, e.g., `Phase::Settled(amount)`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VariantPattern {
+    /// The name of the enum.
+    pub enum_name: Identifier,
+    /// The name of the variant.
+    pub variant: Identifier,
+    /// The variable bound to the payload of the variant, if any.
+    pub binding: Option<Identifier>,
+    /// The span from the enum name to the closing parenthesis, if any.
+    pub span: Span,
+}
+
+impl fmt::Display for VariantPattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}::{}", self.enum_name, self.variant)?;
+        match &self.binding {
+            Some(binding) => write!(f, "({binding})"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// An arm of a `match`, e.g., `Phase::Settled(amount) => amount`.
+/// The body is an expression in a `match` expression, and a block in a `match` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchArm<T> {
+    /// The pattern that selects the arm.
+    pub pattern: MatchPattern,
+    /// The body evaluated when the pattern matches.
+    pub body: T,
+    /// The span from the pattern to the end of the body.
+    pub span: Span,
+}
+
+impl<T: fmt::Display> fmt::Display for MatchArm<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} => {}", self.pattern, self.body)
+    }
+}
+
+/// A `match` expression, e.g., `match phase { Phase::Settled(amount) => amount, _ => 0u64 }` or `match x { 0u8 => a, _ => b }`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchExpression {
+    /// The expression being matched.
+    pub expression: Box<Expression>,
+    /// The arms of the match, in order.
+    pub arms: Vec<MatchArm<Expression>>,
+    /// The span from `match` to `}`.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl fmt::Display for MatchExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "match {} {{ ", self.expression)?;
+        for arm in self.arms.iter() {
+            write!(f, "{arm}, ")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+crate::simple_node_impl!(MatchExpression);
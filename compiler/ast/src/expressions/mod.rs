@@ -41,6 +41,9 @@ pub use struct_init::*;
 mod err;
 pub use err::*;
 
+mod repeat;
+pub use repeat::*;
+
 mod ternary;
 pub use ternary::*;
 
@@ -56,6 +59,9 @@ pub use unit::*;
 mod literal;
 pub use literal::*;
 
+mod match_;
+pub use match_::*;
+
 /// Expression that evaluates to a value.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Expression {
@@ -78,6 +84,10 @@ pub enum Expression {
     Identifier(Identifier),
     /// A literal expression.
     Literal(Literal),
+    /// A `match` expression, e.g., `match phase { Phase::Open => 0u64, _ => 1u64 }`.
+    Match(MatchExpression),
+    /// An array repeat expression, e.g., `[0u8; 32]`.
+    Repeat(RepeatExpression),
     /// A ternary conditional expression `cond ? if_expr : else_expr`.
     Ternary(TernaryExpression),
     /// A tuple expression e.g., `(foo, 42, true)`.
@@ -101,6 +111,8 @@ impl Node for Expression {
             Err(n) => n.span(),
             Identifier(n) => n.span(),
             Literal(n) => n.span(),
+            Match(n) => n.span(),
+            Repeat(n) => n.span(),
             Ternary(n) => n.span(),
             Tuple(n) => n.span(),
             Unary(n) => n.span(),
@@ -119,6 +131,8 @@ impl Node for Expression {
             Struct(n) => n.set_span(span),
             Identifier(n) => n.set_span(span),
             Literal(n) => n.set_span(span),
+            Match(n) => n.set_span(span),
+            Repeat(n) => n.set_span(span),
             Err(n) => n.set_span(span),
             Ternary(n) => n.set_span(span),
             Tuple(n) => n.set_span(span),
@@ -138,6 +152,8 @@ impl Node for Expression {
             Struct(n) => n.id(),
             Identifier(n) => n.id(),
             Literal(n) => n.id(),
+            Match(n) => n.id(),
+            Repeat(n) => n.id(),
             Err(n) => n.id(),
             Ternary(n) => n.id(),
             Tuple(n) => n.id(),
@@ -157,6 +173,8 @@ impl Node for Expression {
             Struct(n) => n.set_id(id),
             Identifier(n) => n.set_id(id),
             Literal(n) => n.set_id(id),
+            Match(n) => n.set_id(id),
+            Repeat(n) => n.set_id(id),
             Err(n) => n.set_id(id),
             Ternary(n) => n.set_id(id),
             Tuple(n) => n.set_id(id),
@@ -179,6 +197,8 @@ impl fmt::Display for Expression {
             Err(n) => n.fmt(f),
             Identifier(n) => n.fmt(f),
             Literal(n) => n.fmt(f),
+            Match(n) => n.fmt(f),
+            Repeat(n) => n.fmt(f),
             Ternary(n) => n.fmt(f),
             Tuple(n) => n.fmt(f),
             Unary(n) => n.fmt(f),
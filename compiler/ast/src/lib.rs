@@ -28,6 +28,9 @@ pub use self::access::*;
 pub mod r#struct;
 pub use self::r#struct::*;
 
+pub mod r#enum;
+pub use self::r#enum::*;
+
 pub mod common;
 pub use self::common::*;
 
@@ -46,6 +49,9 @@ pub use self::input::*;
 pub mod mapping;
 pub use self::mapping::*;
 
+pub mod module;
+pub use self::module::*;
+
 pub mod passes;
 pub use self::passes::*;
 
@@ -58,6 +64,9 @@ pub use self::statement::*;
 pub mod types;
 pub use self::types::*;
 
+pub mod type_alias;
+pub use self::type_alias::*;
+
 pub mod value;
 pub use self::value::*;
 
@@ -34,6 +34,8 @@ pub trait ExpressionConsumer {
             Expression::Err(err) => self.consume_err(err),
             Expression::Identifier(identifier) => self.consume_identifier(identifier),
             Expression::Literal(value) => self.consume_literal(value),
+            Expression::Match(match_) => self.consume_match(match_),
+            Expression::Repeat(repeat) => self.consume_repeat(repeat),
             Expression::Ternary(ternary) => self.consume_ternary(ternary),
             Expression::Tuple(tuple) => self.consume_tuple(tuple),
             Expression::Unary(unary) => self.consume_unary(unary),
@@ -61,6 +63,10 @@ pub trait ExpressionConsumer {
 
     fn consume_literal(&mut self, _input: Literal) -> Self::Output;
 
+    fn consume_match(&mut self, _input: MatchExpression) -> Self::Output;
+
+    fn consume_repeat(&mut self, _input: RepeatExpression) -> Self::Output;
+
     fn consume_ternary(&mut self, _input: TernaryExpression) -> Self::Output;
 
     fn consume_tuple(&mut self, _input: TupleExpression) -> Self::Output;
@@ -76,37 +82,49 @@ pub trait StatementConsumer {
 
     fn consume_statement(&mut self, input: Statement) -> Self::Output {
         match input {
+            Statement::ArrayIteration(stmt) => self.consume_array_iteration(*stmt),
             Statement::Assert(assert) => self.consume_assert(assert),
             Statement::Assign(stmt) => self.consume_assign(*stmt),
             Statement::Block(stmt) => self.consume_block(stmt),
+            Statement::Break(stmt) => self.consume_break(stmt),
             Statement::Conditional(stmt) => self.consume_conditional(stmt),
             Statement::Console(stmt) => self.consume_console(stmt),
             Statement::Const(stmt) => self.consume_const(stmt),
+            Statement::Continue(stmt) => self.consume_continue(stmt),
             Statement::Definition(stmt) => self.consume_definition(stmt),
             Statement::Expression(stmt) => self.consume_expression_statement(stmt),
             Statement::Iteration(stmt) => self.consume_iteration(*stmt),
+            Statement::Match(stmt) => self.consume_match_statement(stmt),
             Statement::Return(stmt) => self.consume_return(stmt),
         }
     }
 
+    fn consume_array_iteration(&mut self, input: ArrayIterationStatement) -> Self::Output;
+
     fn consume_assert(&mut self, input: AssertStatement) -> Self::Output;
 
     fn consume_assign(&mut self, input: AssignStatement) -> Self::Output;
 
     fn consume_block(&mut self, input: Block) -> Self::Output;
 
+    fn consume_break(&mut self, input: BreakStatement) -> Self::Output;
+
     fn consume_conditional(&mut self, input: ConditionalStatement) -> Self::Output;
 
     fn consume_console(&mut self, input: ConsoleStatement) -> Self::Output;
 
     fn consume_const(&mut self, input: ConstDeclaration) -> Self::Output;
 
+    fn consume_continue(&mut self, input: ContinueStatement) -> Self::Output;
+
     fn consume_definition(&mut self, input: DefinitionStatement) -> Self::Output;
 
     fn consume_expression_statement(&mut self, input: ExpressionStatement) -> Self::Output;
 
     fn consume_iteration(&mut self, input: IterationStatement) -> Self::Output;
 
+    fn consume_match_statement(&mut self, input: MatchStatement) -> Self::Output;
+
     fn consume_return(&mut self, input: ReturnStatement) -> Self::Output;
 }
 
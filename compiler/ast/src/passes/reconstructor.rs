@@ -35,6 +35,8 @@ pub trait ExpressionReconstructor {
             Expression::Err(err) => self.reconstruct_err(err),
             Expression::Identifier(identifier) => self.reconstruct_identifier(identifier),
             Expression::Literal(value) => self.reconstruct_literal(value),
+            Expression::Match(match_) => self.reconstruct_match(match_),
+            Expression::Repeat(repeat) => self.reconstruct_repeat(repeat),
             Expression::Ternary(ternary) => self.reconstruct_ternary(ternary),
             Expression::Tuple(tuple) => self.reconstruct_tuple(tuple),
             Expression::Unary(unary) => self.reconstruct_unary(unary),
@@ -198,6 +200,49 @@ pub trait ExpressionReconstructor {
         (Expression::Literal(input), Default::default())
     }
 
+    fn reconstruct_match(&mut self, input: MatchExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Match(MatchExpression {
+                expression: Box::new(self.reconstruct_expression(*input.expression).0),
+                arms: input
+                    .arms
+                    .into_iter()
+                    .map(|arm| MatchArm {
+                        pattern: self.reconstruct_match_pattern(arm.pattern),
+                        body: self.reconstruct_expression(arm.body).0,
+                        span: arm.span,
+                    })
+                    .collect(),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_match_pattern(&mut self, input: MatchPattern) -> MatchPattern {
+        match input {
+            MatchPattern::Constant(expression) => MatchPattern::Constant(self.reconstruct_expression(expression).0),
+            MatchPattern::Or(patterns, span) => MatchPattern::Or(
+                patterns.into_iter().map(|pattern| self.reconstruct_match_pattern(pattern)).collect(),
+                span,
+            ),
+            pattern => pattern,
+        }
+    }
+
+    fn reconstruct_repeat(&mut self, input: RepeatExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Repeat(RepeatExpression {
+                expr: Box::new(self.reconstruct_expression(*input.expr).0),
+                count: input.count,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_ternary(&mut self, input: TernaryExpression) -> (Expression, Self::AdditionalOutput) {
         (
             Expression::Ternary(TernaryExpression {
@@ -243,22 +288,40 @@ pub trait ExpressionReconstructor {
 pub trait StatementReconstructor: ExpressionReconstructor {
     fn reconstruct_statement(&mut self, input: Statement) -> (Statement, Self::AdditionalOutput) {
         match input {
+            Statement::ArrayIteration(stmt) => self.reconstruct_array_iteration(*stmt),
             Statement::Assert(assert) => self.reconstruct_assert(assert),
             Statement::Assign(stmt) => self.reconstruct_assign(*stmt),
             Statement::Block(stmt) => {
                 let (stmt, output) = self.reconstruct_block(stmt);
                 (Statement::Block(stmt), output)
             }
+            Statement::Break(stmt) => self.reconstruct_break(stmt),
             Statement::Conditional(stmt) => self.reconstruct_conditional(stmt),
             Statement::Console(stmt) => self.reconstruct_console(stmt),
             Statement::Const(stmt) => self.reconstruct_const(stmt),
+            Statement::Continue(stmt) => self.reconstruct_continue(stmt),
             Statement::Definition(stmt) => self.reconstruct_definition(stmt),
             Statement::Expression(stmt) => self.reconstruct_expression_statement(stmt),
             Statement::Iteration(stmt) => self.reconstruct_iteration(*stmt),
+            Statement::Match(stmt) => self.reconstruct_match_statement(stmt),
             Statement::Return(stmt) => self.reconstruct_return(stmt),
         }
     }
 
+    fn reconstruct_array_iteration(&mut self, input: ArrayIterationStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::ArrayIteration(Box::new(ArrayIterationStatement {
+                variable: input.variable,
+                type_: input.type_,
+                array: self.reconstruct_expression(input.array).0,
+                block: self.reconstruct_block(input.block).0,
+                span: input.span,
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_assert(&mut self, input: AssertStatement) -> (Statement, Self::AdditionalOutput) {
         (
             Statement::Assert(AssertStatement {
@@ -303,6 +366,10 @@ pub trait StatementReconstructor: ExpressionReconstructor {
         )
     }
 
+    fn reconstruct_break(&mut self, input: BreakStatement) -> (Statement, Self::AdditionalOutput) {
+        (Statement::Break(input), Default::default())
+    }
+
     fn reconstruct_conditional(&mut self, input: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
         (
             Statement::Conditional(ConditionalStatement {
@@ -329,6 +396,10 @@ pub trait StatementReconstructor: ExpressionReconstructor {
                         self.reconstruct_expression(left).0,
                         self.reconstruct_expression(right).0,
                     ),
+                    ConsoleFunction::Log(format, args) => ConsoleFunction::Log(
+                        format,
+                        args.into_iter().map(|arg| self.reconstruct_expression(arg).0).collect(),
+                    ),
                 },
                 span: input.span,
                 id: input.id,
@@ -350,6 +421,10 @@ pub trait StatementReconstructor: ExpressionReconstructor {
         )
     }
 
+    fn reconstruct_continue(&mut self, input: ContinueStatement) -> (Statement, Self::AdditionalOutput) {
+        (Statement::Continue(input), Default::default())
+    }
+
     fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
         (
             Statement::Definition(DefinitionStatement {
@@ -393,6 +468,26 @@ pub trait StatementReconstructor: ExpressionReconstructor {
         )
     }
 
+    fn reconstruct_match_statement(&mut self, input: MatchStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Match(MatchStatement {
+                expression: self.reconstruct_expression(input.expression).0,
+                arms: input
+                    .arms
+                    .into_iter()
+                    .map(|arm| MatchArm {
+                        pattern: self.reconstruct_match_pattern(arm.pattern),
+                        body: self.reconstruct_block(arm.body).0,
+                        span: arm.span,
+                    })
+                    .collect(),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_return(&mut self, input: ReturnStatement) -> (Statement, Self::AdditionalOutput) {
         (
             Statement::Return(ReturnStatement {
@@ -428,7 +523,10 @@ pub trait ProgramReconstructor: StatementReconstructor {
     fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
         ProgramScope {
             program_id: input.program_id,
+            modules: input.modules,
+            type_aliases: input.type_aliases.into_iter().map(|(i, t)| (i, self.reconstruct_type_alias(t))).collect(),
             structs: input.structs.into_iter().map(|(i, c)| (i, self.reconstruct_struct(c))).collect(),
+            enums: input.enums.into_iter().map(|(i, e)| (i, self.reconstruct_enum(e))).collect(),
             mappings: input.mappings.into_iter().map(|(id, mapping)| (id, self.reconstruct_mapping(mapping))).collect(),
             functions: input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect(),
             consts: input
@@ -466,10 +564,18 @@ pub trait ProgramReconstructor: StatementReconstructor {
         }
     }
 
+    fn reconstruct_type_alias(&mut self, input: TypeAlias) -> TypeAlias {
+        input
+    }
+
     fn reconstruct_struct(&mut self, input: Struct) -> Struct {
         input
     }
 
+    fn reconstruct_enum(&mut self, input: Enum) -> Enum {
+        input
+    }
+
     fn reconstruct_import(&mut self, input: Program) -> Program {
         self.reconstruct_program(input)
     }
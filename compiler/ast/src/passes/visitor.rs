@@ -36,6 +36,8 @@ pub trait ExpressionVisitor<'a> {
             Expression::Err(err) => self.visit_err(err, additional),
             Expression::Identifier(identifier) => self.visit_identifier(identifier, additional),
             Expression::Literal(literal) => self.visit_literal(literal, additional),
+            Expression::Match(match_) => self.visit_match(match_, additional),
+            Expression::Repeat(repeat) => self.visit_repeat(repeat, additional),
             Expression::Ternary(ternary) => self.visit_ternary(ternary, additional),
             Expression::Tuple(tuple) => self.visit_tuple(tuple, additional),
             Expression::Unary(unary) => self.visit_unary(unary, additional),
@@ -107,6 +109,30 @@ pub trait ExpressionVisitor<'a> {
         Default::default()
     }
 
+    fn visit_match(&mut self, input: &'a MatchExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.expression, &Default::default());
+        input.arms.iter().for_each(|arm| {
+            self.visit_match_pattern(&arm.pattern);
+            self.visit_expression(&arm.body, additional);
+        });
+        Default::default()
+    }
+
+    fn visit_match_pattern(&mut self, input: &'a MatchPattern) {
+        match input {
+            MatchPattern::Constant(expression) => {
+                self.visit_expression(expression, &Default::default());
+            }
+            MatchPattern::Or(patterns, _) => patterns.iter().for_each(|pattern| self.visit_match_pattern(pattern)),
+            MatchPattern::Variant(_) | MatchPattern::Wildcard(_) => {}
+        }
+    }
+
+    fn visit_repeat(&mut self, input: &'a RepeatExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.expr, additional);
+        Default::default()
+    }
+
     fn visit_ternary(&mut self, input: &'a TernaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
         self.visit_expression(&input.condition, additional);
         self.visit_expression(&input.if_true, additional);
@@ -135,19 +161,28 @@ pub trait ExpressionVisitor<'a> {
 pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
     fn visit_statement(&mut self, input: &'a Statement) {
         match input {
+            Statement::ArrayIteration(stmt) => self.visit_array_iteration(stmt),
             Statement::Assert(stmt) => self.visit_assert(stmt),
             Statement::Assign(stmt) => self.visit_assign(stmt),
             Statement::Block(stmt) => self.visit_block(stmt),
+            Statement::Break(stmt) => self.visit_break(stmt),
             Statement::Conditional(stmt) => self.visit_conditional(stmt),
             Statement::Console(stmt) => self.visit_console(stmt),
             Statement::Const(stmt) => self.visit_const(stmt),
+            Statement::Continue(stmt) => self.visit_continue(stmt),
             Statement::Definition(stmt) => self.visit_definition(stmt),
             Statement::Expression(stmt) => self.visit_expression_statement(stmt),
             Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Match(stmt) => self.visit_match_statement(stmt),
             Statement::Return(stmt) => self.visit_return(stmt),
         }
     }
 
+    fn visit_array_iteration(&mut self, input: &'a ArrayIterationStatement) {
+        self.visit_expression(&input.array, &Default::default());
+        self.visit_block(&input.block);
+    }
+
     fn visit_assert(&mut self, input: &'a AssertStatement) {
         match &input.variant {
             AssertVariant::Assert(expr) => self.visit_expression(expr, &Default::default()),
@@ -166,6 +201,8 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
         input.statements.iter().for_each(|stmt| self.visit_statement(stmt));
     }
 
+    fn visit_break(&mut self, _input: &'a BreakStatement) {}
+
     fn visit_conditional(&mut self, input: &'a ConditionalStatement) {
         self.visit_expression(&input.condition, &Default::default());
         self.visit_block(&input.then);
@@ -187,6 +224,11 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
                 self.visit_expression(left, &Default::default());
                 self.visit_expression(right, &Default::default());
             }
+            ConsoleFunction::Log(_, args) => {
+                args.iter().for_each(|arg| {
+                    self.visit_expression(arg, &Default::default());
+                });
+            }
         };
     }
 
@@ -194,6 +236,8 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
         self.visit_expression(&input.value, &Default::default());
     }
 
+    fn visit_continue(&mut self, _input: &'a ContinueStatement) {}
+
     fn visit_definition(&mut self, input: &'a DefinitionStatement) {
         self.visit_expression(&input.value, &Default::default());
     }
@@ -208,6 +252,14 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
         self.visit_block(&input.block);
     }
 
+    fn visit_match_statement(&mut self, input: &'a MatchStatement) {
+        self.visit_expression(&input.expression, &Default::default());
+        input.arms.iter().for_each(|arm| {
+            self.visit_match_pattern(&arm.pattern);
+            self.visit_block(&arm.body);
+        });
+    }
+
     fn visit_return(&mut self, input: &'a ReturnStatement) {
         self.visit_expression(&input.expression, &Default::default());
         if let Some(arguments) = &input.finalize_arguments {
@@ -227,8 +279,12 @@ pub trait ProgramVisitor<'a>: StatementVisitor<'a> {
     }
 
     fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        input.type_aliases.iter().for_each(|(_, c)| self.visit_type_alias(c));
+
         input.structs.iter().for_each(|(_, c)| (self.visit_struct(c)));
 
+        input.enums.iter().for_each(|(_, c)| (self.visit_enum(c)));
+
         input.mappings.iter().for_each(|(_, c)| (self.visit_mapping(c)));
 
         input.functions.iter().for_each(|(_, c)| (self.visit_function(c)));
@@ -240,8 +296,12 @@ pub trait ProgramVisitor<'a>: StatementVisitor<'a> {
         self.visit_program(input)
     }
 
+    fn visit_type_alias(&mut self, _input: &'a TypeAlias) {}
+
     fn visit_struct(&mut self, _input: &'a Struct) {}
 
+    fn visit_enum(&mut self, _input: &'a Enum) {}
+
     fn visit_mapping(&mut self, _input: &'a Mapping) {}
 
     fn visit_function(&mut self, input: &'a Function) {
@@ -14,9 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-//! A Leo program scope consists of struct, function, and mapping definitions.
+//! A Leo program scope consists of module declarations, and const, type alias, struct, enum, function, and mapping definitions.
 
-use crate::{ConstDeclaration, Function, Mapping, ProgramId, Struct};
+use crate::{ConstDeclaration, Enum, Function, Mapping, Module, ProgramId, Struct, TypeAlias};
 
 use leo_span::{Span, Symbol};
 use serde::{Deserialize, Serialize};
@@ -27,10 +27,16 @@ use std::fmt;
 pub struct ProgramScope {
     /// The program id of the program scope.
     pub program_id: ProgramId,
+    /// A vector of module declarations.
+    pub modules: Vec<(Symbol, Module)>,
     /// A vector of const definitions
     pub consts: Vec<(Symbol, ConstDeclaration)>,
+    /// A vector of type alias definitions.
+    pub type_aliases: Vec<(Symbol, TypeAlias)>,
     /// A vector of struct definitions.
     pub structs: Vec<(Symbol, Struct)>,
+    /// A vector of enum definitions.
+    pub enums: Vec<(Symbol, Enum)>,
     /// A vector of mapping definitions.
     pub mappings: Vec<(Symbol, Mapping)>,
     /// A vector of function definitions.
@@ -42,9 +48,21 @@ pub struct ProgramScope {
 impl fmt::Display for ProgramScope {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         writeln!(f, "program {} {{", self.program_id)?;
+        for (_, module) in self.modules.iter() {
+            writeln!(f, "    {module}")?;
+        }
+        for (_, const_) in self.consts.iter() {
+            writeln!(f, "    const {const_}")?;
+        }
+        for (_, type_alias) in self.type_aliases.iter() {
+            writeln!(f, "    {type_alias}")?;
+        }
         for (_, struct_) in self.structs.iter() {
             writeln!(f, "    {struct_}")?;
         }
+        for (_, enum_) in self.enums.iter() {
+            writeln!(f, "    {enum_}")?;
+        }
         for (_, mapping) in self.mappings.iter() {
             writeln!(f, "    {mapping}")?;
         }
@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Block, Expression, Identifier, Node, NodeID, Type};
+
+use leo_span::Span;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A `for` loop over the elements of an array, `for variable in array block`.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct ArrayIterationStatement {
+    /// The binding / variable to introduce in the body `block`.
+    pub variable: Identifier,
+    /// The type of the variable, if it is annotated.
+    pub type_: Option<Type>,
+    /// The array whose elements are iterated over.
+    pub array: Expression,
+    /// The block to run on each iteration.
+    pub block: Block,
+    /// The span from `for` to `block`.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl fmt::Display for ArrayIterationStatement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.type_ {
+            Some(type_) => write!(f, "for {}: {type_} in {} {}", self.variable, self.array, self.block),
+            None => write!(f, "for {} in {} {}", self.variable, self.array, self.block),
+        }
+    }
+}
+
+crate::simple_node_impl!(ArrayIterationStatement);
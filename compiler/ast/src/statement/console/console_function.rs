@@ -28,6 +28,8 @@ pub enum ConsoleFunction {
     AssertEq(Expression, Expression),
     /// A `console.assert_neq(expr1, expr2)` call to invoke, asserting that the operands are not equal.
     AssertNeq(Expression, Expression),
+    /// A `console.log("format {}", expr)` call to invoke, printing the format string with each `{}` replaced by an argument.
+    Log(String, Vec<Expression>),
 }
 
 impl fmt::Display for ConsoleFunction {
@@ -36,6 +38,13 @@ impl fmt::Display for ConsoleFunction {
             ConsoleFunction::Assert(expr) => write!(f, "assert({expr})"),
             ConsoleFunction::AssertEq(expr1, expr2) => write!(f, "assert_eq({expr1}, {expr2})"),
             ConsoleFunction::AssertNeq(expr1, expr2) => write!(f, "assert_neq({expr1}, {expr2})"),
+            ConsoleFunction::Log(format, args) => {
+                write!(f, "log(\"{format}\"")?;
+                for arg in args.iter() {
+                    write!(f, ", {arg}")?;
+                }
+                write!(f, ")")
+            }
         }
     }
 }
@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod array_iteration;
+pub use array_iteration::*;
+
 pub mod assert;
 pub use assert::*;
 
@@ -23,6 +26,9 @@ pub use assign::*;
 pub mod block;
 pub use block::*;
 
+pub mod break_;
+pub use break_::*;
+
 pub mod conditional;
 pub use conditional::*;
 
@@ -32,6 +38,9 @@ pub use console::*;
 pub mod const_;
 pub use const_::*;
 
+pub mod continue_;
+pub use continue_::*;
+
 pub mod definition;
 pub use definition::*;
 
@@ -41,6 +50,9 @@ pub use expression::*;
 pub mod iteration;
 pub use iteration::*;
 
+pub mod match_;
+pub use match_::*;
+
 pub mod return_;
 pub use return_::*;
 
@@ -54,24 +66,32 @@ use std::fmt;
 /// Program statement that defines some action (or expression) to be carried out.
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub enum Statement {
+    /// A `for` statement over the elements of an array.
+    ArrayIteration(Box<ArrayIterationStatement>),
     /// An assert statement.
     Assert(AssertStatement),
     /// An assignment statement.
     Assign(Box<AssignStatement>),
     /// A block statement.
     Block(Block),
+    /// A `break` statement.
+    Break(BreakStatement),
     /// An `if` statement.
     Conditional(ConditionalStatement),
     /// A console logging statement.
     Console(ConsoleStatement),
     /// A binding from identifier to constant value.
     Const(ConstDeclaration),
+    /// A `continue` statement.
+    Continue(ContinueStatement),
     /// A binding or set of bindings / variables to declare.
     Definition(DefinitionStatement),
     /// An expression statement
     Expression(ExpressionStatement),
     /// A `for` statement.
     Iteration(Box<IterationStatement>),
+    /// A `match` statement.
+    Match(MatchStatement),
     /// A return statement `return expr;`.
     Return(ReturnStatement),
 }
@@ -86,15 +106,19 @@ impl Statement {
 impl fmt::Display for Statement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            Statement::ArrayIteration(x) => x.fmt(f),
             Statement::Assert(x) => x.fmt(f),
             Statement::Assign(x) => x.fmt(f),
             Statement::Block(x) => x.fmt(f),
+            Statement::Break(x) => x.fmt(f),
             Statement::Conditional(x) => x.fmt(f),
             Statement::Console(x) => x.fmt(f),
             Statement::Const(x) => x.fmt(f),
+            Statement::Continue(x) => x.fmt(f),
             Statement::Definition(x) => x.fmt(f),
             Statement::Expression(x) => x.fmt(f),
             Statement::Iteration(x) => x.fmt(f),
+            Statement::Match(x) => x.fmt(f),
             Statement::Return(x) => x.fmt(f),
         }
     }
@@ -104,15 +128,19 @@ impl Node for Statement {
     fn span(&self) -> Span {
         use Statement::*;
         match self {
+            ArrayIteration(n) => n.span(),
             Assert(n) => n.span(),
             Assign(n) => n.span(),
             Block(n) => n.span(),
+            Break(n) => n.span(),
             Conditional(n) => n.span(),
             Console(n) => n.span(),
             Const(n) => n.span(),
+            Continue(n) => n.span(),
             Definition(n) => n.span(),
             Expression(n) => n.span(),
             Iteration(n) => n.span(),
+            Match(n) => n.span(),
             Return(n) => n.span(),
         }
     }
@@ -120,15 +148,19 @@ impl Node for Statement {
     fn set_span(&mut self, span: Span) {
         use Statement::*;
         match self {
+            ArrayIteration(n) => n.set_span(span),
             Assert(n) => n.set_span(span),
             Assign(n) => n.set_span(span),
             Block(n) => n.set_span(span),
+            Break(n) => n.set_span(span),
             Conditional(n) => n.set_span(span),
             Console(n) => n.set_span(span),
             Const(n) => n.set_span(span),
+            Continue(n) => n.set_span(span),
             Definition(n) => n.set_span(span),
             Expression(n) => n.set_span(span),
             Iteration(n) => n.set_span(span),
+            Match(n) => n.set_span(span),
             Return(n) => n.set_span(span),
         }
     }
@@ -136,15 +168,19 @@ impl Node for Statement {
     fn id(&self) -> NodeID {
         use Statement::*;
         match self {
+            ArrayIteration(n) => n.id(),
             Assert(n) => n.id(),
             Assign(n) => n.id(),
             Block(n) => n.id(),
+            Break(n) => n.id(),
             Conditional(n) => n.id(),
             Console(n) => n.id(),
             Const(n) => n.id(),
+            Continue(n) => n.id(),
             Definition(n) => n.id(),
             Expression(n) => n.id(),
             Iteration(n) => n.id(),
+            Match(n) => n.id(),
             Return(n) => n.id(),
         }
     }
@@ -152,15 +188,19 @@ impl Node for Statement {
     fn set_id(&mut self, id: NodeID) {
         use Statement::*;
         match self {
+            ArrayIteration(n) => n.set_id(id),
             Assert(n) => n.set_id(id),
             Assign(n) => n.set_id(id),
             Block(n) => n.set_id(id),
+            Break(n) => n.set_id(id),
             Conditional(n) => n.set_id(id),
             Console(n) => n.set_id(id),
             Const(n) => n.set_id(id),
+            Continue(n) => n.set_id(id),
             Definition(n) => n.set_id(id),
             Expression(n) => n.set_id(id),
             Iteration(n) => n.set_id(id),
+            Match(n) => n.set_id(id),
             Return(n) => n.set_id(id),
         }
     }
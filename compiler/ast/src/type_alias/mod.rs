@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Identifier, Node, NodeID, Type};
+
+use leo_span::{Span, Symbol};
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A type alias declaration, e.g. `type Board = [[u8; 3]; 3];`.
+///
+/// Unlike structs and enums, aliases are structural: `Board` and `[[u8; 3]; 3]` are the same type.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TypeAlias {
+    /// The name of the alias.
+    pub identifier: Identifier,
+    /// The type the alias stands for.
+    pub type_: Type,
+    /// The entire span of the type alias declaration.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl TypeAlias {
+    /// Returns the alias name as a Symbol.
+    pub fn name(&self) -> Symbol {
+        self.identifier.name
+    }
+}
+
+impl fmt::Display for TypeAlias {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "type {} = {}", self.identifier, self.type_)
+    }
+}
+
+crate::simple_node_impl!(TypeAlias);
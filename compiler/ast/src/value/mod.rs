@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{GroupLiteral, Identifier, IntegerType, Literal, NodeID, Type};
+use crate::{ArrayType, GroupLiteral, Identifier, IntegerType, Literal, NodeID, NonNegativeNumber, TupleType, Type};
 
 use leo_errors::{type_name, FlattenError, LeoError, Result};
 use leo_span::{Span, Symbol};
@@ -28,9 +28,6 @@ use std::{
 
 // TODO: Consider refactoring this module to use the console implementations from snarkVM.
 
-// This is temporary since the currently unused code is used in constant folding.
-#[allow(dead_code)]
-
 // Macro for making implementing unary operations over appropriate types easier.
 macro_rules! implement_const_unary {
     (
@@ -71,9 +68,7 @@ macro_rules! implement_const_unary {
             l: $logic:expr
         ]),+]
     ) => {
-        // TODO: This is temporary since the currently unused code is used in constant folding.
-        #[allow(dead_code)]
-        pub(crate) fn $name(self, span: Span) -> Result<Self> {
+        pub fn $name(self, span: Span) -> Result<Self> {
             use Value::*;
 
             match self {
@@ -157,9 +152,7 @@ macro_rules! implement_const_binary {
             logic: $logic:expr
         ]),+]
     ) => {
-        // This is temporary since the currently unused code is used in constant folding.
-        #[allow(dead_code)]
-        pub(crate) fn $name(self, other: Self, span: Span) -> Result<Self> {
+        pub fn $name(self, other: Self, span: Span) -> Result<Self> {
             use Value::*;
 
             match (self, other) {
@@ -199,6 +192,8 @@ pub enum Value {
     U128(u128, Span),
     Scalar(String, Span),
     String(String, Span),
+    Array(Vec<Value>),
+    Tuple(Vec<Value>),
 }
 
 impl Value {
@@ -566,6 +561,43 @@ impl Value {
         ]
     );
 
+    implement_const_binary!(
+        @overflowing
+        name: rem,
+        method: checked_rem,
+        string: "%",
+        patterns: [
+            [I8, [I8], I8, i8, i8],
+            [I16, [I16], I16, i16, i16],
+            [I32, [I32], I32, i32, i32],
+            [I64, [I64], I64, i64, i64],
+            [I128, [I128], I128, i128, i128],
+            [U8, [U8], U8, u8, u8],
+            [U16, [U16], U16, u16, u16],
+            [U32, [U32], U32, u32, u32],
+            [U64, [U64], U64, u64, u64],
+            [U128, [U128], U128, u128, u128]
+        ]
+    );
+
+    implement_const_binary!(
+        @non-overflowing
+        name: rem_wrapped,
+        method: wrapping_rem,
+        patterns: [
+            [I8, [I8], I8, i8, i8],
+            [I16, [I16], I16, i16, i16],
+            [I32, [I32], I32, i32, i32],
+            [I64, [I64], I64, i64, i64],
+            [I128, [I128], I128, i128, i128],
+            [U8, [U8], U8, u8, u8],
+            [U16, [U16], U16, u16, u16],
+            [U32, [U32], U32, u32, u32],
+            [U64, [U64], U64, u64, u64],
+            [U128, [U128], U128, u128, u128]
+        ]
+    );
+
     implement_const_binary!(
         @overflowing
         name: shl,
@@ -702,9 +734,8 @@ impl Value {
         ]
     );
 
-    // TODO: This is temporary since the currently unused code is used in constant folding.
-    #[allow(dead_code)]
-    pub(crate) fn is_supported_const_fold_type(&self) -> bool {
+    /// Returns `true` if the const operations above are defined on the value.
+    pub fn is_supported_const_fold_type(&self) -> bool {
         use Value::*;
         matches!(
             self,
@@ -745,6 +776,8 @@ impl Display for Value {
             U128(val, _) => write!(f, "{val}"),
             Scalar(val, _) => write!(f, "{val}"),
             String(val, _) => write!(f, "{val}"),
+            Array(vals) => write!(f, "[{}]", vals.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ")),
+            Tuple(vals) => write!(f, "({})", vals.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ")),
         }
     }
 }
@@ -861,6 +894,12 @@ impl From<&Value> for Type {
             U128(_, _) => Type::Integer(IntegerType::U128),
             Scalar(_, _) => Type::Scalar,
             String(_, _) => Type::String,
+            Array(vals) => Type::Array(ArrayType::new(
+                vals.first().map_or(Type::Err, Type::from),
+                NonNegativeNumber::from(vals.len()),
+            )),
+            Tuple(vals) if vals.is_empty() => Type::Unit,
+            Tuple(vals) => Type::Tuple(TupleType::new(vals.iter().map(Type::from).collect())),
         }
     }
 }
@@ -897,14 +936,14 @@ impl TryFrom<&Literal> for Value {
 }
 
 impl Literal {
-    #[allow(unused)]
-    fn from_value(v: Value, id: NodeID) -> Self {
+    /// Converts a constant value back into a literal with the given node ID.
+    /// Returns `None` for inputs, structs, arrays, and tuples, which have no literal form.
+    pub fn from_value(v: Value, id: NodeID) -> Option<Self> {
         use Value::*;
-        match v {
-            Input(_, _) => todo!("We need to test if this is hittable"),
+        Some(match v {
+            Input(..) | Struct(..) | Array(_) | Tuple(_) => return None,
             Address(v, span) => Literal::Address(v, span, id),
             Boolean(v, span) => Literal::Boolean(v, span, id),
-            Struct(_ident, _values) => todo!("We need to test if this is hittable"),
             Field(v, span) => Literal::Field(v, span, id),
             Group(v) => Literal::Group(v),
             I8(v, span) => Literal::Integer(IntegerType::I8, v.to_string(), span, id),
@@ -919,6 +958,6 @@ impl Literal {
             U128(v, span) => Literal::Integer(IntegerType::U128, v.to_string(), span, id),
             Scalar(v, span) => Literal::Scalar(v, span, id),
             String(v, span) => Literal::String(v, span, id),
-        }
+        })
     }
 }
@@ -22,10 +22,14 @@ use leo_ast::{NodeBuilder, Program};
 use leo_errors::{emitter::Handler, CompilerError, Result};
 pub use leo_passes::SymbolTable;
 use leo_passes::*;
-use leo_span::{source_map::FileName, symbol::with_session_globals};
+use leo_span::{source_map::FileName, sym, symbol::with_session_globals, Symbol};
 
+use indexmap::IndexMap;
 use sha2::{Digest, Sha256};
-use std::{fs, path::PathBuf};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 
 use crate::CompilerOptions;
 
@@ -46,6 +50,10 @@ pub struct Compiler<'a> {
     pub ast: Ast,
     /// The input ast for the program if it exists.
     pub input_ast: Option<InputAst>,
+    /// The AST of the program once it is type checked, before it is lowered, e.g. for the interpreter.
+    pub type_checked_ast: Option<Ast>,
+    /// The paths to the Leo source of the imported programs which are not in the `imports/` directory of the package.
+    pub import_paths: IndexMap<Symbol, PathBuf>,
     /// Options configuring compilation.
     compiler_options: CompilerOptions,
     /// The `NodeCounter` used to generate sequentially increasing `NodeID`s.
@@ -77,6 +85,8 @@ impl<'a> Compiler<'a> {
             network,
             ast: Ast::new(Program::default()),
             input_ast: None,
+            type_checked_ast: None,
+            import_paths: IndexMap::new(),
             compiler_options: compiler_options.unwrap_or_default(),
             node_builder,
             assigner,
@@ -120,6 +130,9 @@ impl<'a> Compiler<'a> {
             .into());
         }
 
+        self.module_resolution_pass()?;
+        self.import_resolution_pass()?;
+
         if self.compiler_options.output.initial_ast {
             self.write_ast_to_json("initial_ast.json")?;
         }
@@ -133,7 +146,76 @@ impl<'a> Compiler<'a> {
         let program_string = fs::read_to_string(&self.main_file_path)
             .map_err(|e| CompilerError::file_read_error(&self.main_file_path, e))?;
 
-        self.parse_program_from_string(&program_string, FileName::Real(self.main_file_path.clone()))
+        self.parse_program_from_string(&program_string, FileName::Real(self.main_file_path.clone()))?;
+
+        // Test transitions are only compiled by `leo test`, so that they never ship in the program emitted by `leo build`.
+        self.check_no_test_annotations()
+    }
+
+    /// Returns an error if a function of the program or of its imports is annotated with `@test` or `@should_fail`.
+    fn check_no_test_annotations(&self) -> Result<()> {
+        let program = &self.ast.ast;
+        let scopes = program
+            .program_scopes
+            .values()
+            .chain(program.imports.values().flat_map(|(import, _)| import.program_scopes.values()));
+        for (_, function) in scopes.flat_map(|scope| scope.functions.iter()) {
+            if let Some(annotation) = function
+                .annotations
+                .iter()
+                .find(|annotation| matches!(annotation.identifier.name, sym::test | sym::should_fail))
+            {
+                return Err(CompilerError::test_annotation_outside_tests_directory(annotation, annotation.span).into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses the test files and adds their declarations to the program scope of the main program.
+    /// Note that each test file must declare the same program scope as the main program.
+    pub fn parse_tests(&mut self, test_file_paths: &[PathBuf]) -> Result<()> {
+        for test_file_path in test_file_paths {
+            // Load the test file.
+            let test_string =
+                fs::read_to_string(test_file_path).map_err(|e| CompilerError::file_read_error(test_file_path, e))?;
+
+            // Register the source in the source map and parse it.
+            let test_sf =
+                with_session_globals(|s| s.source_map.new_source(&test_string, FileName::Real(test_file_path.clone())));
+            let test_ast = leo_parser::parse_ast(self.handler, &self.node_builder, &test_sf.src, test_sf.start_pos)?;
+
+            // The modules of a test file are in the directory of the test file.
+            let test_directory = test_file_path.parent().unwrap_or(Path::new(""));
+            let test_ast = ModuleResolver::do_pass((test_ast, self.handler, &self.node_builder, test_directory))?;
+            let Program { imports, program_scopes } = test_ast.into_repr();
+
+            // Note that parsing enforces that there is exactly one program scope in a file.
+            let test_scope = program_scopes.into_values().next().unwrap();
+            let test_scope_name = format!("{}", test_scope.program_id.name);
+            if test_scope_name != self.program_name {
+                return Err(CompilerError::program_scope_name_does_not_match(
+                    test_scope_name,
+                    self.program_name.clone(),
+                    test_scope.program_id.name.span,
+                )
+                .into());
+            }
+
+            // Merge the test declarations into the main program.
+            let program = &mut self.ast.ast;
+            program.imports.extend(imports);
+            let program_scope = program.program_scopes.values_mut().next().unwrap();
+            program_scope.modules.extend(test_scope.modules);
+            program_scope.consts.extend(test_scope.consts);
+            program_scope.type_aliases.extend(test_scope.type_aliases);
+            program_scope.structs.extend(test_scope.structs);
+            program_scope.enums.extend(test_scope.enums);
+            program_scope.mappings.extend(test_scope.mappings);
+            program_scope.functions.extend(test_scope.functions);
+        }
+
+        // Resolve the imports of the test files along with those of the main program.
+        self.import_resolution_pass()
     }
 
     /// Parses and stores the input file, constructs a syntax tree, and generates a program input.
@@ -167,6 +249,29 @@ impl<'a> Compiler<'a> {
         Ok(())
     }
 
+    /// Runs the module resolution pass, which looks up the modules of the program in the directory of the main file.
+    pub fn module_resolution_pass(&mut self) -> Result<()> {
+        let directory = self.main_file_path.parent().unwrap_or(Path::new(""));
+        self.ast =
+            ModuleResolver::do_pass((std::mem::take(&mut self.ast), self.handler, &self.node_builder, directory))?;
+        Ok(())
+    }
+
+    /// Runs the import resolution pass.
+    /// The imports are looked up in the import paths, and otherwise in the package of the main file,
+    /// which is either in its `src/` or `imports/` directory.
+    pub fn import_resolution_pass(&mut self) -> Result<()> {
+        let package_root = self.main_file_path.parent().and_then(Path::parent).unwrap_or(Path::new(""));
+        self.ast = ImportResolver::do_pass((
+            std::mem::take(&mut self.ast),
+            self.handler,
+            &self.node_builder,
+            package_root,
+            &self.import_paths,
+        ))?;
+        Ok(())
+    }
+
     /// Runs the symbol table pass.
     pub fn symbol_table_pass(&self) -> Result<SymbolTable> {
         let symbol_table = SymbolTableCreator::do_pass((&self.ast, self.handler))?;
@@ -176,6 +281,18 @@ impl<'a> Compiler<'a> {
         Ok(symbol_table)
     }
 
+    /// Runs the type alias resolution pass.
+    pub fn type_alias_resolution_pass(&mut self, symbol_table: SymbolTable) -> Result<SymbolTable> {
+        let (ast, symbol_table) = TypeAliasResolver::do_pass((
+            std::mem::take(&mut self.ast),
+            self.handler,
+            &self.node_builder,
+            symbol_table,
+        ))?;
+        self.ast = ast;
+        Ok(symbol_table)
+    }
+
     /// Runs the type checker pass.
     pub fn type_checker_pass(&'a self, symbol_table: SymbolTable) -> Result<(SymbolTable, StructGraph, CallGraph)> {
         let (symbol_table, struct_graph, call_graph) =
@@ -186,12 +303,32 @@ impl<'a> Compiler<'a> {
         Ok((symbol_table, struct_graph, call_graph))
     }
 
+    /// Runs the enum lowering pass.
+    pub fn enum_lowering_pass(&mut self, symbol_table: SymbolTable) -> Result<SymbolTable> {
+        let (ast, symbol_table) = EnumLowerer::do_pass((
+            std::mem::take(&mut self.ast),
+            &self.node_builder,
+            &self.assigner,
+            symbol_table,
+            &self.type_table,
+        ))?;
+        self.ast = ast;
+        Ok(symbol_table)
+    }
+
+    /// Runs the console removal pass.
+    pub fn console_removal_pass(&mut self) -> Result<()> {
+        self.ast = ConsoleRemover::do_pass(std::mem::take(&mut self.ast))?;
+        Ok(())
+    }
+
     /// Runs the loop unrolling pass.
     pub fn loop_unrolling_pass(&mut self, symbol_table: SymbolTable) -> Result<SymbolTable> {
         let (ast, symbol_table) = Unroller::do_pass((
             std::mem::take(&mut self.ast),
             self.handler,
             &self.node_builder,
+            &self.assigner,
             symbol_table,
             &self.type_table,
         ))?;
@@ -208,6 +345,17 @@ impl<'a> Compiler<'a> {
         Ok(symbol_table)
     }
 
+    /// Runs the constant folding pass.
+    pub fn constant_folding_pass(&mut self) -> Result<()> {
+        self.ast = ConstantFolder::do_pass((
+            std::mem::take(&mut self.ast),
+            self.handler,
+            &self.node_builder,
+            &self.type_table,
+        ))?;
+        Ok(())
+    }
+
     /// Runs the static single assignment pass.
     pub fn static_single_assignment_pass(&mut self, symbol_table: &SymbolTable) -> Result<()> {
         self.ast = StaticSingleAssigner::do_pass((
@@ -229,6 +377,7 @@ impl<'a> Compiler<'a> {
     pub fn flattening_pass(&mut self, symbol_table: &SymbolTable) -> Result<()> {
         self.ast = Flattener::do_pass((
             std::mem::take(&mut self.ast),
+            self.handler,
             symbol_table,
             &self.type_table,
             &self.node_builder,
@@ -276,6 +425,17 @@ impl<'a> Compiler<'a> {
         Ok(())
     }
 
+    /// Runs the common subexpression elimination pass.
+    pub fn common_subexpression_elimination_pass(&mut self) -> Result<()> {
+        self.ast = CommonSubexpressionEliminator::do_pass(std::mem::take(&mut self.ast))?;
+
+        if self.compiler_options.output.cse_ast {
+            self.write_ast_to_json("cse_ast.json")?;
+        }
+
+        Ok(())
+    }
+
     /// Runs the dead code elimination pass.
     pub fn dead_code_elimination_pass(&mut self) -> Result<()> {
         if self.compiler_options.build.dce_enabled {
@@ -302,11 +462,19 @@ impl<'a> Compiler<'a> {
     /// Runs the compiler stages.
     pub fn compiler_stages(&mut self) -> Result<(SymbolTable, StructGraph, CallGraph)> {
         let st = self.symbol_table_pass()?;
+        let st = self.type_alias_resolution_pass(st)?;
         let (st, struct_graph, call_graph) = self.type_checker_pass(st)?;
+        self.type_checked_ast = Some(self.ast.clone());
+
+        let st = self.enum_lowering_pass(st)?;
+
+        self.console_removal_pass()?;
 
         // TODO: Make this pass optional.
         let st = self.loop_unrolling_pass(st)?;
 
+        self.constant_folding_pass()?;
+
         self.static_single_assignment_pass(&st)?;
 
         self.flattening_pass(&st)?;
@@ -315,6 +483,8 @@ impl<'a> Compiler<'a> {
 
         self.function_inlining_pass(&call_graph)?;
 
+        self.common_subexpression_elimination_pass()?;
+
         self.dead_code_elimination_pass()?;
 
         Ok((st, struct_graph, call_graph))
@@ -331,6 +501,18 @@ impl<'a> Compiler<'a> {
         Ok((symbol_table, bytecode))
     }
 
+    /// Returns the compiled Leo program, including the transitions declared in the given test files.
+    pub fn compile_tests(&mut self, test_file_paths: &[PathBuf]) -> Result<(SymbolTable, String)> {
+        // Parse the program and its tests.
+        self.parse_program()?;
+        self.parse_tests(test_file_paths)?;
+        // Run the intermediate compiler stages.
+        let (symbol_table, struct_graph, call_graph) = self.compiler_stages()?;
+        // Run code generation.
+        let bytecode = self.code_generation_pass(&symbol_table, &struct_graph, &call_graph)?;
+        Ok((symbol_table, bytecode))
+    }
+
     /// Writes the AST to a JSON file.
     fn write_ast_to_json(&self, file_suffix: &str) -> Result<()> {
         // Remove `Span`s if they are not enabled.
@@ -56,6 +56,8 @@ pub struct OutputOptions {
     pub destructured_ast: bool,
     /// If enabled writes the AST after inlining.
     pub inlined_ast: bool,
+    /// If enabled writes the AST after common subexpression elimination.
+    pub cse_ast: bool,
     /// If enabled writes the AST after dead code elimination.
     pub dce_ast: bool,
 }
@@ -68,6 +68,7 @@ struct CompileOutput {
     pub flattened_ast: String,
     pub destructured_ast: String,
     pub inlined_ast: String,
+    pub cse_ast: String,
     pub dce_ast: String,
     pub bytecode: String,
     pub warnings: String,
@@ -98,6 +99,7 @@ fn run_test(test: Test, handler: &Handler, buf: &BufferEmitter) -> Result<Value,
                 flattened_ast: true,
                 destructured_ast: true,
                 inlined_ast: true,
+                cse_ast: true,
                 dce_ast: true,
             },
         };
@@ -118,7 +120,8 @@ fn run_test(test: Test, handler: &Handler, buf: &BufferEmitter) -> Result<Value,
         handler.extend_if_error(package.get_process().map_err(LeoError::Anyhow))?;
 
         // Hash the ast files.
-        let (initial_ast, unrolled_ast, ssa_ast, flattened_ast, destructured_ast, inlined_ast, dce_ast) = hash_asts();
+        let (initial_ast, unrolled_ast, ssa_ast, flattened_ast, destructured_ast, inlined_ast, cse_ast, dce_ast) =
+            hash_asts();
 
         // Hash the symbol tables.
         let (initial_symbol_table, type_checked_symbol_table, unrolled_symbol_table) = hash_symbol_tables();
@@ -138,6 +141,7 @@ fn run_test(test: Test, handler: &Handler, buf: &BufferEmitter) -> Result<Value,
             flattened_ast,
             destructured_ast,
             inlined_ast,
+            cse_ast,
             dce_ast,
             bytecode: hash_content(&bytecode),
             warnings: buf.1.take().to_string(),
@@ -76,6 +76,7 @@ struct ExecuteOutput {
     pub flattened_ast: String,
     pub destructured_ast: String,
     pub inlined_ast: String,
+    pub cse_ast: String,
     pub dce_ast: String,
     pub bytecode: String,
     pub warnings: String,
@@ -112,6 +113,7 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
                 flattened_ast: true,
                 destructured_ast: true,
                 inlined_ast: true,
+                cse_ast: true,
                 dce_ast: true,
             },
         };
@@ -202,7 +204,8 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
         }
 
         // Hash the ast files.
-        let (initial_ast, unrolled_ast, ssa_ast, flattened_ast, destructured_ast, inlined_ast, dce_ast) = hash_asts();
+        let (initial_ast, unrolled_ast, ssa_ast, flattened_ast, destructured_ast, inlined_ast, cse_ast, dce_ast) =
+            hash_asts();
 
         // Hash the symbol tables.
         let (initial_symbol_table, type_checked_symbol_table, unrolled_symbol_table) = hash_symbol_tables();
@@ -222,6 +225,7 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
             flattened_ast,
             destructured_ast,
             inlined_ast,
+            cse_ast,
             dce_ast,
             bytecode: hash_content(&bytecode),
             warnings: err_buf.1.take().to_string(),
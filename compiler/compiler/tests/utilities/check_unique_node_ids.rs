@@ -54,6 +54,22 @@ impl<'a> CheckUniqueNodeIds<'a> {
             _ => {}
         }
     }
+
+    /// Checks that the nodes in the given `MatchPattern` have unique `NodeID`s.
+    pub fn check_pattern(&mut self, pattern: &'a MatchPattern) {
+        match pattern {
+            MatchPattern::Constant(expression) => self.visit_expression(expression, &Default::default()),
+            MatchPattern::Or(patterns, _) => patterns.iter().for_each(|pattern| self.check_pattern(pattern)),
+            MatchPattern::Variant(VariantPattern { enum_name, variant, binding, .. }) => {
+                self.visit_identifier(enum_name, &Default::default());
+                self.visit_identifier(variant, &Default::default());
+                if let Some(binding) = binding {
+                    self.visit_identifier(binding, &Default::default());
+                }
+            }
+            MatchPattern::Wildcard(_) => {}
+        }
+    }
 }
 
 impl<'a> ExpressionVisitor<'a> for CheckUniqueNodeIds<'a> {
@@ -143,6 +159,22 @@ impl<'a> ExpressionVisitor<'a> for CheckUniqueNodeIds<'a> {
         self.check(input.id())
     }
 
+    fn visit_match(&mut self, input: &'a MatchExpression, _: &Self::AdditionalInput) -> Self::Output {
+        let MatchExpression { expression, arms, id, .. } = input;
+        self.visit_expression(expression, &Default::default());
+        for MatchArm { pattern, body, .. } in arms {
+            self.check_pattern(pattern);
+            self.visit_expression(body, &Default::default());
+        }
+        self.check(*id);
+    }
+
+    fn visit_repeat(&mut self, input: &'a RepeatExpression, _: &Self::AdditionalInput) -> Self::Output {
+        let RepeatExpression { expr, id, .. } = input;
+        self.visit_expression(expr, &Default::default());
+        self.check(*id);
+    }
+
     fn visit_ternary(&mut self, input: &'a TernaryExpression, _: &Self::AdditionalInput) -> Self::Output {
         let TernaryExpression { condition, if_true, if_false, id, .. } = input;
         self.visit_expression(condition, &Default::default());
@@ -171,6 +203,16 @@ impl<'a> ExpressionVisitor<'a> for CheckUniqueNodeIds<'a> {
 }
 
 impl<'a> StatementVisitor<'a> for CheckUniqueNodeIds<'a> {
+    fn visit_array_iteration(&mut self, input: &'a ArrayIterationStatement) {
+        self.visit_identifier(&input.variable, &Default::default());
+        if let Some(type_) = &input.type_ {
+            self.check_ty(type_);
+        }
+        self.visit_expression(&input.array, &Default::default());
+        self.visit_block(&input.block);
+        self.check(input.id)
+    }
+
     fn visit_assert(&mut self, input: &'a AssertStatement) {
         match &input.variant {
             AssertVariant::Assert(expr) => self.visit_expression(expr, &Default::default()),
@@ -193,6 +235,10 @@ impl<'a> StatementVisitor<'a> for CheckUniqueNodeIds<'a> {
         self.check(input.id)
     }
 
+    fn visit_break(&mut self, input: &'a BreakStatement) {
+        self.check(input.id)
+    }
+
     fn visit_conditional(&mut self, input: &'a ConditionalStatement) {
         self.visit_expression(&input.condition, &Default::default());
         self.visit_block(&input.then);
@@ -215,10 +261,19 @@ impl<'a> StatementVisitor<'a> for CheckUniqueNodeIds<'a> {
                 self.visit_expression(left, &Default::default());
                 self.visit_expression(right, &Default::default());
             }
+            ConsoleFunction::Log(_, args) => {
+                args.iter().for_each(|arg| {
+                    self.visit_expression(arg, &Default::default());
+                });
+            }
         };
         self.check(input.id)
     }
 
+    fn visit_continue(&mut self, input: &'a ContinueStatement) {
+        self.check(input.id)
+    }
+
     fn visit_definition(&mut self, input: &'a DefinitionStatement) {
         self.visit_expression(&input.place, &Default::default());
         self.check_ty(&input.type_);
@@ -240,6 +295,15 @@ impl<'a> StatementVisitor<'a> for CheckUniqueNodeIds<'a> {
         self.check(input.id)
     }
 
+    fn visit_match_statement(&mut self, input: &'a MatchStatement) {
+        self.visit_expression(&input.expression, &Default::default());
+        for MatchArm { pattern, body, .. } in &input.arms {
+            self.check_pattern(pattern);
+            self.visit_block(body);
+        }
+        self.check(input.id)
+    }
+
     fn visit_return(&mut self, input: &'a ReturnStatement) {
         self.visit_expression(&input.expression, &Default::default());
         if let Some(arguments) = &input.finalize_arguments {
@@ -263,6 +327,26 @@ impl<'a> ProgramVisitor<'a> for CheckUniqueNodeIds<'a> {
         self.check(*id);
     }
 
+    fn visit_enum(&mut self, input: &'a Enum) {
+        let Enum { identifier, variants, id, .. } = input;
+        self.visit_identifier(identifier, &Default::default());
+        for EnumVariant { identifier, payload, id, .. } in variants {
+            self.visit_identifier(identifier, &Default::default());
+            if let Some(payload) = payload {
+                self.check_ty(payload);
+            }
+            self.check(*id);
+        }
+        self.check(*id);
+    }
+
+    fn visit_type_alias(&mut self, input: &'a TypeAlias) {
+        let TypeAlias { identifier, type_, id, .. } = input;
+        self.visit_identifier(identifier, &Default::default());
+        self.check_ty(type_);
+        self.check(*id);
+    }
+
     fn visit_mapping(&mut self, input: &'a Mapping) {
         let Mapping { identifier, key_type, value_type, id, .. } = input;
         self.visit_identifier(identifier, &Default::default());
@@ -44,16 +44,17 @@ pub type Network = Testnet3;
 #[allow(unused)]
 pub type Aleo = snarkvm::circuit::AleoV0;
 
-pub fn hash_asts() -> (String, String, String, String, String, String, String) {
+pub fn hash_asts() -> (String, String, String, String, String, String, String, String) {
     let initial_ast = hash_file("/tmp/output/test.initial_ast.json");
     let unrolled_ast = hash_file("/tmp/output/test.unrolled_ast.json");
     let ssa_ast = hash_file("/tmp/output/test.ssa_ast.json");
     let flattened_ast = hash_file("/tmp/output/test.flattened_ast.json");
     let destructured_ast = hash_file("/tmp/output/test.destructured_ast.json");
     let inlined_ast = hash_file("/tmp/output/test.inlined_ast.json");
+    let cse_ast = hash_file("/tmp/output/test.cse_ast.json");
     let dce_ast = hash_file("/tmp/output/test.dce_ast.json");
 
-    (initial_ast, unrolled_ast, ssa_ast, flattened_ast, destructured_ast, inlined_ast, dce_ast)
+    (initial_ast, unrolled_ast, ssa_ast, flattened_ast, destructured_ast, inlined_ast, cse_ast, dce_ast)
 }
 
 pub fn hash_symbol_tables() -> (String, String, String) {
@@ -226,12 +227,22 @@ pub fn compile_and_process<'a>(parsed: &'a mut Compiler<'a>) -> Result<String, L
 
     CheckUniqueNodeIds::new().visit_program(&parsed.ast.ast);
 
+    let st = parsed.type_alias_resolution_pass(st)?;
+
+    CheckUniqueNodeIds::new().visit_program(&parsed.ast.ast);
+
     let (st, struct_graph, call_graph) = parsed.type_checker_pass(st)?;
 
     CheckUniqueNodeIds::new().visit_program(&parsed.ast.ast);
 
+    let st = parsed.enum_lowering_pass(st)?;
+
+    parsed.console_removal_pass()?;
+
     let st = parsed.loop_unrolling_pass(st)?;
 
+    parsed.constant_folding_pass()?;
+
     parsed.static_single_assignment_pass(&st)?;
 
     parsed.flattening_pass(&st)?;
@@ -240,6 +251,8 @@ pub fn compile_and_process<'a>(parsed: &'a mut Compiler<'a>) -> Result<String, L
 
     parsed.function_inlining_pass(&call_graph)?;
 
+    parsed.common_subexpression_elimination_pass()?;
+
     parsed.dead_code_elimination_pass()?;
 
     // Compile Leo program to bytecode.
@@ -0,0 +1,216 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    evaluate_unary_operation,
+    format_value,
+    from_literal,
+    literal_type_from_suffix,
+    to_literal,
+    to_plaintext,
+    CurrentNetwork,
+    Hook,
+    Interpreter,
+};
+
+use leo_ast::{AssociatedFunction, Expression, Type, UnaryOperation, Value};
+use leo_errors::{InterpreterError, Result};
+use leo_span::{sym, Span, Symbol};
+
+use snarkvm_console::{
+    network::{
+        prelude::{Result as ConsoleResult, ToBits, ToFields, Uniform},
+        Network,
+    },
+    program::{Literal, LiteralType},
+    types::Field,
+};
+
+type N = CurrentNetwork;
+
+impl<'a, H: Hook> Interpreter<'a, H> {
+    /// Evaluates a call to a core function, e.g. `BHP256::hash_to_field(x)` or `Mapping::get(m, k)`.
+    pub(crate) fn evaluate_core_function(&mut self, function: &'a AssociatedFunction) -> Result<Value> {
+        let (module, span) = match &function.ty {
+            Type::Identifier(module) => (module.name, function.span),
+            _ => unreachable!("Type checking guarantees that core functions are called on core modules."),
+        };
+        if module == sym::Mapping {
+            return self.evaluate_mapping_function(function.name.name, &function.arguments, span);
+        }
+
+        let arguments = self.evaluate_all(&function.arguments)?;
+        let name = function.name.name.to_string();
+        match (module, name.split_once("_to_")) {
+            (sym::group, _) => match UnaryOperation::from_symbol(function.name.name) {
+                Some(op) => evaluate_unary_operation(op, arguments[0].clone(), span),
+                None => Err(InterpreterError::unsupported(format!("`{function}`"), span).into()),
+            },
+            (sym::ChaCha, _) => self.rand(name.trim_start_matches("rand_"), span),
+            (_, Some(("hash", output))) => self.hash(module, &arguments[0], output, span),
+            (_, Some(("commit", output))) => self.commit(module, &arguments[0], &arguments[1], output, span),
+            _ => Err(InterpreterError::unsupported(format!("`{function}`"), span).into()),
+        }
+    }
+
+    fn evaluate_mapping_function(
+        &mut self,
+        function: Symbol,
+        arguments: &'a [Expression],
+        span: Span,
+    ) -> Result<Value> {
+        // The first argument names the mapping, rather than evaluating to a value.
+        let mapping = match &arguments[0] {
+            Expression::Identifier(mapping) => mapping,
+            _ => unreachable!("Type checking guarantees that the first argument is a mapping."),
+        };
+        let program = self.frame().program;
+        let key = self.evaluate(&arguments[1])?;
+        let entry = to_plaintext(&key, &self.structs, span)?.to_string();
+        let entries = self.mappings.entry((program, mapping.name)).or_default();
+
+        match function {
+            sym::get => entries
+                .get(&entry)
+                .cloned()
+                .ok_or_else(|| InterpreterError::mapping_key_not_found(mapping, format_value(&key), span).into()),
+            sym::get_or_use => {
+                let value = entries.get(&entry).cloned();
+                match value {
+                    Some(value) => Ok(value),
+                    None => self.evaluate(&arguments[2]),
+                }
+            }
+            sym::set => {
+                let value = self.evaluate(&arguments[2])?;
+                self.mappings.entry((program, mapping.name)).or_default().insert(entry, value);
+                Ok(Value::Tuple(Vec::new()))
+            }
+            sym::remove => {
+                entries.shift_remove(&entry);
+                Ok(Value::Tuple(Vec::new()))
+            }
+            sym::contains => Ok(Value::Boolean(entries.contains_key(&entry), span)),
+            _ => Err(InterpreterError::unsupported(format!("`Mapping::{function}`"), span).into()),
+        }
+    }
+
+    fn hash(&self, module: Symbol, input: &Value, output: &str, span: Span) -> Result<Value> {
+        let operation = format!("{module}::hash_to_{output}");
+        let output_type = literal_type_from_suffix(output, span)?;
+        let input = to_plaintext(input, &self.structs, span)?;
+        let bits = input.to_bits_le();
+
+        // As in snarkVM, addresses and groups are derived from a hash to a group element,
+        // and the other types from a hash to a field element.
+        let to_group = matches!(output_type, LiteralType::Address | LiteralType::Group);
+        let hash = match module {
+            sym::BHP256 => bhp(256, &bits, to_group),
+            sym::BHP512 => bhp(512, &bits, to_group),
+            sym::BHP768 => bhp(768, &bits, to_group),
+            sym::BHP1024 => bhp(1024, &bits, to_group),
+            sym::Keccak256 => N::hash_keccak256(&bits).and_then(|bits| bhp(256, &bits, to_group)),
+            sym::Keccak384 => N::hash_keccak384(&bits).and_then(|bits| bhp(512, &bits, to_group)),
+            sym::Keccak512 => N::hash_keccak512(&bits).and_then(|bits| bhp(512, &bits, to_group)),
+            sym::Pedersen64 if to_group => N::hash_to_group_ped64(&bits).map(Literal::Group),
+            sym::Pedersen64 => N::hash_ped64(&bits).map(Literal::Field),
+            sym::Pedersen128 if to_group => N::hash_to_group_ped128(&bits).map(Literal::Group),
+            sym::Pedersen128 => N::hash_ped128(&bits).map(Literal::Field),
+            sym::Poseidon2 => input.to_fields().and_then(|fields| poseidon(2, &fields, to_group)),
+            sym::Poseidon4 => input.to_fields().and_then(|fields| poseidon(4, &fields, to_group)),
+            sym::Poseidon8 => input.to_fields().and_then(|fields| poseidon(8, &fields, to_group)),
+            sym::SHA3_256 => N::hash_sha3_256(&bits).and_then(|bits| bhp(256, &bits, to_group)),
+            sym::SHA3_384 => N::hash_sha3_384(&bits).and_then(|bits| bhp(512, &bits, to_group)),
+            sym::SHA3_512 => N::hash_sha3_512(&bits).and_then(|bits| bhp(512, &bits, to_group)),
+            _ => return Err(InterpreterError::unsupported(format!("`{operation}`"), span).into()),
+        };
+
+        // The hash is cast to the output type by truncation.
+        let output = hash
+            .and_then(|hash| hash.cast_lossy(output_type))
+            .map_err(|err| InterpreterError::operation_failed(&operation, err, span))?;
+        from_literal(output, span)
+    }
+
+    fn commit(&self, module: Symbol, input: &Value, randomizer: &Value, output: &str, span: Span) -> Result<Value> {
+        let operation = format!("{module}::commit_to_{output}");
+        let output_type = literal_type_from_suffix(output, span)?;
+        let bits = to_plaintext(input, &self.structs, span)?.to_bits_le();
+        let randomizer = match to_literal(randomizer, span)? {
+            Literal::Scalar(randomizer) => randomizer,
+            _ => unreachable!("Type checking guarantees that the randomizer is a scalar."),
+        };
+
+        let to_group = matches!(output_type, LiteralType::Address | LiteralType::Group);
+        let commitment = match module {
+            sym::BHP256 if to_group => N::commit_to_group_bhp256(&bits, &randomizer).map(Literal::Group),
+            sym::BHP256 => N::commit_bhp256(&bits, &randomizer).map(Literal::Field),
+            sym::BHP512 if to_group => N::commit_to_group_bhp512(&bits, &randomizer).map(Literal::Group),
+            sym::BHP512 => N::commit_bhp512(&bits, &randomizer).map(Literal::Field),
+            sym::BHP768 if to_group => N::commit_to_group_bhp768(&bits, &randomizer).map(Literal::Group),
+            sym::BHP768 => N::commit_bhp768(&bits, &randomizer).map(Literal::Field),
+            sym::BHP1024 if to_group => N::commit_to_group_bhp1024(&bits, &randomizer).map(Literal::Group),
+            sym::BHP1024 => N::commit_bhp1024(&bits, &randomizer).map(Literal::Field),
+            sym::Pedersen64 if to_group => N::commit_to_group_ped64(&bits, &randomizer).map(Literal::Group),
+            sym::Pedersen64 => N::commit_ped64(&bits, &randomizer).map(Literal::Field),
+            sym::Pedersen128 if to_group => N::commit_to_group_ped128(&bits, &randomizer).map(Literal::Group),
+            sym::Pedersen128 => N::commit_ped128(&bits, &randomizer).map(Literal::Field),
+            _ => return Err(InterpreterError::unsupported(format!("`{operation}`"), span).into()),
+        };
+
+        let output = commitment
+            .and_then(|commitment| commitment.cast_lossy(output_type))
+            .map_err(|err| InterpreterError::operation_failed(&operation, err, span))?;
+        from_literal(output, span)
+    }
+
+    /// Samples a random value of the given type.
+    /// Unlike on chain, where the randomness is derived from the transition, the values are sampled from the local RNG.
+    fn rand(&self, output: &str, span: Span) -> Result<Value> {
+        let output_type = literal_type_from_suffix(output, span)?;
+        let field = Field::<N>::rand(&mut rand::thread_rng());
+        let output = Literal::Field(field)
+            .cast_lossy(output_type)
+            .map_err(|err| InterpreterError::operation_failed(format!("ChaCha::rand_{output}"), err, span))?;
+        from_literal(output, span)
+    }
+}
+
+/// Hashes the bits with the BHP hash of the given size.
+fn bhp(size: usize, bits: &[bool], to_group: bool) -> ConsoleResult<Literal<N>> {
+    Ok(match (size, to_group) {
+        (256, true) => Literal::Group(N::hash_to_group_bhp256(bits)?),
+        (256, false) => Literal::Field(N::hash_bhp256(bits)?),
+        (512, true) => Literal::Group(N::hash_to_group_bhp512(bits)?),
+        (512, false) => Literal::Field(N::hash_bhp512(bits)?),
+        (768, true) => Literal::Group(N::hash_to_group_bhp768(bits)?),
+        (768, false) => Literal::Field(N::hash_bhp768(bits)?),
+        (_, true) => Literal::Group(N::hash_to_group_bhp1024(bits)?),
+        (_, false) => Literal::Field(N::hash_bhp1024(bits)?),
+    })
+}
+
+/// Hashes the fields with the Poseidon hash of the given rate.
+fn poseidon(rate: usize, fields: &[Field<N>], to_group: bool) -> ConsoleResult<Literal<N>> {
+    Ok(match (rate, to_group) {
+        (2, true) => Literal::Group(N::hash_to_group_psd2(fields)?),
+        (2, false) => Literal::Field(N::hash_psd2(fields)?),
+        (4, true) => Literal::Group(N::hash_to_group_psd4(fields)?),
+        (4, false) => Literal::Field(N::hash_psd4(fields)?),
+        (_, true) => Literal::Group(N::hash_to_group_psd8(fields)?),
+        (_, false) => Literal::Field(N::hash_psd8(fields)?),
+    })
+}
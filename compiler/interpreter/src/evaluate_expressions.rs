@@ -0,0 +1,291 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    cast,
+    evaluate_binary_operation,
+    evaluate_unary_operation,
+    format_value,
+    from_literal,
+    integer_value,
+    values_equal,
+    CurrentNetwork,
+    Hook,
+    Interpreter,
+};
+
+use leo_ast::{AccessExpression, Expression, GroupLiteral, Identifier, MatchPattern, Node, Type, Value};
+use leo_errors::{InterpreterError, Result};
+use leo_span::{sym, Span, Symbol};
+
+use snarkvm_console::{
+    network::prelude::Zero,
+    program::Literal,
+    types::{Address, Group},
+};
+
+use indexmap::IndexMap;
+
+impl<'a, H: Hook> Interpreter<'a, H> {
+    /// Evaluates an expression in the innermost frame.
+    pub(crate) fn evaluate(&mut self, expression: &'a Expression) -> Result<Value> {
+        match expression {
+            Expression::Access(access) => self.evaluate_access(access),
+            Expression::Array(array) => Ok(Value::Array(self.evaluate_all(&array.elements)?)),
+            Expression::Binary(binary) => {
+                let left = self.evaluate(&binary.left)?;
+                let right = self.evaluate(&binary.right)?;
+                evaluate_binary_operation(binary.op, left, right, binary.span)
+            }
+            Expression::Call(call) => {
+                let arguments = self.evaluate_all(&call.arguments)?;
+                let function = match &*call.function {
+                    Expression::Identifier(function) => function.name,
+                    _ => unreachable!("Parsing guarantees that a function name is an identifier."),
+                };
+                match &call.external {
+                    // The caller of a transition of another program is the address of the calling program.
+                    Some(external) => {
+                        let program = match &**external {
+                            Expression::Identifier(program) => program.name,
+                            _ => unreachable!("Parsing guarantees that a program name is an identifier."),
+                        };
+                        let caller = self.program_address(self.frame().program, call.span)?;
+                        self.call_function(program, function, arguments, caller)
+                    }
+                    None => {
+                        let (program, caller) = (self.frame().program, self.frame().caller.clone());
+                        self.call_function(program, function, arguments, caller)
+                    }
+                }
+            }
+            Expression::Cast(cast_) => {
+                let value = self.evaluate(&cast_.expression)?;
+                cast(value, &cast_.type_, cast_.span)
+            }
+            Expression::Err(err) => Err(InterpreterError::unsupported("erroneous expressions", err.span).into()),
+            Expression::Identifier(identifier) => self.evaluate_identifier(identifier),
+            Expression::Literal(literal) => Value::try_from(literal)
+                .map_err(|err| InterpreterError::operation_failed(literal, err, literal.span()).into()),
+            // Every arm is evaluated, as in Aleo instructions, with the payload it binds in a scope of its own.
+            Expression::Match(match_) => {
+                let value = self.evaluate(&match_.expression)?;
+                let mut selected = None;
+                for arm in match_.arms.iter() {
+                    let (is_selected, bindings) = self.match_pattern(&value, &arm.pattern)?;
+                    self.frame_mut().scopes.push(bindings);
+                    let result = self.evaluate(&arm.body);
+                    self.frame_mut().scopes.pop();
+                    let result = result?;
+                    if is_selected && selected.is_none() {
+                        selected = Some(result);
+                    }
+                }
+                Ok(selected.expect("type checking guarantees that the arms of a match are exhaustive"))
+            }
+            // The element is evaluated once and then repeated.
+            Expression::Repeat(repeat) => {
+                let element = self.evaluate(&repeat.expr)?;
+                Ok(Value::Array(vec![element; repeat.count.value()]))
+            }
+            Expression::Struct(struct_) => {
+                let mut members = IndexMap::new();
+                for member in struct_.members.iter() {
+                    // A member without an expression is initialized with the variable of the same name.
+                    let value = match &member.expression {
+                        Some(expression) => self.evaluate(expression)?,
+                        None => self.evaluate_identifier(&member.identifier)?,
+                    };
+                    members.insert(member.identifier.name, value);
+                }
+                Ok(Value::Struct(struct_.name, members))
+            }
+            // Both branches are evaluated, as in Aleo instructions.
+            Expression::Ternary(ternary) => {
+                let condition = self.evaluate_condition(&ternary.condition)?;
+                let if_true = self.evaluate(&ternary.if_true)?;
+                let if_false = self.evaluate(&ternary.if_false)?;
+                Ok(if condition { if_true } else { if_false })
+            }
+            Expression::Tuple(tuple) => Ok(Value::Tuple(self.evaluate_all(&tuple.elements)?)),
+            Expression::Unary(unary) => {
+                let value = self.evaluate(&unary.receiver)?;
+                evaluate_unary_operation(unary.op, value, unary.span)
+            }
+            Expression::Unit(_) => Ok(Value::Tuple(Vec::new())),
+        }
+    }
+
+    /// Evaluates the expressions in order.
+    pub(crate) fn evaluate_all(&mut self, expressions: &'a [Expression]) -> Result<Vec<Value>> {
+        expressions.iter().map(|expression| self.evaluate(expression)).collect()
+    }
+
+    /// Looks up a variable, falling back to the global constants of the program.
+    fn evaluate_identifier(&mut self, identifier: &'a Identifier) -> Result<Value> {
+        if let Some(value) = self.frame().lookup(identifier.name) {
+            return Ok(value.clone());
+        }
+        let program = self.frame().program;
+        match self.programs[&program].consts.iter().find(|(name, _)| *name == identifier.name) {
+            Some((_, declaration)) => self.evaluate(&declaration.value),
+            None => Err(InterpreterError::undefined_variable(identifier, identifier.span).into()),
+        }
+    }
+
+    fn evaluate_access(&mut self, access: &'a AccessExpression) -> Result<Value> {
+        match access {
+            AccessExpression::Array(access) => {
+                let array = self.evaluate(&access.array)?;
+                let index = self.evaluate(&access.index)?;
+                let index = u128::try_from(&index)?;
+                match array {
+                    Value::Array(mut values) => match usize::try_from(index).ok().filter(|index| *index < values.len())
+                    {
+                        Some(index) => Ok(values.swap_remove(index)),
+                        None => {
+                            Err(InterpreterError::array_index_out_of_bounds(index, values.len(), access.span).into())
+                        }
+                    },
+                    _ => unreachable!("Type checking guarantees that only arrays are indexed."),
+                }
+            }
+            AccessExpression::AssociatedConstant(constant) => match (&constant.ty, constant.name.name) {
+                (Type::Group, sym::GEN) => {
+                    from_literal(Literal::Group(Group::<CurrentNetwork>::generator()), constant.span)
+                }
+                (Type::Identifier(name), variant) if self.enums.contains_key(&name.name) => {
+                    Ok(self.variant_value(*name, variant, None, constant.span))
+                }
+                _ => Err(InterpreterError::unsupported(format!("`{constant}`"), constant.span).into()),
+            },
+            AccessExpression::AssociatedFunction(function) => match &function.ty {
+                Type::Identifier(name) if self.enums.contains_key(&name.name) => {
+                    let payload = self.evaluate_all(&function.arguments)?.into_iter().next();
+                    Ok(self.variant_value(*name, function.name.name, payload, function.span))
+                }
+                _ => self.evaluate_core_function(function),
+            },
+            AccessExpression::Member(access) => match &*access.inner {
+                Expression::Identifier(identifier) if identifier.name == sym::SelfLower => match access.name.name {
+                    sym::caller => Ok(self.frame().caller.clone()),
+                    sym::signer => Ok(self.signer.clone()),
+                    _ => Err(InterpreterError::unsupported(format!("`{access}`"), access.span).into()),
+                },
+                Expression::Identifier(identifier) if identifier.name == sym::block => match access.name.name {
+                    sym::height => Ok(Value::U32(self.block_height, access.span)),
+                    _ => Err(InterpreterError::unsupported(format!("`{access}`"), access.span).into()),
+                },
+                inner => match self.evaluate(inner)? {
+                    Value::Struct(_, mut members) => members
+                        .shift_remove(&access.name.name)
+                        .ok_or_else(|| InterpreterError::undefined_variable(access, access.span).into()),
+                    value => Err(InterpreterError::unsupported(
+                        format!("accessing `{}` of `{}`", access.name, format_value(&value)),
+                        access.span,
+                    )
+                    .into()),
+                },
+            },
+            AccessExpression::Tuple(access) => match self.evaluate(&access.tuple)? {
+                Value::Tuple(mut values) if access.index.value() < values.len() => {
+                    Ok(values.swap_remove(access.index.value()))
+                }
+                _ => unreachable!("Type checking guarantees that tuple indices are in bounds."),
+            },
+        }
+    }
+
+    /// Returns the value of a variant of an enum, in the layout of the struct the enum is lowered to.
+    /// The payloads of the other variants hold default values.
+    fn variant_value(&self, name: Identifier, variant: Symbol, payload: Option<Value>, span: Span) -> Value {
+        let enum_ = self.enums[&name.name];
+        let (tag, _) = enum_.variant(variant).expect("type checking guarantees that the variant exists");
+        let mut payload = payload;
+        let mut members = IndexMap::from([(sym::tag, Value::U8(tag as u8, span))]);
+        for (index, variant) in enum_.variants.iter().enumerate() {
+            if let Some(type_) = &variant.payload {
+                let value = if index == tag { payload.take() } else { None };
+                members.insert(variant.name(), value.unwrap_or_else(|| self.default_value(type_, span)));
+            }
+        }
+        Value::Struct(name, members)
+    }
+
+    /// Returns the default value of the type, which fills the payloads of the inactive variants of an enum.
+    fn default_value(&self, type_: &Type, span: Span) -> Value {
+        match type_ {
+            Type::Address => Value::Address(Address::<CurrentNetwork>::new(Group::zero()).to_string(), span),
+            Type::Boolean => Value::Boolean(false, span),
+            Type::Field => Value::Field("0".to_string(), span),
+            Type::Group => Value::Group(Box::new(GroupLiteral::Single("0".to_string(), span, Default::default()))),
+            Type::Integer(integer_type) => integer_value(integer_type, 0, span),
+            Type::Scalar => Value::Scalar("0".to_string(), span),
+            Type::Array(array_type) => {
+                Value::Array(vec![self.default_value(array_type.element_type(), span); array_type.length()])
+            }
+            Type::Identifier(name) => {
+                let declaration = &self.structs[&name.name];
+                let members = declaration
+                    .members
+                    .iter()
+                    .map(|member| (member.name(), self.default_value(&member.type_, span)))
+                    .collect();
+                Value::Struct(*name, members)
+            }
+            _ => unreachable!("Type checking guarantees that the payload of a variant has a default value."),
+        }
+    }
+
+    /// Returns whether the pattern matches the value, along with the variable it binds to the payload, if any.
+    /// The variable is bound even if the pattern does not match, to the payload held by the value.
+    pub(crate) fn match_pattern(
+        &mut self,
+        value: &Value,
+        pattern: &'a MatchPattern,
+    ) -> Result<(bool, IndexMap<Symbol, Value>)> {
+        match (value, pattern) {
+            (_, MatchPattern::Wildcard(_)) => Ok((true, IndexMap::new())),
+            (_, MatchPattern::Constant(expression)) => {
+                let constant = self.evaluate(expression)?;
+                Ok((values_equal(value, &constant, expression.span())?, IndexMap::new()))
+            }
+            (_, MatchPattern::Or(patterns, _)) => {
+                for pattern in patterns.iter() {
+                    if self.match_pattern(value, pattern)?.0 {
+                        return Ok((true, IndexMap::new()));
+                    }
+                }
+                Ok((false, IndexMap::new()))
+            }
+            (Value::Struct(name, members), MatchPattern::Variant(pattern)) => {
+                let (tag, _) = self.enums[&name.name]
+                    .variant(pattern.variant.name)
+                    .expect("type checking guarantees that the variant exists");
+                let is_selected = matches!(members.get(&sym::tag), Some(Value::U8(value, _)) if *value as usize == tag);
+                let bindings = pattern
+                    .binding
+                    .iter()
+                    .filter_map(|binding| {
+                        members.get(&pattern.variant.name).map(|payload| (binding.name, payload.clone()))
+                    })
+                    .collect();
+                Ok((is_selected, bindings))
+            }
+            _ => unreachable!("Type checking guarantees that variants are only matched against enums."),
+        }
+    }
+}
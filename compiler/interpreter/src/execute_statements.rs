@@ -0,0 +1,263 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{format_value, integer_value, values_equal, Hook, Interpreter};
+
+use leo_ast::{
+    AccessExpression,
+    ArrayIterationStatement,
+    AssertVariant,
+    Block,
+    ConsoleFunction,
+    Expression,
+    IterationStatement,
+    Node,
+    Statement,
+    Type,
+    Value,
+};
+use leo_errors::{InterpreterError, Result};
+use leo_span::Span;
+
+use indexmap::IndexMap;
+
+/// How the execution of a statement ended.
+pub(crate) enum Flow {
+    /// Execution continues with the next statement.
+    Next,
+    /// A `break` statement was reached.
+    Break,
+    /// A `continue` statement was reached.
+    Continue,
+    /// A `return` statement was reached, with the value it returns.
+    Return(Value),
+}
+
+impl<'a, H: Hook> Interpreter<'a, H> {
+    /// Executes a block in a new scope.
+    /// Returns how the execution of the block ended, e.g. with the value of the `return` statement that was reached.
+    pub(crate) fn execute_block(&mut self, block: &'a Block) -> Result<Flow> {
+        self.frame_mut().scopes.push(IndexMap::new());
+        let result = self.execute_statements(&block.statements);
+        self.frame_mut().scopes.pop();
+        result
+    }
+
+    fn execute_statements(&mut self, statements: &'a [Statement]) -> Result<Flow> {
+        for statement in statements.iter() {
+            match self.execute_statement(statement)? {
+                Flow::Next => {}
+                flow => return Ok(flow),
+            }
+        }
+        Ok(Flow::Next)
+    }
+
+    fn execute_statement(&mut self, statement: &'a Statement) -> Result<Flow> {
+        // Blocks are not reported to the hook, since their statements are.
+        if !matches!(statement, Statement::Block(_)) {
+            self.hook.before_statement(statement, &self.frames)?;
+        }
+
+        match statement {
+            Statement::ArrayIteration(iteration) => return self.execute_array_iteration(iteration),
+            Statement::Assert(assert) => match &assert.variant {
+                AssertVariant::Assert(condition) => self.assert(condition, assert.span)?,
+                AssertVariant::AssertEq(left, right) => self.assert_eq(left, right, true, assert.span)?,
+                AssertVariant::AssertNeq(left, right) => self.assert_eq(left, right, false, assert.span)?,
+            },
+            Statement::Assign(assign) => {
+                let value = self.evaluate(&assign.value)?;
+                *self.place_mut(&assign.place)? = value;
+            }
+            Statement::Block(block) => return self.execute_block(block),
+            Statement::Break(_) => return Ok(Flow::Break),
+            Statement::Conditional(conditional) => {
+                return match self.evaluate_condition(&conditional.condition)? {
+                    true => self.execute_block(&conditional.then),
+                    false => match &conditional.otherwise {
+                        Some(otherwise) => self.execute_statement(otherwise),
+                        None => Ok(Flow::Next),
+                    },
+                };
+            }
+            Statement::Console(console) => match &console.function {
+                ConsoleFunction::Assert(condition) => self.assert(condition, console.span)?,
+                ConsoleFunction::AssertEq(left, right) => self.assert_eq(left, right, true, console.span)?,
+                ConsoleFunction::AssertNeq(left, right) => self.assert_eq(left, right, false, console.span)?,
+                ConsoleFunction::Log(format, args) => {
+                    let args = self.evaluate_all(args)?;
+                    self.hook.log(&format_log(format, &args), console.span);
+                }
+            },
+            Statement::Const(declaration) => {
+                let value = self.evaluate(&declaration.value)?;
+                self.frame_mut().define(declaration.place.name, value);
+            }
+            Statement::Continue(_) => return Ok(Flow::Continue),
+            Statement::Definition(definition) => {
+                let value = self.evaluate(&definition.value)?;
+                self.define(&definition.place, value)?;
+            }
+            Statement::Expression(statement) => {
+                self.evaluate(&statement.expression)?;
+            }
+            Statement::Iteration(iteration) => return self.execute_iteration(iteration),
+            // Only the first arm whose pattern matches is executed, with the payload it binds in a scope of its own.
+            Statement::Match(match_) => {
+                let value = self.evaluate(&match_.expression)?;
+                for arm in match_.arms.iter() {
+                    if let (true, bindings) = self.match_pattern(&value, &arm.pattern)? {
+                        self.frame_mut().scopes.push(bindings);
+                        let result = self.execute_block(&arm.body);
+                        self.frame_mut().scopes.pop();
+                        return result;
+                    }
+                }
+                unreachable!("Type checking guarantees that the arms of a match are exhaustive.");
+            }
+            Statement::Return(return_) => {
+                let value = self.evaluate(&return_.expression)?;
+                if let Some(arguments) = &return_.finalize_arguments {
+                    let arguments = arguments.iter().map(|argument| self.evaluate(argument)).collect::<Result<_>>()?;
+                    self.frame_mut().finalize_arguments = Some(arguments);
+                }
+                return Ok(Flow::Return(value));
+            }
+        }
+        Ok(Flow::Next)
+    }
+
+    fn execute_iteration(&mut self, iteration: &'a IterationStatement) -> Result<Flow> {
+        let integer_type = match &iteration.type_ {
+            Type::Integer(integer_type) => integer_type,
+            _ => unreachable!("Type checking guarantees that the loop variable is an integer."),
+        };
+        let start = i128::try_from(self.evaluate(&iteration.start)?)?;
+        let stop = i128::try_from(self.evaluate(&iteration.stop)?)?;
+        let stop = if iteration.inclusive { stop.saturating_add(1) } else { stop };
+
+        for index in start..stop {
+            // The loop variable is defined in a scope of its own, which encloses the scope of the block.
+            let variable = integer_value(integer_type, index, iteration.variable.span);
+            self.frame_mut().scopes.push(IndexMap::from([(iteration.variable.name, variable)]));
+            let result = self.execute_block(&iteration.block);
+            self.frame_mut().scopes.pop();
+            match result? {
+                Flow::Break => break,
+                Flow::Return(value) => return Ok(Flow::Return(value)),
+                Flow::Next | Flow::Continue => {}
+            }
+        }
+        Ok(Flow::Next)
+    }
+
+    /// Executes the block of a loop over an array once for each element.
+    fn execute_array_iteration(&mut self, iteration: &'a ArrayIterationStatement) -> Result<Flow> {
+        let elements = match self.evaluate(&iteration.array)? {
+            Value::Array(elements) => elements,
+            _ => unreachable!("Type checking guarantees that only arrays are iterated over."),
+        };
+
+        for element in elements {
+            // The loop variable is defined in a scope of its own, which encloses the scope of the block.
+            self.frame_mut().scopes.push(IndexMap::from([(iteration.variable.name, element)]));
+            let result = self.execute_block(&iteration.block);
+            self.frame_mut().scopes.pop();
+            match result? {
+                Flow::Break => break,
+                Flow::Return(value) => return Ok(Flow::Return(value)),
+                Flow::Next | Flow::Continue => {}
+            }
+        }
+        Ok(Flow::Next)
+    }
+
+    /// Defines the variables of a definition statement, destructuring tuples.
+    fn define(&mut self, place: &'a Expression, value: Value) -> Result<()> {
+        match (place, value) {
+            (Expression::Identifier(identifier), value) => self.frame_mut().define(identifier.name, value),
+            (Expression::Tuple(tuple), Value::Tuple(values)) => {
+                for (element, value) in tuple.elements.iter().zip(values) {
+                    self.define(element, value)?;
+                }
+            }
+            (place, _) => {
+                return Err(InterpreterError::unsupported(format!("defining `{place}`"), place.span()).into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns a mutable reference to the value at the place of an assignment.
+    fn place_mut(&mut self, place: &'a Expression) -> Result<&mut Value> {
+        let undefined = || InterpreterError::undefined_variable(place, place.span());
+        match place {
+            Expression::Identifier(identifier) => {
+                self.frame_mut().lookup_mut(identifier.name).ok_or_else(|| undefined().into())
+            }
+            Expression::Access(AccessExpression::Member(access)) => match self.place_mut(&access.inner)? {
+                Value::Struct(_, members) => members.get_mut(&access.name.name).ok_or_else(|| undefined().into()),
+                _ => Err(undefined().into()),
+            },
+            Expression::Access(AccessExpression::Tuple(access)) => match self.place_mut(&access.tuple)? {
+                Value::Tuple(values) => values.get_mut(access.index.value()).ok_or_else(|| undefined().into()),
+                _ => Err(undefined().into()),
+            },
+            _ => Err(InterpreterError::unsupported(format!("assigning to `{place}`"), place.span()).into()),
+        }
+    }
+
+    /// Evaluates the condition of a statement.
+    pub(crate) fn evaluate_condition(&mut self, condition: &'a Expression) -> Result<bool> {
+        match self.evaluate(condition)? {
+            Value::Boolean(boolean, _) => Ok(boolean),
+            _ => unreachable!("Type checking guarantees that conditions are booleans."),
+        }
+    }
+
+    fn assert(&mut self, condition: &'a Expression, span: Span) -> Result<()> {
+        match self.evaluate_condition(condition)? {
+            true => Ok(()),
+            false => Err(InterpreterError::assertion_failed(condition, span).into()),
+        }
+    }
+
+    fn assert_eq(&mut self, left: &'a Expression, right: &'a Expression, expected: bool, span: Span) -> Result<()> {
+        let left = self.evaluate(left)?;
+        let right = self.evaluate(right)?;
+        match (values_equal(&left, &right, span)?, expected) {
+            (true, true) | (false, false) => Ok(()),
+            (false, true) => {
+                Err(InterpreterError::assert_eq_failed(format_value(&left), format_value(&right), span).into())
+            }
+            (true, false) => {
+                Err(InterpreterError::assert_neq_failed(format_value(&left), format_value(&right), span).into())
+            }
+        }
+    }
+}
+
+/// Returns the format string of a `console.log` statement with each `{}` replaced by an argument.
+fn format_log(format: &str, args: &[Value]) -> String {
+    let mut parts = format.split("{}");
+    let mut message = parts.next().unwrap_or_default().to_string();
+    for (part, arg) in parts.zip(args.iter()) {
+        message.push_str(&format_value(arg));
+        message.push_str(part);
+    }
+    message
+}
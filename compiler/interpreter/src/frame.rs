@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::Value;
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+/// A call of a function, transition, or finalize block being evaluated by the interpreter.
+/// A call on the call stack of the interpreter.
+#[derive(Clone, Debug)]
+pub struct Frame {
+    /// The name of the program that defines the function.
+    pub program: Symbol,
+    /// The name of the function.
+    pub function: Symbol,
+    /// Is this the finalize block of the function?
+    pub is_finalize: bool,
+    /// The address of the caller of the function, i.e. `self.caller`.
+    pub caller: Value,
+    /// The variables in scope, from the outermost to the innermost scope.
+    pub(crate) scopes: Vec<IndexMap<Symbol, Value>>,
+    /// The arguments of the finalize block, once the function returns.
+    pub(crate) finalize_arguments: Option<Vec<Value>>,
+}
+
+impl Frame {
+    /// Returns a new frame, whose outermost scope holds the inputs of the function.
+    pub(crate) fn new(
+        program: Symbol,
+        function: Symbol,
+        is_finalize: bool,
+        caller: Value,
+        inputs: IndexMap<Symbol, Value>,
+    ) -> Self {
+        Self { program, function, is_finalize, caller, scopes: vec![inputs], finalize_arguments: None }
+    }
+
+    /// Returns the value of the innermost variable with the given name.
+    pub fn lookup(&self, name: Symbol) -> Option<&Value> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(&name))
+    }
+
+    /// Returns a mutable reference to the value of the innermost variable with the given name.
+    pub(crate) fn lookup_mut(&mut self, name: Symbol) -> Option<&mut Value> {
+        self.scopes.iter_mut().rev().find_map(|scope| scope.get_mut(&name))
+    }
+
+    /// Returns the variables in scope, in the order they were defined.
+    /// Variables shadowed by an inner scope are omitted.
+    pub fn locals(&self) -> IndexMap<Symbol, &Value> {
+        let mut locals = IndexMap::new();
+        for scope in self.scopes.iter() {
+            for (name, value) in scope.iter() {
+                // Remove the shadowed variable first, so that the variable moves to its new position.
+                locals.shift_remove(name);
+                locals.insert(*name, value);
+            }
+        }
+        locals
+    }
+
+    /// Defines a variable in the innermost scope.
+    pub(crate) fn define(&mut self, name: Symbol, value: Value) {
+        self.scopes.last_mut().expect("a frame always has a scope").insert(name, value);
+    }
+}
@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Frame;
+
+use leo_ast::Statement;
+use leo_errors::Result;
+use leo_span::{symbol::with_session_globals, Span};
+
+/// A hook called by the interpreter as it evaluates a program.
+/// Observes the execution of the interpreter, e.g. to pause at breakpoints.
+pub trait Hook {
+    /// Called before the interpreter executes `statement`, with the call stack of the interpreter.
+    /// The innermost call is the last frame.
+    /// Returning an error stops the execution.
+    fn before_statement(&mut self, statement: &Statement, frames: &[Frame]) -> Result<()>;
+
+    /// Called with the message of a `console.log` statement.
+    /// By default, the message is printed along with the source location of the statement.
+    fn log(&mut self, message: &str, span: Span) {
+        match with_session_globals(|s| s.source_map.span_to_location(span)) {
+            Some(location) => println!("{}:{}: {message}", location.source_file.name, location.line_start),
+            None => println!("{message}"),
+        }
+    }
+}
+
+/// Runs the program without interruption.
+impl Hook for () {
+    fn before_statement(&mut self, _: &Statement, _: &[Frame]) -> Result<()> {
+        Ok(())
+    }
+}
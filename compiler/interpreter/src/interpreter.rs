@@ -0,0 +1,266 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{execute_statements::Flow, from_plaintext, literal_type, to_plaintext, CurrentNetwork, Frame, Hook};
+
+use leo_ast::{Enum, Function, NodeBuilder, Program, ProgramScope, Struct, Type, Value};
+use leo_errors::{InterpreterError, Result};
+use leo_span::{Span, Symbol};
+
+use snarkvm_console::{
+    network::prelude::Zero,
+    program::{Plaintext, ProgramID},
+    types::{Address, Group},
+};
+
+use indexmap::IndexMap;
+use std::str::FromStr;
+
+/// The entries of every mapping, by program and mapping name.
+/// The keys are in the Aleo plaintext syntax, e.g. `1u32` or `{ x: 1u32, y: 2u32 }`.
+pub type Mappings = IndexMap<(Symbol, Symbol), IndexMap<String, Value>>;
+
+/// Evaluates the transitions of a type checked program, along with their finalize blocks.
+pub struct Interpreter<'a, H: Hook = ()> {
+    /// The name of the main program.
+    main: Symbol,
+    /// The program scopes of the main program and its imports, by program name.
+    pub(crate) programs: IndexMap<Symbol, &'a ProgramScope>,
+    /// The structs and records of every program, by name.
+    /// Enums are included as the structs they are lowered to, so that their values have the same layout.
+    pub(crate) structs: IndexMap<Symbol, Struct>,
+    /// The enums of every program, by name.
+    pub(crate) enums: IndexMap<Symbol, &'a Enum>,
+    /// The state of the mappings.
+    pub mappings: Mappings,
+    /// The address that signs the transitions, i.e. `self.signer`.
+    pub signer: Value,
+    /// The height of the block the finalize blocks are evaluated in, i.e. `block.height`.
+    pub block_height: u32,
+    /// The call stack.
+    pub(crate) frames: Vec<Frame>,
+    /// The finalize blocks to evaluate once the transition completes, along with their arguments.
+    pub(crate) finalizes: Vec<(Symbol, &'a Function, Vec<Value>)>,
+    /// Called before each statement is executed.
+    pub hook: H,
+}
+
+impl<'a, H: Hook> Interpreter<'a, H> {
+    /// Returns a new interpreter for the type checked program, with empty mappings.
+    pub fn new(program: &'a Program, hook: H) -> Self {
+        let mut programs = IndexMap::new();
+        collect_programs(program, &mut programs);
+        let main = program.program_scopes.values().next().expect("a program has a program scope").program_id.name.name;
+
+        // Enums are lowered with a throwaway node builder, since the node IDs of the structs are never used.
+        let node_builder = NodeBuilder::default();
+        let structs = programs
+            .values()
+            .flat_map(|scope| {
+                let structs = scope.structs.iter().map(|(name, declaration)| (*name, declaration.clone()));
+                let enums = scope.enums.iter().map(|(name, declaration)| (*name, declaration.to_struct(&node_builder)));
+                structs.chain(enums)
+            })
+            .collect();
+        let enums = programs
+            .values()
+            .flat_map(|scope| scope.enums.iter().map(|(name, declaration)| (*name, declaration)))
+            .collect();
+        let mappings = programs
+            .iter()
+            .flat_map(|(program, scope)| scope.mappings.iter().map(|(name, _)| ((*program, *name), IndexMap::new())))
+            .collect();
+
+        // The transitions are signed by the address of the zero group element, unless another signer is set.
+        let signer = Value::Address(Address::<CurrentNetwork>::new(Group::zero()).to_string(), Span::default());
+
+        Self {
+            main,
+            programs,
+            structs,
+            enums,
+            mappings,
+            signer,
+            block_height: 0,
+            frames: Vec::new(),
+            finalizes: Vec::new(),
+            hook,
+        }
+    }
+
+    /// Returns the name of the main program.
+    pub fn main_program(&self) -> Symbol {
+        self.main
+    }
+
+    /// Returns the transitions of the main program.
+    pub fn transitions(&self) -> impl Iterator<Item = &'a Function> {
+        self.programs[&self.main].functions.iter().map(|(_, function)| function).filter(|function| function.is_main())
+    }
+
+    /// Returns the function with the given name in the given program.
+    pub fn function(&self, program: Symbol, name: Symbol) -> Result<&'a Function> {
+        self.programs
+            .get(&program)
+            .and_then(|scope| scope.functions.iter().find(|(function, _)| *function == name))
+            .map(|(_, function)| function)
+            .ok_or_else(|| InterpreterError::function_not_found(program, name).into())
+    }
+
+    /// Parses the arguments of a function of the main program, given in the Aleo syntax.
+    /// Literal arguments may omit their type suffix, e.g. `1` for a `u32` argument.
+    pub fn parse_arguments(&self, function: Symbol, arguments: &[String]) -> Result<Vec<Value>> {
+        let function = self.function(self.main, function)?;
+        if function.input.len() != arguments.len() {
+            return Err(InterpreterError::wrong_number_of_arguments(
+                function.name(),
+                function.input.len(),
+                arguments.len(),
+            )
+            .into());
+        }
+        function
+            .input
+            .iter()
+            .zip(arguments.iter())
+            .map(|(input, argument)| self.parse_argument(argument, &input.type_()))
+            .collect()
+    }
+
+    /// Sets an entry of a mapping, with the key and the value given in the Aleo syntax.
+    /// This starts the interpreter from existing mapping state, e.g. that of a local ledger.
+    /// Entries of mappings which the program does not declare are ignored, since they are never read.
+    pub fn set_mapping_entry(&mut self, program: Symbol, mapping: Symbol, key: &str, value: &str) -> Result<()> {
+        let declaration = self
+            .programs
+            .get(&program)
+            .and_then(|scope| scope.mappings.iter().find(|(name, _)| *name == mapping))
+            .map(|(_, declaration)| declaration);
+        let Some(declaration) = declaration else {
+            return Ok(());
+        };
+        let key = self.parse_argument(key, &declaration.key_type)?;
+        let value = self.parse_argument(value, &declaration.value_type)?;
+        // Note that the key is formatted as the interpreter formats the keys it sets.
+        let entry = to_plaintext(&key, &self.structs, Span::default())?.to_string();
+        self.mappings.entry((program, mapping)).or_default().insert(entry, value);
+        Ok(())
+    }
+
+    /// Parses an argument of the given type.
+    fn parse_argument(&self, argument: &str, type_: &Type) -> Result<Value> {
+        let invalid = |error: &dyn std::fmt::Display| InterpreterError::invalid_argument(argument, type_, error);
+        let plaintext = match (Plaintext::<CurrentNetwork>::from_str(argument), literal_type(type_)) {
+            (Ok(plaintext), _) => plaintext,
+            // Literal arguments may omit their type suffix.
+            (Err(error), Some(literal_type)) => {
+                Plaintext::from_str(&format!("{argument}{literal_type}")).map_err(|_| invalid(&error))?
+            }
+            (Err(error), None) => return Err(invalid(&error).into()),
+        };
+        from_plaintext(&plaintext, type_, &self.structs).map_err(|error| invalid(&error).into())
+    }
+
+    /// Calls a transition of the main program, then evaluates the finalize blocks of the transitions it called.
+    /// If a finalize block fails, the mappings are left unchanged.
+    pub fn call(&mut self, function: Symbol, arguments: Vec<Value>) -> Result<Value> {
+        self.frames.clear();
+        self.finalizes.clear();
+
+        let output = self.call_function(self.main, function, arguments, self.signer.clone())?;
+
+        // As on chain, the finalize blocks are evaluated in the order their transitions complete.
+        let mappings = self.mappings.clone();
+        for (program, function, arguments) in std::mem::take(&mut self.finalizes) {
+            if let Err(error) = self.call_finalize(program, function, arguments) {
+                self.mappings = mappings;
+                return Err(error);
+            }
+        }
+        self.block_height += 1;
+
+        Ok(output)
+    }
+
+    /// Calls a function, queueing its finalize block if it has one.
+    pub(crate) fn call_function(
+        &mut self,
+        program: Symbol,
+        name: Symbol,
+        arguments: Vec<Value>,
+        caller: Value,
+    ) -> Result<Value> {
+        let function = self.function(program, name)?;
+        if function.input.len() != arguments.len() {
+            return Err(InterpreterError::wrong_number_of_arguments(name, function.input.len(), arguments.len()).into());
+        }
+        let inputs = function.input.iter().map(|input| input.identifier().name).zip(arguments).collect();
+
+        self.frames.push(Frame::new(program, name, false, caller, inputs));
+        let result = self.execute_block(&function.block);
+        let frame = self.frames.pop().expect("the frame was pushed above");
+        // Type checking guarantees that `break` and `continue` statements are inside of a loop.
+        let output = match result? {
+            Flow::Return(value) => value,
+            _ => Value::Tuple(Vec::new()),
+        };
+
+        if let (Some(_), Some(arguments)) = (&function.finalize, frame.finalize_arguments) {
+            self.finalizes.push((program, function, arguments));
+        }
+        Ok(output)
+    }
+
+    /// Evaluates the finalize block of a function.
+    fn call_finalize(&mut self, program: Symbol, function: &'a Function, arguments: Vec<Value>) -> Result<()> {
+        let finalize = function.finalize.as_ref().expect("only functions with a finalize block are queued");
+        let inputs = finalize.input.iter().map(|input| input.identifier().name).zip(arguments).collect();
+
+        // The caller is not accessible in a finalize block.
+        self.frames.push(Frame::new(program, function.name(), true, self.signer.clone(), inputs));
+        let result = self.execute_block(&finalize.block);
+        self.frames.pop();
+        result.map(|_| ())
+    }
+
+    /// Returns the address of a program, i.e. the caller of the transitions it calls.
+    pub(crate) fn program_address(&self, program: Symbol, span: Span) -> Result<Value> {
+        let address = ProgramID::<CurrentNetwork>::from_str(&format!("{program}.aleo"))
+            .and_then(|program_id| program_id.to_address())
+            .map_err(|err| InterpreterError::operation_failed(format!("the address of `{program}.aleo`"), err, span))?;
+        Ok(Value::Address(address.to_string(), span))
+    }
+
+    /// Returns the innermost frame.
+    pub(crate) fn frame(&self) -> &Frame {
+        self.frames.last().expect("the interpreter is evaluating a function")
+    }
+
+    /// Returns the innermost frame mutably.
+    pub(crate) fn frame_mut(&mut self) -> &mut Frame {
+        self.frames.last_mut().expect("the interpreter is evaluating a function")
+    }
+}
+
+/// Collects the program scopes of the program and of its imports.
+fn collect_programs<'a>(program: &'a Program, programs: &mut IndexMap<Symbol, &'a ProgramScope>) {
+    for (import, _) in program.imports.values() {
+        collect_programs(import, programs);
+    }
+    for scope in program.program_scopes.values() {
+        programs.insert(scope.program_id.name.name, scope);
+    }
+}
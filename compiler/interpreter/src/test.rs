@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{format_value, Frame, Hook, Interpreter};
+
+use leo_ast::{NodeBuilder, Statement};
+use leo_errors::{emitter::Handler, LeoError};
+use leo_passes::{Pass, SymbolTableCreator, TypeAliasResolver, TypeChecker, TypeTable};
+use leo_span::{
+    source_map::FileName,
+    symbol::{create_session_if_not_set_then, SessionGlobals},
+    Span,
+    Symbol,
+};
+use leo_test_framework::{
+    runner::{Namespace, ParseType, Runner},
+    Test,
+};
+use serde_yaml::{Mapping, Value};
+
+struct InterpretNamespace;
+
+impl Namespace for InterpretNamespace {
+    fn parse_type(&self) -> ParseType {
+        ParseType::Whole
+    }
+
+    fn run_test(&self, test: Test) -> Result<Value, String> {
+        create_session_if_not_set_then(|s| {
+            let (handler, buf) = Handler::new_with_buf();
+            handler
+                .extend_if_error(interpret(&handler, &test, s))
+                .map_err(|_| buf.extract_errs().to_string() + &buf.extract_warnings().to_string())
+        })
+    }
+}
+
+/// Records the messages of the `console.log` statements, instead of printing them.
+#[derive(Default)]
+struct LogRecorder(Vec<String>);
+
+impl Hook for LogRecorder {
+    fn before_statement(&mut self, _: &Statement, _: &[Frame]) -> leo_errors::Result<()> {
+        Ok(())
+    }
+
+    fn log(&mut self, message: &str, _: Span) {
+        self.0.push(message.to_string());
+    }
+}
+
+/// Type checks the program of the test, then calls its functions with the inputs of each case, in order.
+/// Errors raised by a call are part of the output of the case, so that a passing test may expect them.
+fn interpret(handler: &Handler, test: &Test, s: &SessionGlobals) -> Result<Value, LeoError> {
+    let source_file = s.source_map.new_source(&test.content, FileName::Custom("interpreter-test".into()));
+    let node_builder = NodeBuilder::default();
+    let ast = leo_parser::parse_ast(handler, &node_builder, &source_file.src, source_file.start_pos)?;
+    let symbol_table = SymbolTableCreator::do_pass((&ast, handler))?;
+    let (ast, symbol_table) = TypeAliasResolver::do_pass((ast, handler, &node_builder, symbol_table))?;
+    TypeChecker::do_pass((&ast, handler, symbol_table, &TypeTable::default()))?;
+
+    let cases = test
+        .config
+        .extra
+        .get("cases")
+        .and_then(Value::as_mapping)
+        .expect("An `Interpret` config must have a `cases` field.");
+
+    let mut interpreter = Interpreter::new(ast.as_repr(), LogRecorder::default());
+    let mut results = Mapping::new();
+    for (function_name, function_cases) in cases {
+        let function = Symbol::intern(function_name.as_str().unwrap());
+        let mut function_results = Vec::new();
+
+        for case in function_cases.as_sequence().unwrap() {
+            let inputs: Vec<String> =
+                case["input"].as_sequence().unwrap().iter().map(|input| input.as_str().unwrap().to_string()).collect();
+            let output = match interpreter
+                .parse_arguments(function, &inputs)
+                .and_then(|arguments| interpreter.call(function, arguments))
+            {
+                Ok(value) => format_value(&value),
+                Err(err) => err.to_string(),
+            };
+
+            let mut result = Mapping::new();
+            result.insert("input".into(), format!("[{}]", inputs.join(", ")).into());
+            result.insert("output".into(), output.into());
+            // The messages logged by the case, if any.
+            let logs = std::mem::take(&mut interpreter.hook.0);
+            if !logs.is_empty() {
+                result.insert("logs".into(), logs.into());
+            }
+            function_results.push(Value::Mapping(result));
+        }
+        results.insert(function_name.clone(), Value::Sequence(function_results));
+    }
+
+    // The state of the mappings once every case has been run.
+    let mut mappings = Mapping::new();
+    for ((program, mapping), entries) in interpreter.mappings.iter() {
+        let entries = entries.iter().map(|(key, value)| (key.clone().into(), format_value(value).into())).collect();
+        mappings.insert(format!("{program}.aleo/{mapping}").into(), Value::Mapping(entries));
+    }
+
+    let mut output = Mapping::new();
+    output.insert("results".into(), Value::Mapping(results));
+    output.insert("mappings".into(), Value::Mapping(mappings));
+    Ok(Value::Mapping(output))
+}
+
+struct TestRunner;
+
+impl Runner for TestRunner {
+    fn resolve_namespace(&self, name: &str) -> Option<Box<dyn Namespace>> {
+        Some(match name {
+            "Interpret" => Box::new(InterpretNamespace),
+            _ => return None,
+        })
+    }
+}
+
+#[test]
+pub fn interpreter_tests() {
+    leo_test_framework::run_tests(&TestRunner, "interpreter");
+}
@@ -0,0 +1,378 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::CurrentNetwork;
+
+use leo_ast::{BinaryOperation, GroupLiteral, IntegerType, Struct, Type, UnaryOperation, Value};
+use leo_errors::{InterpreterError, Result};
+use leo_span::{Span, Symbol};
+
+use snarkvm_console::{
+    network::prelude::{Compare, Double, Inverse, Pow, Square, SquareRoot, Zero},
+    program::{Literal, LiteralType, Plaintext},
+};
+
+use indexmap::IndexMap;
+use std::str::FromStr;
+
+/// Returns the value in the Leo syntax, e.g. `1u32` or `Point { x: 1u32, y: 2u32 }`.
+pub fn format_value(value: &Value) -> String {
+    let join = |values: &[Value]| values.iter().map(format_value).collect::<Vec<_>>().join(", ");
+    match value {
+        Value::Input(_, identifier) => identifier.to_string(),
+        Value::Address(address, _) => address.clone(),
+        Value::Boolean(boolean, _) => boolean.to_string(),
+        Value::Struct(name, members) => {
+            let members =
+                members.iter().map(|(name, value)| format!("{name}: {}", format_value(value))).collect::<Vec<_>>();
+            format!("{name} {{ {} }}", members.join(", "))
+        }
+        Value::Field(field, _) => format!("{field}field"),
+        Value::Group(group) => format!("{group}group"),
+        Value::I8(integer, _) => format!("{integer}i8"),
+        Value::I16(integer, _) => format!("{integer}i16"),
+        Value::I32(integer, _) => format!("{integer}i32"),
+        Value::I64(integer, _) => format!("{integer}i64"),
+        Value::I128(integer, _) => format!("{integer}i128"),
+        Value::U8(integer, _) => format!("{integer}u8"),
+        Value::U16(integer, _) => format!("{integer}u16"),
+        Value::U32(integer, _) => format!("{integer}u32"),
+        Value::U64(integer, _) => format!("{integer}u64"),
+        Value::U128(integer, _) => format!("{integer}u128"),
+        Value::Scalar(scalar, _) => format!("{scalar}scalar"),
+        Value::String(string, _) => format!("\"{string}\""),
+        Value::Array(values) => format!("[{}]", join(values)),
+        Value::Tuple(values) => format!("({})", join(values)),
+    }
+}
+
+/// Returns the literal type corresponding to the type, if it is a literal type.
+pub(crate) fn literal_type(type_: &Type) -> Option<LiteralType> {
+    Some(match type_ {
+        Type::Address => LiteralType::Address,
+        Type::Boolean => LiteralType::Boolean,
+        Type::Field => LiteralType::Field,
+        Type::Group => LiteralType::Group,
+        Type::Integer(IntegerType::I8) => LiteralType::I8,
+        Type::Integer(IntegerType::I16) => LiteralType::I16,
+        Type::Integer(IntegerType::I32) => LiteralType::I32,
+        Type::Integer(IntegerType::I64) => LiteralType::I64,
+        Type::Integer(IntegerType::I128) => LiteralType::I128,
+        Type::Integer(IntegerType::U8) => LiteralType::U8,
+        Type::Integer(IntegerType::U16) => LiteralType::U16,
+        Type::Integer(IntegerType::U32) => LiteralType::U32,
+        Type::Integer(IntegerType::U64) => LiteralType::U64,
+        Type::Integer(IntegerType::U128) => LiteralType::U128,
+        Type::Scalar => LiteralType::Scalar,
+        Type::Signature => LiteralType::Signature,
+        Type::String => LiteralType::String,
+        _ => return None,
+    })
+}
+
+/// Returns the literal type named by the suffix of a core function, e.g. `field` in `hash_to_field`.
+pub(crate) fn literal_type_from_suffix(suffix: &str, span: Span) -> Result<LiteralType> {
+    // Leo names booleans `bool`, while Aleo names them `boolean`.
+    let suffix = if suffix == "bool" { "boolean" } else { suffix };
+    LiteralType::from_str(suffix)
+        .map_err(|err| InterpreterError::operation_failed(format!("the output type `{suffix}`"), err, span).into())
+}
+
+/// Returns an integer value of the given type.
+/// The value must lie in the range of the type.
+pub(crate) fn integer_value(type_: &IntegerType, value: i128, span: Span) -> Value {
+    match type_ {
+        IntegerType::I8 => Value::I8(value as i8, span),
+        IntegerType::I16 => Value::I16(value as i16, span),
+        IntegerType::I32 => Value::I32(value as i32, span),
+        IntegerType::I64 => Value::I64(value as i64, span),
+        IntegerType::I128 => Value::I128(value, span),
+        IntegerType::U8 => Value::U8(value as u8, span),
+        IntegerType::U16 => Value::U16(value as u16, span),
+        IntegerType::U32 => Value::U32(value as u32, span),
+        IntegerType::U64 => Value::U64(value as u64, span),
+        IntegerType::U128 => Value::U128(value as u128, span),
+    }
+}
+
+/// Converts a value to an Aleo literal.
+pub(crate) fn to_literal(value: &Value, span: Span) -> Result<Literal<CurrentNetwork>> {
+    let string = match value {
+        Value::Input(..) | Value::Struct(..) | Value::String(..) | Value::Array(_) | Value::Tuple(_) => {
+            return Err(InterpreterError::unsupported(format!("`{}` as a literal", format_value(value)), span).into());
+        }
+        Value::Group(group) if matches!(**group, GroupLiteral::Tuple(_)) => {
+            return Err(InterpreterError::unsupported("affine group literals", span).into());
+        }
+        // Leo allows underscores in numeric literals, while Aleo does not.
+        value => format_value(value).replace('_', ""),
+    };
+    Literal::from_str(&string).map_err(|err| InterpreterError::operation_failed(&string, err, span).into())
+}
+
+/// Converts an Aleo literal to a value.
+pub(crate) fn from_literal(literal: Literal<CurrentNetwork>, span: Span) -> Result<Value> {
+    // Field, group, and scalar values are stored without their type suffix.
+    let strip = |string: String, suffix: &str| string.strip_suffix(suffix).unwrap_or(&string).to_string();
+    Ok(match literal {
+        Literal::Address(address) => Value::Address(address.to_string(), span),
+        Literal::Boolean(boolean) => Value::Boolean(*boolean, span),
+        Literal::Field(field) => Value::Field(strip(field.to_string(), "field"), span),
+        Literal::Group(group) => {
+            Value::Group(Box::new(GroupLiteral::Single(strip(group.to_string(), "group"), span, Default::default())))
+        }
+        Literal::I8(integer) => Value::I8(*integer, span),
+        Literal::I16(integer) => Value::I16(*integer, span),
+        Literal::I32(integer) => Value::I32(*integer, span),
+        Literal::I64(integer) => Value::I64(*integer, span),
+        Literal::I128(integer) => Value::I128(*integer, span),
+        Literal::U8(integer) => Value::U8(*integer, span),
+        Literal::U16(integer) => Value::U16(*integer, span),
+        Literal::U32(integer) => Value::U32(*integer, span),
+        Literal::U64(integer) => Value::U64(*integer, span),
+        Literal::U128(integer) => Value::U128(*integer, span),
+        Literal::Scalar(scalar) => Value::Scalar(strip(scalar.to_string(), "scalar"), span),
+        Literal::Signature(_) | Literal::String(_) => {
+            return Err(InterpreterError::unsupported("signature and string values", span).into());
+        }
+    })
+}
+
+/// Converts a value to an Aleo plaintext, with the struct members in declaration order.
+pub(crate) fn to_plaintext(
+    value: &Value,
+    structs: &IndexMap<Symbol, Struct>,
+    span: Span,
+) -> Result<Plaintext<CurrentNetwork>> {
+    let string = plaintext_string(value, structs, span)?;
+    Plaintext::from_str(&string).map_err(|err| InterpreterError::operation_failed(&string, err, span).into())
+}
+
+/// Returns the value in the Aleo plaintext syntax.
+fn plaintext_string(value: &Value, structs: &IndexMap<Symbol, Struct>, span: Span) -> Result<String> {
+    match value {
+        Value::Struct(name, members) => {
+            let declaration =
+                structs.get(&name.name).ok_or_else(|| InterpreterError::undefined_variable(name, span))?;
+            let members = declaration
+                .members
+                .iter()
+                .map(|member| {
+                    let value = members.get(&member.name()).ok_or_else(|| {
+                        InterpreterError::undefined_variable(format!("{name}.{}", member.identifier), span)
+                    })?;
+                    Ok(format!("{}: {}", member.identifier, plaintext_string(value, structs, span)?))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(format!("{{ {} }}", members.join(", ")))
+        }
+        Value::Array(values) => {
+            let values =
+                values.iter().map(|value| plaintext_string(value, structs, span)).collect::<Result<Vec<_>>>()?;
+            Ok(format!("[{}]", values.join(", ")))
+        }
+        value => Ok(to_literal(value, span)?.to_string()),
+    }
+}
+
+/// Converts an Aleo plaintext to a value of the given type.
+pub(crate) fn from_plaintext(
+    plaintext: &Plaintext<CurrentNetwork>,
+    type_: &Type,
+    structs: &IndexMap<Symbol, Struct>,
+) -> std::result::Result<Value, String> {
+    match (plaintext, type_) {
+        (Plaintext::Literal(literal, _), type_) if literal_type(type_) == Some(literal.to_type()) => {
+            from_literal(literal.clone(), Span::default()).map_err(|err| err.to_string())
+        }
+        (Plaintext::Struct(members, _), Type::Identifier(name)) => {
+            let declaration = structs.get(&name.name).ok_or_else(|| format!("`{name}` is not a struct."))?;
+            if members.len() != declaration.members.len() {
+                return Err(format!("Expected the {} members of `{name}`.", declaration.members.len()));
+            }
+            let mut values = IndexMap::new();
+            for member in declaration.members.iter() {
+                let plaintext = members
+                    .iter()
+                    .find(|(identifier, _)| identifier.to_string() == member.identifier.to_string())
+                    .map(|(_, plaintext)| plaintext)
+                    .ok_or_else(|| format!("Expected the member `{}`.", member.identifier))?;
+                values.insert(member.name(), from_plaintext(plaintext, &member.type_, structs)?);
+            }
+            Ok(Value::Struct(*name, values))
+        }
+        _ => Err(format!("Expected a value of type `{type_}`.")),
+    }
+}
+
+/// Returns `true` if the values are equal, comparing structs, arrays, and tuples member by member.
+pub(crate) fn values_equal(left: &Value, right: &Value, span: Span) -> Result<bool> {
+    match (left, right) {
+        (Value::Struct(_, left), Value::Struct(_, right)) => {
+            if left.len() != right.len() {
+                return Ok(false);
+            }
+            for (name, left) in left.iter() {
+                match right.get(name) {
+                    Some(right) if values_equal(left, right, span)? => {}
+                    _ => return Ok(false),
+                }
+            }
+            Ok(true)
+        }
+        (Value::Array(left), Value::Array(right)) | (Value::Tuple(left), Value::Tuple(right)) => {
+            if left.len() != right.len() {
+                return Ok(false);
+            }
+            for (left, right) in left.iter().zip(right.iter()) {
+                if !values_equal(left, right, span)? {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+        _ => Ok(to_literal(left, span)? == to_literal(right, span)?),
+    }
+}
+
+/// Returns `true` if the value is an integer equal to zero.
+fn is_zero_integer(value: &Value) -> bool {
+    value.is_supported_const_fold_type() && i128::try_from(value).map_or(false, |integer| integer == 0)
+}
+
+/// Applies the binary operation to the values.
+pub(crate) fn evaluate_binary_operation(op: BinaryOperation, left: Value, right: Value, span: Span) -> Result<Value> {
+    use BinaryOperation::*;
+
+    if matches!(op, Eq | Neq) {
+        let equal = values_equal(&left, &right, span)?;
+        return Ok(Value::Boolean(equal == (op == Eq), span));
+    }
+
+    if matches!(op, Div | DivWrapped | Mod | Rem | RemWrapped) && is_zero_integer(&right) {
+        return Err(InterpreterError::division_by_zero(span).into());
+    }
+
+    // Booleans and integers use the same operations as constant folding.
+    if left.is_supported_const_fold_type() && right.is_supported_const_fold_type() {
+        return match op {
+            Add => left.add(right, span),
+            AddWrapped => left.add_wrapped(right, span),
+            And | BitwiseAnd => left.bitand(right, span),
+            Div => left.div(right, span),
+            DivWrapped => left.div_wrapped(right, span),
+            Gte => left.ge(right, span),
+            Gt => left.gt(right, span),
+            Lte => left.le(right, span),
+            Lt => left.lt(right, span),
+            // `mod` is only defined on unsigned integers, for which it coincides with `%`.
+            Mod | Rem => left.rem(right, span),
+            Mul => left.mul(right, span),
+            MulWrapped => left.mul_wrapped(right, span),
+            Nand => left.bitand(right, span)?.not(span),
+            Nor => left.bitor(right, span)?.not(span),
+            Or | BitwiseOr => left.bitor(right, span),
+            Pow => left.pow(right, span),
+            PowWrapped => left.pow_wrapped(right, span),
+            RemWrapped => left.rem_wrapped(right, span),
+            Shl => left.shl(right, span),
+            ShlWrapped => left.shl_wrapped(right, span),
+            Shr => left.shr(right, span),
+            ShrWrapped => left.shr_wrapped(right, span),
+            Sub => left.sub(right, span),
+            SubWrapped => left.sub_wrapped(right, span),
+            Xor => left.xor(right, span),
+            Eq | Neq => unreachable!("Equality is handled above."),
+        };
+    }
+
+    // Fields, groups, and scalars use the operations of snarkVM.
+    let result = match (op, to_literal(&left, span)?, to_literal(&right, span)?) {
+        (Add, Literal::Field(a), Literal::Field(b)) => Literal::Field(a + b),
+        (Add, Literal::Group(a), Literal::Group(b)) => Literal::Group(a + b),
+        (Add, Literal::Scalar(a), Literal::Scalar(b)) => Literal::Scalar(a + b),
+        (Sub, Literal::Field(a), Literal::Field(b)) => Literal::Field(a - b),
+        (Sub, Literal::Group(a), Literal::Group(b)) => Literal::Group(a - b),
+        (Mul, Literal::Field(a), Literal::Field(b)) => Literal::Field(a * b),
+        (Mul, Literal::Group(a), Literal::Scalar(b)) => Literal::Group(a * b),
+        (Mul, Literal::Scalar(a), Literal::Group(b)) => Literal::Group(b * a),
+        (Div, Literal::Field(a), Literal::Field(b)) => {
+            if b.is_zero() {
+                return Err(InterpreterError::division_by_zero(span).into());
+            }
+            Literal::Field(a / b)
+        }
+        (Pow, Literal::Field(a), Literal::Field(b)) => Literal::Field(a.pow(b)),
+        (Lt, Literal::Field(a), Literal::Field(b)) => Literal::Boolean(a.is_less_than(&b)),
+        (Lte, Literal::Field(a), Literal::Field(b)) => Literal::Boolean(a.is_less_than_or_equal(&b)),
+        (Gt, Literal::Field(a), Literal::Field(b)) => Literal::Boolean(a.is_greater_than(&b)),
+        (Gte, Literal::Field(a), Literal::Field(b)) => Literal::Boolean(a.is_greater_than_or_equal(&b)),
+        (Lt, Literal::Scalar(a), Literal::Scalar(b)) => Literal::Boolean(a.is_less_than(&b)),
+        (Lte, Literal::Scalar(a), Literal::Scalar(b)) => Literal::Boolean(a.is_less_than_or_equal(&b)),
+        (Gt, Literal::Scalar(a), Literal::Scalar(b)) => Literal::Boolean(a.is_greater_than(&b)),
+        (Gte, Literal::Scalar(a), Literal::Scalar(b)) => Literal::Boolean(a.is_greater_than_or_equal(&b)),
+        (op, left, right) => {
+            return Err(InterpreterError::unsupported(format!("`{left} {op} {right}`"), span).into());
+        }
+    };
+    from_literal(result, span)
+}
+
+/// Applies the unary operation to the value.
+pub(crate) fn evaluate_unary_operation(op: UnaryOperation, value: Value, span: Span) -> Result<Value> {
+    use UnaryOperation::*;
+
+    // Booleans and integers use the same operations as constant folding.
+    if value.is_supported_const_fold_type() {
+        match op {
+            Abs => return value.abs(span),
+            AbsWrapped => return value.abs_wrapped(span),
+            Negate => return value.neg(span),
+            Not => return value.not(span),
+            _ => {}
+        }
+    }
+
+    // Fields and groups use the operations of snarkVM.
+    let operation = format!("{}.{op:?}()", format_value(&value));
+    let failed = |error: String| InterpreterError::operation_failed(&operation, error, span);
+    let result = match (op, to_literal(&value, span)?) {
+        (Negate, Literal::Field(a)) => Literal::Field(-a),
+        (Negate, Literal::Group(a)) => Literal::Group(-a),
+        (Double, Literal::Field(a)) => Literal::Field(a.double()),
+        (Double, Literal::Group(a)) => Literal::Group(a.double()),
+        (Square, Literal::Field(a)) => Literal::Field(a.square()),
+        (Inverse, Literal::Field(a)) => Literal::Field(a.inverse().map_err(|err| failed(err.to_string()))?),
+        (SquareRoot, Literal::Field(a)) => Literal::Field(a.square_root().map_err(|err| failed(err.to_string()))?),
+        (ToXCoordinate, Literal::Group(a)) => Literal::Field(a.to_x_coordinate()),
+        (ToYCoordinate, Literal::Group(a)) => Literal::Field(a.to_y_coordinate()),
+        _ => return Err(InterpreterError::unsupported(format!("`{operation}`"), span).into()),
+    };
+    from_literal(result, span)
+}
+
+/// Casts the value to the given type.
+pub(crate) fn cast(value: Value, type_: &Type, span: Span) -> Result<Value> {
+    let operation = format!("{} as {type_}", format_value(&value));
+    let literal_type = match literal_type(type_) {
+        Some(literal_type) => literal_type,
+        None => return Err(InterpreterError::unsupported(format!("`{operation}`"), span).into()),
+    };
+    let result = to_literal(&value, span)?
+        .cast(literal_type)
+        .map_err(|err| InterpreterError::operation_failed(&operation, err, span))?;
+    from_literal(result, span)
+}
@@ -0,0 +1,788 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The formatter to print Leo code text in a canonical style.
+//!
+//! This module contains the [`format()`] method which parses a program into an AST and prints it back.
+//! Comments are not part of the AST, so they are kept aside by the tokenizer and re-attached
+//! to the item or statement that follows them, or to the end of the line they trail.
+
+use crate::{tokenizer::*, ParserContext, Token};
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, Result};
+use leo_span::{span::BytePos, sym};
+
+/// The number of spaces in a level of indentation.
+const INDENT: &str = "    ";
+
+/// Formats the given source code text into the canonical style.
+pub fn format(handler: &Handler, node_builder: &NodeBuilder, source: &str, start_pos: BytePos) -> Result<String> {
+    let tokens = crate::tokenize(source, start_pos)?;
+    let mut formatter = Formatter::new(source, start_pos, &tokens);
+
+    let program = ParserContext::new(handler, node_builder, tokens).parse_program()?;
+
+    formatter.format_program(&program);
+    Ok(formatter.output)
+}
+
+/// Formats the given source code text of a module into the canonical style.
+pub fn format_module(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    source: &str,
+    start_pos: BytePos,
+) -> Result<String> {
+    let tokens = crate::tokenize(source, start_pos)?;
+    let mut formatter = Formatter::new(source, start_pos, &tokens);
+
+    // Note that the program the module belongs to does not matter to its formatting.
+    let program_id = ProgramId {
+        name: Identifier::new(sym::main, node_builder.next_id()),
+        network: Identifier::new(sym::aleo, node_builder.next_id()),
+    };
+    let module = ParserContext::new(handler, node_builder, tokens).parse_module(program_id)?;
+
+    formatter.format_program_scope_items(&module);
+    // Write the comments at the end of the file.
+    formatter.comments_before(BytePos(u32::MAX));
+    Ok(formatter.output)
+}
+
+/// Binding strengths of expressions, from the loosest to the tightest, as defined in the parser.
+mod precedence {
+    pub const TERNARY: u8 = 1;
+    pub const OR: u8 = 2;
+    pub const AND: u8 = 3;
+    pub const EQUALITY: u8 = 4;
+    pub const ORDERING: u8 = 5;
+    pub const XOR: u8 = 6;
+    pub const BITWISE_OR: u8 = 7;
+    pub const BITWISE_AND: u8 = 8;
+    pub const SHIFT: u8 = 9;
+    pub const ADDITIVE: u8 = 10;
+    pub const MULTIPLICATIVE: u8 = 11;
+    pub const EXPONENTIAL: u8 = 12;
+    pub const CAST: u8 = 13;
+    pub const UNARY: u8 = 14;
+    pub const POSTFIX: u8 = 15;
+}
+
+struct Formatter<'a> {
+    /// The source code text being formatted.
+    source: &'a str,
+    /// The position at which the source code text starts in the source map.
+    start_pos: BytePos,
+    /// The comments in the source code text, in order of appearance.
+    comments: Vec<SpannedToken>,
+    /// The index of the first comment that has not been printed yet.
+    next_comment: usize,
+    /// The formatted source code text.
+    output: String,
+    /// The current level of indentation.
+    indent: usize,
+}
+
+impl<'a> Formatter<'a> {
+    /// Returns a formatter for the given source code text, whose comments are among the given tokens.
+    fn new(source: &'a str, start_pos: BytePos, tokens: &[SpannedToken]) -> Self {
+        // Keep the comments aside, since the parser strips them out.
+        let comments = tokens
+            .iter()
+            .filter(|t| matches!(t.token, Token::CommentLine(_) | Token::CommentBlock(_)))
+            .cloned()
+            .collect();
+
+        Self { source, start_pos, comments, next_comment: 0, output: String::new(), indent: 0 }
+    }
+
+    /// Writes a line at the current level of indentation.
+    fn line(&mut self, text: &str) {
+        for _ in 0..self.indent {
+            self.output.push_str(INDENT);
+        }
+        self.output.push_str(text);
+        self.output.push('\n');
+    }
+
+    /// Writes an empty line, unless the output is empty or already ends with one.
+    fn blank_line(&mut self) {
+        if !self.output.is_empty() && !self.output.ends_with("{\n") && !self.output.ends_with("\n\n") {
+            self.output.push('\n');
+        }
+    }
+
+    /// Returns the source code text between the two positions.
+    fn source_between(&self, lo: BytePos, hi: BytePos) -> &str {
+        let lo = (lo.0.saturating_sub(self.start_pos.0) as usize).min(self.source.len());
+        let hi = (hi.0.saturating_sub(self.start_pos.0) as usize).clamp(lo, self.source.len());
+        self.source.get(lo..hi).unwrap_or_default()
+    }
+
+    /// Returns the text of the comment without its trailing newline.
+    fn comment_text(comment: &SpannedToken) -> &str {
+        match &comment.token {
+            Token::CommentLine(text) | Token::CommentBlock(text) => text.trim_end(),
+            _ => unreachable!("only comments are kept aside"),
+        }
+    }
+
+    /// Returns the position where the next item or statement starts, including the comments before it.
+    fn leading_position(&self, pos: BytePos) -> BytePos {
+        match self.comments.get(self.next_comment) {
+            Some(comment) if comment.span.lo < pos => comment.span.lo,
+            _ => pos,
+        }
+    }
+
+    /// Writes an empty line if the source code text has one between `prev` and the item or statement at `pos`.
+    fn preserve_blank_line(&mut self, prev: BytePos, pos: BytePos) {
+        let leading = self.leading_position(pos);
+        if self.source_between(prev, leading).matches('\n').count() > 1 {
+            self.blank_line();
+        }
+    }
+
+    /// Writes the comments that start before `pos`, each on its own line.
+    fn comments_before(&mut self, pos: BytePos) {
+        while let Some(comment) = self.comments.get(self.next_comment) {
+            if comment.span.lo >= pos {
+                break;
+            }
+            let text = Self::comment_text(comment).to_string();
+            self.next_comment += 1;
+            self.line(&text);
+        }
+    }
+
+    /// Appends the comment that trails the line ending at `end`, if any, to the last written line.
+    fn trailing_comment(&mut self, end: BytePos) {
+        if let Some(comment) = self.comments.get(self.next_comment) {
+            if comment.span.lo >= end && !self.source_between(end, comment.span.lo).contains('\n') {
+                let text = Self::comment_text(comment).to_string();
+                self.next_comment += 1;
+                self.output.pop();
+                self.output.push(' ');
+                self.output.push_str(&text);
+                self.output.push('\n');
+            }
+        }
+    }
+
+    fn format_program(&mut self, program: &Program) {
+        for (name, (_, span)) in program.imports.iter() {
+            self.comments_before(span.lo);
+            self.line(&format!("import {name}.leo;"));
+            self.trailing_comment(span.hi);
+        }
+
+        for scope in program.program_scopes.values() {
+            self.blank_line();
+            self.comments_before(scope.span.lo);
+            self.format_program_scope(scope);
+        }
+
+        // Write the comments at the end of the file.
+        self.comments_before(BytePos(u32::MAX));
+    }
+
+    fn format_program_scope(&mut self, scope: &ProgramScope) {
+        self.line(&format!("program {}.{} {{", scope.program_id.name, scope.program_id.network));
+        self.indent += 1;
+        self.format_program_scope_items(scope);
+        self.comments_before(scope.span.hi);
+        self.indent -= 1;
+        self.line("}");
+    }
+
+    fn format_program_scope_items(&mut self, scope: &ProgramScope) {
+        // The program scope stores each kind of item separately, so restore the order of the source code text.
+        enum Item<'a> {
+            Module(&'a Module),
+            Const(&'a ConstDeclaration),
+            TypeAlias(&'a TypeAlias),
+            Struct(&'a Struct),
+            Enum(&'a Enum),
+            Mapping(&'a Mapping),
+            Function(&'a Function),
+        }
+        let mut items: Vec<(BytePos, Item)> = Vec::new();
+        items.extend(scope.modules.iter().map(|(_, m)| (m.span.lo, Item::Module(m))));
+        items.extend(scope.consts.iter().map(|(_, c)| (c.span.lo, Item::Const(c))));
+        items.extend(scope.type_aliases.iter().map(|(_, t)| (t.span.lo, Item::TypeAlias(t))));
+        items.extend(scope.structs.iter().map(|(_, s)| (s.span.lo, Item::Struct(s))));
+        items.extend(scope.enums.iter().map(|(_, e)| (e.span.lo, Item::Enum(e))));
+        items.extend(scope.mappings.iter().map(|(_, m)| (m.span.lo, Item::Mapping(m))));
+        items.extend(scope.functions.iter().map(|(_, f)| {
+            // Note that the span of a function does not include its annotations.
+            let lo = f.annotations.iter().map(|a| a.span.lo).chain([f.span.lo]).min().unwrap();
+            (lo, Item::Function(f))
+        }));
+        items.sort_by_key(|(lo, _)| *lo);
+
+        let mut prev = None;
+        for (lo, item) in items {
+            // Consecutive modules, constants, type aliases, and mappings are grouped together,
+            // while other items are separated by a blank line.
+            let kind = std::mem::discriminant(&item);
+            let grouped = matches!(item, Item::Module(_) | Item::Const(_) | Item::TypeAlias(_) | Item::Mapping(_))
+                && prev == Some(kind);
+            if prev.is_some() && !grouped {
+                self.blank_line();
+            }
+            prev = Some(kind);
+            self.comments_before(lo);
+            match item {
+                Item::Module(m) => {
+                    self.line(&format!("mod {};", m.identifier));
+                    self.trailing_comment(m.span.hi);
+                }
+                Item::Const(c) => self.format_statement(&Statement::Const(c.clone())),
+                Item::TypeAlias(t) => {
+                    self.line(&format!("type {} = {};", t.identifier, Self::type_(&t.type_)));
+                    self.trailing_comment(t.span.hi);
+                }
+                Item::Struct(s) => self.format_struct(s),
+                Item::Enum(e) => self.format_enum(e),
+                Item::Mapping(m) => {
+                    self.line(&format!(
+                        "mapping {}: {} => {};",
+                        m.identifier,
+                        Self::type_(&m.key_type),
+                        Self::type_(&m.value_type)
+                    ));
+                    self.trailing_comment(m.span.hi);
+                }
+                Item::Function(f) => self.format_function(f),
+            }
+        }
+    }
+
+    fn format_struct(&mut self, struct_: &Struct) {
+        let keyword = if struct_.is_record { "record" } else { "struct" };
+        self.line(&format!("{keyword} {} {{", struct_.identifier));
+        self.indent += 1;
+        for member in struct_.members.iter() {
+            self.comments_before(member.span.lo);
+            self.line(&format!("{}{}: {},", Self::mode(member.mode), member.identifier, Self::type_(&member.type_)));
+            self.trailing_comment(member.span.hi);
+        }
+        self.comments_before(struct_.span.hi);
+        self.indent -= 1;
+        self.line("}");
+    }
+
+    fn format_enum(&mut self, enum_: &Enum) {
+        self.line(&format!("enum {} {{", enum_.identifier));
+        self.indent += 1;
+        for variant in enum_.variants.iter() {
+            self.comments_before(variant.span.lo);
+            let text = match &variant.payload {
+                Some(payload) => format!("{}({}),", variant.identifier, Self::type_(payload)),
+                None => format!("{},", variant.identifier),
+            };
+            self.line(&text);
+            self.trailing_comment(variant.span.hi);
+        }
+        self.comments_before(enum_.span.hi);
+        self.indent -= 1;
+        self.line("}");
+    }
+
+    fn format_function(&mut self, function: &Function) {
+        for annotation in function.annotations.iter() {
+            self.comments_before(annotation.span.lo);
+            self.line(&annotation.to_string());
+        }
+        self.comments_before(function.span.lo);
+
+        let variant = match function.variant {
+            Variant::Inline => "inline",
+            Variant::Standard => "function",
+            Variant::Transition => "transition",
+        };
+        let header = format!("{variant} {}{}", function.identifier, Self::signature(&function.input, &function.output));
+        self.format_block(&header, &function.block);
+
+        if let Some(finalize) = &function.finalize {
+            self.blank_line();
+            self.comments_before(finalize.span.lo);
+            let header =
+                format!("finalize {}{}", finalize.identifier, Self::signature(&finalize.input, &finalize.output));
+            self.format_block(&header, &finalize.block);
+        }
+    }
+
+    /// Returns the parameters and return type of a function, e.g. `(a: u8, b: u8) -> u8`.
+    fn signature(input: &[Input], output: &[Output]) -> String {
+        let parameters = input
+            .iter()
+            .map(|input| match input {
+                Input::Internal(input) => {
+                    format!("{}{}: {}", Self::mode(input.mode), input.identifier, Self::type_(&input.type_))
+                }
+                Input::External(input) => {
+                    format!("{}: {}.leo/{}.record", input.identifier, input.program_name, input.record)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let outputs = output
+            .iter()
+            .map(|output| match output {
+                Output::Internal(output) => format!("{}{}", Self::mode(output.mode), Self::type_(&output.type_)),
+                Output::External(output) => format!("{}.leo/{}.record", output.program_name, output.record),
+            })
+            .collect::<Vec<_>>();
+
+        match outputs.len() {
+            0 => format!("({parameters})"),
+            1 => format!("({parameters}) -> {}", outputs[0]),
+            _ => format!("({parameters}) -> ({})", outputs.join(", ")),
+        }
+    }
+
+    /// Writes a block, preceded on its opening line by the given header.
+    fn format_block(&mut self, header: &str, block: &Block) {
+        let opening = match header.is_empty() {
+            true => "{".to_string(),
+            false => format!("{header} {{"),
+        };
+        self.line(&opening);
+        self.indent += 1;
+        self.format_statements(block);
+        self.comments_before(block.span.hi);
+        self.indent -= 1;
+        self.line("}");
+    }
+
+    fn format_statements(&mut self, block: &Block) {
+        let mut prev = None;
+        for statement in block.statements.iter() {
+            if let Some(prev) = prev {
+                self.preserve_blank_line(prev, statement.span().lo);
+            }
+            self.comments_before(statement.span().lo);
+            self.format_statement(statement);
+            prev = Some(statement.span().hi);
+        }
+    }
+
+    fn format_statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::ArrayIteration(iteration) => {
+                let header = match &iteration.type_ {
+                    Some(type_) => format!(
+                        "for {}: {} in {}",
+                        iteration.variable,
+                        Self::type_(type_),
+                        Self::expr_without_struct(&iteration.array)
+                    ),
+                    None => format!("for {} in {}", iteration.variable, Self::expr_without_struct(&iteration.array)),
+                };
+                self.format_block(&header, &iteration.block);
+            }
+            Statement::Assert(assert) => {
+                let text = match &assert.variant {
+                    AssertVariant::Assert(e) => format!("assert({});", Self::expr(e)),
+                    AssertVariant::AssertEq(l, r) => format!("assert_eq({}, {});", Self::expr(l), Self::expr(r)),
+                    AssertVariant::AssertNeq(l, r) => format!("assert_neq({}, {});", Self::expr(l), Self::expr(r)),
+                };
+                self.line(&text);
+            }
+            Statement::Assign(assign) => {
+                let place = Self::expr(&assign.place);
+                let text = match &assign.value {
+                    // The parser desugars `x op= y` into `x = x op y`, and gives the binary expression the span of
+                    // the whole statement, which tells it apart from a user-written `x = x op y`.
+                    Expression::Binary(binary) if binary.span == assign.span && Self::infix(binary.op).is_some() => {
+                        format!("{place} {}= {};", Self::infix(binary.op).unwrap(), Self::expr(&binary.right))
+                    }
+                    value => format!("{place} = {};", Self::expr(value)),
+                };
+                self.line(&text);
+            }
+            Statement::Block(block) => self.format_block("", block),
+            Statement::Break(_) => self.line("break;"),
+            Statement::Conditional(conditional) => self.format_conditional(conditional, ""),
+            Statement::Console(console) => {
+                let text = match &console.function {
+                    ConsoleFunction::Assert(e) => format!("console.assert({});", Self::expr(e)),
+                    ConsoleFunction::AssertEq(l, r) => {
+                        format!("console.assert_eq({}, {});", Self::expr(l), Self::expr(r))
+                    }
+                    ConsoleFunction::AssertNeq(l, r) => {
+                        format!("console.assert_neq({}, {});", Self::expr(l), Self::expr(r))
+                    }
+                    ConsoleFunction::Log(format, args) => {
+                        let args = args.iter().map(|arg| format!(", {}", Self::expr(arg))).collect::<String>();
+                        format!("console.log(\"{format}\"{args});")
+                    }
+                };
+                self.line(&text);
+            }
+            Statement::Const(const_) => self.line(&format!(
+                "const {}: {} = {};",
+                const_.place,
+                Self::type_(&const_.type_),
+                Self::expr(&const_.value)
+            )),
+            Statement::Continue(_) => self.line("continue;"),
+            Statement::Definition(definition) => {
+                let keyword = match definition.declaration_type {
+                    DeclarationType::Const => "const",
+                    DeclarationType::Let => "let",
+                };
+                self.line(&format!(
+                    "{keyword} {}: {} = {};",
+                    Self::expr(&definition.place),
+                    Self::type_(&definition.type_),
+                    Self::expr(&definition.value)
+                ));
+            }
+            Statement::Expression(expression) => self.line(&format!("{};", Self::expr(&expression.expression))),
+            Statement::Iteration(iteration) => {
+                let header = format!(
+                    "for {}: {} in {}{}{}",
+                    iteration.variable,
+                    Self::type_(&iteration.type_),
+                    Self::expr_without_struct(&iteration.start),
+                    if iteration.inclusive { "..=" } else { ".." },
+                    Self::expr_without_struct(&iteration.stop),
+                );
+                self.format_block(&header, &iteration.block);
+            }
+            Statement::Match(match_) => {
+                self.line(&format!("match {} {{", Self::expr_without_struct(&match_.expression)));
+                self.indent += 1;
+                for arm in match_.arms.iter() {
+                    self.comments_before(arm.span.lo);
+                    self.format_block(&format!("{} =>", arm.pattern), &arm.body);
+                }
+                self.comments_before(match_.span.hi);
+                self.indent -= 1;
+                self.line("}");
+            }
+            Statement::Return(return_) => {
+                let mut text = match &return_.expression {
+                    Expression::Unit(_) => "return".to_string(),
+                    e => format!("return {}", Self::expr(e)),
+                };
+                if let Some(arguments) = &return_.finalize_arguments {
+                    text.push_str(" then finalize");
+                    if !arguments.is_empty() {
+                        text.push_str(&format!("({})", Self::exprs(arguments)));
+                    }
+                }
+                text.push(';');
+                self.line(&text);
+            }
+        }
+        // Blocks end with their closing brace, which is not followed by a trailing comment in canonical style.
+        if !matches!(
+            statement,
+            Statement::ArrayIteration(_)
+                | Statement::Block(_)
+                | Statement::Conditional(_)
+                | Statement::Iteration(_)
+                | Statement::Match(_)
+        ) {
+            self.trailing_comment(statement.span().hi);
+        }
+    }
+
+    /// Writes an `if` statement, where `prefix` is `} else ` for an `else if` branch.
+    fn format_conditional(&mut self, conditional: &ConditionalStatement, prefix: &str) {
+        let header = format!("{prefix}if {}", Self::expr_without_struct(&conditional.condition));
+        self.line(&format!("{header} {{"));
+        self.indent += 1;
+        self.format_statements(&conditional.then);
+        self.comments_before(conditional.then.span.hi);
+        self.indent -= 1;
+
+        match conditional.otherwise.as_deref() {
+            None => self.line("}"),
+            Some(Statement::Conditional(otherwise)) => self.format_conditional(otherwise, "} else "),
+            Some(Statement::Block(otherwise)) => {
+                self.line("} else {");
+                self.indent += 1;
+                self.format_statements(otherwise);
+                self.comments_before(otherwise.span.hi);
+                self.indent -= 1;
+                self.line("}");
+            }
+            Some(otherwise) => {
+                // Note that the parser only produces blocks and conditionals in the `else` branch.
+                self.line("} else {");
+                self.indent += 1;
+                self.format_statement(otherwise);
+                self.indent -= 1;
+                self.line("}");
+            }
+        }
+    }
+
+    /// Returns the mode of an input or output, followed by a space if it is not empty.
+    fn mode(mode: Mode) -> String {
+        match mode {
+            Mode::None => String::new(),
+            mode => format!("{mode} "),
+        }
+    }
+
+    fn type_(type_: &Type) -> String {
+        match type_ {
+            Type::Boolean => "bool".to_string(),
+            Type::Array(array) => format!("[{}; {}]", Self::type_(array.element_type()), array.length()),
+            Type::Tuple(tuple) => {
+                format!("({})", tuple.elements().iter().map(Self::type_).collect::<Vec<_>>().join(", "))
+            }
+            type_ => type_.to_string(),
+        }
+    }
+
+    /// Returns the operator of a binary operation that has an infix form, e.g. `+` for `Add`.
+    fn infix(op: BinaryOperation) -> Option<&'static str> {
+        Some(match op {
+            BinaryOperation::Add => "+",
+            BinaryOperation::And => "&&",
+            BinaryOperation::BitwiseAnd => "&",
+            BinaryOperation::BitwiseOr => "|",
+            BinaryOperation::Div => "/",
+            BinaryOperation::Eq => "==",
+            BinaryOperation::Gte => ">=",
+            BinaryOperation::Gt => ">",
+            BinaryOperation::Lte => "<=",
+            BinaryOperation::Lt => "<",
+            BinaryOperation::Mul => "*",
+            BinaryOperation::Neq => "!=",
+            BinaryOperation::Or => "||",
+            BinaryOperation::Pow => "**",
+            BinaryOperation::Rem => "%",
+            BinaryOperation::Shl => "<<",
+            BinaryOperation::Shr => ">>",
+            BinaryOperation::Sub => "-",
+            BinaryOperation::Xor => "^",
+            _ => return None,
+        })
+    }
+
+    /// Returns the method name of a binary operation that only has a method form, e.g. `add_wrapped`.
+    fn method(op: BinaryOperation) -> &'static str {
+        match op {
+            BinaryOperation::AddWrapped => "add_wrapped",
+            BinaryOperation::DivWrapped => "div_wrapped",
+            BinaryOperation::Mod => "mod",
+            BinaryOperation::MulWrapped => "mul_wrapped",
+            BinaryOperation::Nand => "nand",
+            BinaryOperation::Nor => "nor",
+            BinaryOperation::PowWrapped => "pow_wrapped",
+            BinaryOperation::RemWrapped => "rem_wrapped",
+            BinaryOperation::ShlWrapped => "shl_wrapped",
+            BinaryOperation::ShrWrapped => "shr_wrapped",
+            BinaryOperation::SubWrapped => "sub_wrapped",
+            _ => unreachable!("operations with an infix form are printed as such"),
+        }
+    }
+
+    /// Returns the precedence of the given binary operation.
+    fn binary_precedence(op: BinaryOperation) -> u8 {
+        use precedence::*;
+        match op {
+            BinaryOperation::Or => OR,
+            BinaryOperation::And => AND,
+            BinaryOperation::Eq | BinaryOperation::Neq => EQUALITY,
+            BinaryOperation::Lt | BinaryOperation::Lte | BinaryOperation::Gt | BinaryOperation::Gte => ORDERING,
+            BinaryOperation::Xor => XOR,
+            BinaryOperation::BitwiseOr => BITWISE_OR,
+            BinaryOperation::BitwiseAnd => BITWISE_AND,
+            BinaryOperation::Shl | BinaryOperation::Shr => SHIFT,
+            BinaryOperation::Add | BinaryOperation::Sub => ADDITIVE,
+            BinaryOperation::Mul | BinaryOperation::Div | BinaryOperation::Rem => MULTIPLICATIVE,
+            BinaryOperation::Pow => EXPONENTIAL,
+            _ => POSTFIX,
+        }
+    }
+
+    /// Returns the precedence of the given expression.
+    fn precedence(expression: &Expression) -> u8 {
+        match expression {
+            Expression::Ternary(_) => precedence::TERNARY,
+            Expression::Binary(binary) => Self::binary_precedence(binary.op),
+            Expression::Cast(_) => precedence::CAST,
+            // The parser folds a negation into the literal it applies to, e.g. `-1u8`.
+            Expression::Literal(literal) if literal.to_string().starts_with('-') => precedence::UNARY,
+            Expression::Unary(unary) if matches!(unary.op, UnaryOperation::Not | UnaryOperation::Negate) => {
+                precedence::UNARY
+            }
+            _ => precedence::POSTFIX,
+        }
+    }
+
+    /// Returns a comma-separated list of expressions.
+    fn exprs(expressions: &[Expression]) -> String {
+        expressions.iter().map(Self::expr).collect::<Vec<_>>().join(", ")
+    }
+
+    fn expr(expression: &Expression) -> String {
+        Self::expr_in(expression, false)
+    }
+
+    /// Prints an expression in a position where struct initializers must be parenthesized, e.g. an `if` condition.
+    fn expr_without_struct(expression: &Expression) -> String {
+        Self::expr_in(expression, true)
+    }
+
+    /// Prints an operand, wrapping it in parentheses if it binds looser than `min`.
+    fn operand(expression: &Expression, min: u8, no_struct: bool) -> String {
+        match Self::precedence(expression) < min {
+            true => format!("({})", Self::expr(expression)),
+            false => Self::expr_in(expression, no_struct),
+        }
+    }
+
+    fn expr_in(expression: &Expression, no_struct: bool) -> String {
+        use precedence::*;
+        match expression {
+            Expression::Access(access) => match access {
+                AccessExpression::Array(access) => {
+                    format!("{}[{}]", Self::operand(&access.array, POSTFIX, no_struct), Self::expr(&access.index))
+                }
+                AccessExpression::AssociatedConstant(constant) => {
+                    format!("{}::{}", Self::type_(&constant.ty), constant.name)
+                }
+                AccessExpression::AssociatedFunction(function) => match &function.ty {
+                    // Mapping operations and signature verification are printed as methods of their first argument.
+                    Type::Identifier(ty)
+                        if !function.arguments.is_empty()
+                            && (ty.name == sym::Mapping
+                                || (ty.name == sym::signature && function.name.name == sym::verify)) =>
+                    {
+                        format!(
+                            "{}.{}({})",
+                            Self::operand(&function.arguments[0], POSTFIX, no_struct),
+                            function.name,
+                            Self::exprs(&function.arguments[1..])
+                        )
+                    }
+                    ty => format!("{}::{}({})", Self::type_(ty), function.name, Self::exprs(&function.arguments)),
+                },
+                AccessExpression::Member(access) => {
+                    format!("{}.{}", Self::operand(&access.inner, POSTFIX, no_struct), access.name)
+                }
+                AccessExpression::Tuple(access) => {
+                    format!("{}.{}", Self::operand(&access.tuple, POSTFIX, no_struct), access.index)
+                }
+            },
+            Expression::Array(array) => format!("[{}]", Self::exprs(&array.elements)),
+            Expression::Binary(binary) => match Self::infix(binary.op) {
+                Some(op) => {
+                    let prec = Self::binary_precedence(binary.op);
+                    // Equality and ordering operations are not associative, so neither operand may be another one.
+                    let left_min = if matches!(prec, EQUALITY | ORDERING) { prec + 1 } else { prec };
+                    format!(
+                        "{} {op} {}",
+                        Self::operand(&binary.left, left_min, no_struct),
+                        Self::operand(&binary.right, prec + 1, no_struct)
+                    )
+                }
+                None => format!(
+                    "{}.{}({})",
+                    Self::operand(&binary.left, POSTFIX, no_struct),
+                    Self::method(binary.op),
+                    Self::expr(&binary.right)
+                ),
+            },
+            Expression::Call(call) => match &call.external {
+                Some(external) => {
+                    format!(
+                        "{}.leo/{}({})",
+                        Self::expr(external),
+                        Self::expr(&call.function),
+                        Self::exprs(&call.arguments)
+                    )
+                }
+                None => format!("{}({})", Self::expr(&call.function), Self::exprs(&call.arguments)),
+            },
+            Expression::Cast(cast) => {
+                format!("{} as {}", Self::operand(&cast.expression, UNARY, no_struct), Self::type_(&cast.type_))
+            }
+            Expression::Struct(struct_) => {
+                let members = struct_
+                    .members
+                    .iter()
+                    .map(|member| match &member.expression {
+                        Some(expression) => format!("{}: {}", member.identifier, Self::expr(expression)),
+                        None => member.identifier.to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let text = match members.is_empty() {
+                    true => format!("{} {{}}", struct_.name),
+                    false => format!("{} {{ {members} }}", struct_.name),
+                };
+                match no_struct {
+                    true => format!("({text})"),
+                    false => text,
+                }
+            }
+            Expression::Err(_) => "error".to_string(),
+            Expression::Identifier(identifier) => identifier.to_string(),
+            Expression::Literal(Literal::Group(group)) => match group.as_ref() {
+                GroupLiteral::Single(value, ..) => format!("{value}group"),
+                GroupLiteral::Tuple(tuple) => format!("({}, {})group", tuple.x, tuple.y),
+            },
+            Expression::Literal(literal) => literal.to_string(),
+            Expression::Match(match_) => {
+                let arms = match_
+                    .arms
+                    .iter()
+                    .map(|arm| format!("{} => {}", arm.pattern, Self::expr(&arm.body)))
+                    .collect::<Vec<_>>();
+                format!("match {} {{ {} }}", Self::expr_without_struct(&match_.expression), arms.join(", "))
+            }
+            Expression::Repeat(repeat) => format!("[{}; {}]", Self::expr(&repeat.expr), repeat.count),
+            Expression::Ternary(ternary) => format!(
+                "{} ? {} : {}",
+                Self::operand(&ternary.condition, OR, no_struct),
+                Self::expr(&ternary.if_true),
+                Self::expr(&ternary.if_false)
+            ),
+            Expression::Tuple(tuple) => format!("({})", Self::exprs(&tuple.elements)),
+            Expression::Unary(unary) => match unary.op {
+                UnaryOperation::Not => format!("!{}", Self::operand(&unary.receiver, UNARY, no_struct)),
+                UnaryOperation::Negate => format!("-{}", Self::operand(&unary.receiver, UNARY, no_struct)),
+                op => {
+                    let method = match op {
+                        UnaryOperation::Abs => "abs",
+                        UnaryOperation::AbsWrapped => "abs_wrapped",
+                        UnaryOperation::Double => "double",
+                        UnaryOperation::Inverse => "inv",
+                        UnaryOperation::Square => "square",
+                        UnaryOperation::SquareRoot => "square_root",
+                        UnaryOperation::ToXCoordinate => "to_x_coordinate",
+                        UnaryOperation::ToYCoordinate => "to_y_coordinate",
+                        UnaryOperation::Not | UnaryOperation::Negate => unreachable!("handled above"),
+                    };
+                    format!("{}.{method}()", Self::operand(&unary.receiver, POSTFIX, no_struct))
+                }
+            },
+            Expression::Unit(_) => "()".to_string(),
+        }
+    }
+}
@@ -31,6 +31,8 @@ pub(crate) use tokenizer::*;
 pub mod parser;
 pub use parser::*;
 
+mod formatter;
+
 use leo_ast::{input::InputData, Ast, NodeBuilder, ProgramInput};
 use leo_errors::{emitter::Handler, Result};
 
@@ -42,6 +44,26 @@ pub fn parse_ast(handler: &Handler, node_builder: &NodeBuilder, source: &str, st
     Ok(Ast::new(parser::parse(handler, node_builder, source, start_pos)?))
 }
 
+/// Formats the given source code text of a program into the canonical style, preserving its comments.
+pub fn format_source(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    source: &str,
+    start_pos: BytePos,
+) -> Result<String> {
+    formatter::format(handler, node_builder, source, start_pos)
+}
+
+/// Formats the given source code text of a module into the canonical style, preserving its comments.
+pub fn format_module_source(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    source: &str,
+    start_pos: BytePos,
+) -> Result<String> {
+    formatter::format_module(handler, node_builder, source, start_pos)
+}
+
 /// Parses program inputs from the input file path
 pub fn parse_program_inputs(
     handler: &Handler,
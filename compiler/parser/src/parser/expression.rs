@@ -424,6 +424,74 @@ impl ParserContext<'_> {
         }))
     }
 
+    /// Returns a [`MatchPattern`] if the next tokens represent the pattern of an arm of a `match`,
+    /// e.g. `_`, `Phase::Open`, `Phase::Settled(amount)`, `0u8`, or `1u8 | 2u8`.
+    fn parse_match_pattern(&mut self) -> Result<MatchPattern> {
+        let first = self.parse_single_match_pattern()?;
+        if !self.check(&Token::BitOr) {
+            return Ok(first);
+        }
+
+        // Parse the alternatives, which are separated by `|`.
+        let mut patterns = vec![first];
+        while self.eat(&Token::BitOr) {
+            patterns.push(self.parse_single_match_pattern()?);
+        }
+        let span = patterns[0].span() + patterns[patterns.len() - 1].span();
+        Ok(MatchPattern::Or(patterns, span))
+    }
+
+    /// Returns a [`MatchPattern`] if the next tokens represent a pattern without alternatives.
+    fn parse_single_match_pattern(&mut self) -> Result<MatchPattern> {
+        if self.eat(&Token::Underscore) {
+            return Ok(MatchPattern::Wildcard(self.prev_token.span));
+        }
+
+        // A pattern that is not a variant of an enum is a literal or a constant, e.g. `0u8`, `-1i8`, or `MAX`.
+        if !matches!((&self.token.token, self.look_ahead(1, |t| &t.token)), (Token::Identifier(_), Token::DoubleColon))
+        {
+            return Ok(MatchPattern::Constant(self.parse_unary_expression()?));
+        }
+
+        let enum_name = self.expect_identifier()?;
+        self.expect(&Token::DoubleColon)?;
+        let variant = self.expect_identifier()?;
+
+        // Parse the variable bound to the payload, if any.
+        let (binding, span) = match self.eat(&Token::LeftParen) {
+            true => {
+                let binding = self.expect_identifier()?;
+                (Some(binding), enum_name.span + self.expect(&Token::RightParen)?)
+            }
+            false => (None, enum_name.span + variant.span),
+        };
+
+        Ok(MatchPattern::Variant(VariantPattern { enum_name, variant, binding, span }))
+    }
+
+    /// Parses the expression being matched and the arms of a `match`, following the `match` keyword.
+    /// The arms are separated by `sep`, and the body of each arm is parsed by `parse_body`.
+    pub(super) fn parse_match_arms<T: Node>(
+        &mut self,
+        sep: Option<Token>,
+        mut parse_body: impl FnMut(&mut Self) -> Result<T>,
+    ) -> Result<(Expression, Vec<MatchArm<T>>, Span)> {
+        // The expression being matched cannot be a struct expression, as its `{` would be ambiguous.
+        let prior_disallow_struct_construction = core::mem::replace(&mut self.disallow_struct_construction, true);
+        let expression = self.parse_conditional_expression()?;
+        self.disallow_struct_construction = false;
+
+        let (arms, _, span) = self.parse_list(Delimiter::Brace, sep, |p| {
+            let pattern = p.parse_match_pattern()?;
+            p.expect(&Token::BigArrow)?;
+            let body = parse_body(p)?;
+            Ok(Some(MatchArm { span: pattern.span() + body.span(), pattern, body }))
+        })?;
+        self.disallow_struct_construction = prior_disallow_struct_construction;
+
+        Ok((expression, arms, span))
+    }
+
     /// Parses a tuple of `Expression` AST nodes.
     pub(crate) fn parse_expr_tuple(&mut self) -> Result<(Vec<Expression>, bool, Span)> {
         self.parse_paren_comma_list(|p| p.parse_expression().map(Some))
@@ -544,15 +612,36 @@ impl ParserContext<'_> {
     }
 
     /// Returns an [`Expression`] AST node if the next tokens represent an array initialization expression.
+    /// The array is either a list of elements, e.g. `[a, b, c]`, or a repeated element, e.g. `[a; 3]`.
     fn parse_array_expression(&mut self) -> Result<Expression> {
-        let (elements, _, span) = self.parse_bracket_comma_list(|p| p.parse_expression().map(Some))?;
+        let mut count = None;
+        let mut is_first = true;
+        let (elements, _, span) = self.parse_bracket_comma_list(|p| {
+            let element = p.parse_expression()?;
+            // If the first element is followed by a semicolon, parse the number of repetitions, which must close the array.
+            if core::mem::take(&mut is_first) && p.eat(&Token::Semicolon) {
+                count = Some(p.eat_whole_number()?.0);
+                if !p.check(&Token::RightSquare) {
+                    return p.unexpected(&Token::RightSquare);
+                }
+            }
+            Ok(Some(element))
+        })?;
 
-        match elements.is_empty() {
+        match (elements.is_empty(), count) {
             // If the array expression is empty, return an error.
-            true => Err(ParserError::array_must_have_at_least_one_element("expression", span).into()),
+            (true, _) => Err(ParserError::array_must_have_at_least_one_element("expression", span).into()),
+            // If the element is repeated, return an array repeat expression.
+            // Note: This is the only place where `RepeatExpression` is constructed in the parser.
+            (false, Some(count)) => Ok(Expression::Repeat(RepeatExpression {
+                expr: Box::new(elements.into_iter().next().unwrap()),
+                count,
+                span,
+                id: self.node_builder.next_id(),
+            })),
             // Otherwise, return an array expression.
             // Note: This is the only place where `ArrayExpression` is constructed in the parser.
-            false => Ok(Expression::Array(ArrayExpression { elements, span, id: self.node_builder.next_id() })),
+            (false, None) => Ok(Expression::Array(ArrayExpression { elements, span, id: self.node_builder.next_id() })),
         }
     }
 
@@ -726,6 +815,15 @@ impl ParserContext<'_> {
                     Expression::Identifier(ident)
                 }
             }
+            Token::Match => {
+                let (expression, arms, end) = self.parse_match_arms(Some(Token::Comma), |p| p.parse_expression())?;
+                Expression::Match(MatchExpression {
+                    expression: Box::new(expression),
+                    arms,
+                    span: span + end,
+                    id: self.node_builder.next_id(),
+                })
+            }
             Token::SelfLower => {
                 Expression::Identifier(Identifier { name: sym::SelfLower, span, id: self.node_builder.next_id() })
             }
@@ -15,11 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use crate::parse_ast;
-use leo_errors::{CompilerError, ParserError, Result};
-use leo_span::{source_map::FileName, symbol::with_session_globals};
-
-use std::fs;
+use leo_errors::{ParserError, Result};
 
 impl ParserContext<'_> {
     /// Returns a [`Program`] AST if all tokens can be consumed and represent a valid Leo program.
@@ -67,8 +63,8 @@ impl ParserContext<'_> {
         )
     }
 
-    // TODO: remove import resolution from parser.
-    /// Parses an import statement `import foo.leo;`.
+    /// Parses an import statement `import foo.leo;`, or `import foo.aleo;` for a program compiled to Aleo instructions.
+    /// The imported program is left empty; it is loaded from the package when imports are resolved.
     pub(super) fn parse_import(&mut self) -> Result<(Symbol, (Program, Span))> {
         // Parse `import`.
         let start = self.expect(&Token::Import)?;
@@ -76,42 +72,17 @@ impl ParserContext<'_> {
         // Parse `foo`.
         let import_name = self.expect_identifier()?;
 
-        // Parse `.leo`.
+        // Parse `.leo` or `.aleo`.
+        // Note that the file of the imported program is looked up when imports are resolved, whichever is written.
         self.expect(&Token::Dot)?;
-        if !self.eat(&Token::Leo) {
-            // Throw error for non-leo files.
+        if !self.eat(&Token::Leo) && !self.eat(&Token::Identifier(sym::aleo)) {
+            // Throw error for other files.
             return Err(ParserError::leo_imports_only(self.token.span).into());
         }
 
         let end = self.expect(&Token::Semicolon)?;
 
-        // Tokenize and parse import file.
-        // Todo: move this to a different module.
-        let mut import_file_path =
-            std::env::current_dir().map_err(|err| CompilerError::cannot_open_cwd(err, self.token.span))?;
-        import_file_path.push("imports");
-        import_file_path.push(format!("{}.leo", import_name.name));
-
-        // Throw an error if the import file doesn't exist.
-        if !import_file_path.exists() {
-            return Err(CompilerError::import_not_found(import_file_path.display(), self.prev_token.span).into());
-        }
-
-        // Read the import file into string.
-        // Todo: protect against cyclic imports.
-        let program_string =
-            fs::read_to_string(&import_file_path).map_err(|e| CompilerError::file_read_error(&import_file_path, e))?;
-
-        // Create import file name.
-        let name: FileName = FileName::Real(import_file_path);
-
-        // Register the source (`program_string`) in the source map.
-        let prg_sf = with_session_globals(|s| s.source_map.new_source(&program_string, name));
-
-        // Use the parser to construct the imported abstract syntax tree (ast).
-        let program_ast = parse_ast(self.handler, self.node_builder, &prg_sf.src, prg_sf.start_pos)?;
-
-        Ok((import_name.name, (program_ast.into_repr(), start + end)))
+        Ok((import_name.name, (Program::default(), start + end)))
     }
 
     /// Parsers a program scope `program foo.aleo { ... }`.
@@ -138,21 +109,63 @@ impl ParserContext<'_> {
         self.expect(&Token::LeftCurly)?;
 
         // Parse the body of the program scope.
+        let mut program_scope = self.parse_program_scope_items(program_id)?;
+
+        // Parse `}`.
+        let end = self.expect(&Token::RightCurly)?;
+
+        program_scope.span = start + end;
+        Ok(program_scope)
+    }
+
+    /// Parses a module, that is, the items of the program scope `program_id` which are defined in a file of their own.
+    pub fn parse_module(&mut self, program_id: ProgramId) -> Result<ProgramScope> {
+        let start = self.token.span;
+        let mut program_scope = self.parse_program_scope_items(program_id)?;
+
+        // The items of a module are not enclosed in braces.
+        if self.has_next() {
+            return Err(Self::unexpected_item(&self.token, &[Token::Eof]).into());
+        }
+
+        program_scope.span = start + self.token.span;
+        Ok(program_scope)
+    }
+
+    /// Parses the items of the program scope `program_id`, up to its closing `}` or the end of the file.
+    /// Note that the span of the returned program scope is left for the caller to set.
+    fn parse_program_scope_items(&mut self, program_id: ProgramId) -> Result<ProgramScope> {
+        let mut modules: Vec<(Symbol, Module)> = Vec::new();
         let mut consts: Vec<(Symbol, ConstDeclaration)> = Vec::new();
+        let mut type_aliases: Vec<(Symbol, TypeAlias)> = Vec::new();
         let mut functions: Vec<(Symbol, Function)> = Vec::new();
         let mut structs: Vec<(Symbol, Struct)> = Vec::new();
+        let mut enums: Vec<(Symbol, Enum)> = Vec::new();
         let mut mappings: Vec<(Symbol, Mapping)> = Vec::new();
 
         while self.has_next() {
             match &self.token.token {
+                // Note that `mod` is not a keyword, since it is also the name of an operator method.
+                Token::Identifier(name) if *name == sym::Mod => {
+                    let (id, module) = self.parse_module_declaration()?;
+                    modules.push((id, module));
+                }
                 Token::Const => {
                     let declaration = self.parse_const_declaration_statement()?;
                     consts.push((Symbol::intern(&declaration.place.to_string()), declaration));
                 }
+                Token::Type => {
+                    let (id, type_alias) = self.parse_type_alias()?;
+                    type_aliases.push((id, type_alias));
+                }
                 Token::Struct | Token::Record => {
                     let (id, struct_) = self.parse_struct()?;
                     structs.push((id, struct_));
                 }
+                Token::Enum => {
+                    let (id, enum_) = self.parse_enum()?;
+                    enums.push((id, enum_));
+                }
                 Token::Mapping => {
                     let (id, mapping) = self.parse_mapping()?;
                     mappings.push((id, mapping));
@@ -166,6 +179,8 @@ impl ParserContext<'_> {
                     return Err(Self::unexpected_item(&self.token, &[
                         Token::Struct,
                         Token::Record,
+                        Token::Enum,
+                        Token::Type,
                         Token::Mapping,
                         Token::At,
                         Token::Function,
@@ -177,10 +192,17 @@ impl ParserContext<'_> {
             }
         }
 
-        // Parse `}`.
-        let end = self.expect(&Token::RightCurly)?;
-
-        Ok(ProgramScope { program_id, consts, functions, structs, mappings, span: start + end })
+        Ok(ProgramScope {
+            program_id,
+            modules,
+            consts,
+            type_aliases,
+            functions,
+            structs,
+            enums,
+            mappings,
+            span: Span::default(),
+        })
     }
 
     /// Returns a [`Vec<Member>`] AST node if the next tokens represent a struct member.
@@ -249,6 +271,50 @@ impl ParserContext<'_> {
         }))
     }
 
+    /// Parses an enum definition, e.g., `enum Phase { Open, Closed, Settled(u64) }`.
+    pub(super) fn parse_enum(&mut self) -> Result<(Symbol, Enum)> {
+        let start = self.expect(&Token::Enum)?;
+        let enum_name = self.expect_identifier()?;
+
+        let (variants, _, end) = self.parse_list(Delimiter::Brace, Some(Token::Comma), |p| {
+            let identifier = p.expect_identifier()?;
+            // A variant may carry a payload of a single type, e.g. `Settled(u64)`.
+            let (payload, span) = match p.eat(&Token::LeftParen) {
+                true => {
+                    let (type_, _) = p.parse_type()?;
+                    (Some(type_), identifier.span + p.expect(&Token::RightParen)?)
+                }
+                false => (None, identifier.span),
+            };
+            Ok(Some(EnumVariant { identifier, payload, span, id: p.node_builder.next_id() }))
+        })?;
+
+        Ok((enum_name.name, Enum {
+            identifier: enum_name,
+            variants,
+            span: start + end,
+            id: self.node_builder.next_id(),
+        }))
+    }
+
+    /// Parses a module declaration, e.g. `mod math;`.
+    pub(super) fn parse_module_declaration(&mut self) -> Result<(Symbol, Module)> {
+        let start = self.expect(&Token::Identifier(sym::Mod))?;
+        let identifier = self.expect_identifier()?;
+        let end = self.expect(&Token::Semicolon)?;
+        Ok((identifier.name, Module { identifier, span: start + end, id: self.node_builder.next_id() }))
+    }
+
+    /// Parses a type alias declaration, e.g. `type Board = [[u8; 3]; 3];`.
+    pub(super) fn parse_type_alias(&mut self) -> Result<(Symbol, TypeAlias)> {
+        let start = self.expect(&Token::Type)?;
+        let identifier = self.expect_identifier()?;
+        self.expect(&Token::Assign)?;
+        let (type_, _) = self.parse_type()?;
+        let end = self.expect(&Token::Semicolon)?;
+        Ok((identifier.name, TypeAlias { identifier, type_, span: start + end, id: self.node_builder.next_id() }))
+    }
+
     /// Parses a mapping declaration, e.g. `mapping balances: address => u128`.
     pub(super) fn parse_mapping(&mut self) -> Result<(Symbol, Mapping)> {
         let start = self.expect(&Token::Mapping)?;
@@ -45,6 +45,19 @@ pub fn parse(handler: &Handler, node_builder: &NodeBuilder, source: &str, start_
     tokens.parse_program()
 }
 
+/// Parses a module at the given `source` code text into the items of the program scope `program_id`.
+pub fn parse_module(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    source: &str,
+    start_pos: BytePos,
+    program_id: ProgramId,
+) -> Result<ProgramScope> {
+    let mut tokens = ParserContext::new(handler, node_builder, crate::tokenize(source, start_pos)?);
+
+    tokens.parse_module(program_id)
+}
+
 /// Parses an input file at the given file `path` and `source` code text.
 pub fn parse_input(
     handler: &Handler,
@@ -42,12 +42,15 @@ impl ParserContext<'_> {
         match &self.token.token {
             Token::Return => Ok(Statement::Return(self.parse_return_statement()?)),
             Token::If => Ok(Statement::Conditional(self.parse_conditional_statement()?)),
-            Token::For => Ok(Statement::Iteration(Box::new(self.parse_loop_statement()?))),
+            Token::For => self.parse_loop_statement(),
+            Token::Match => Ok(Statement::Match(self.parse_match_statement()?)),
+            Token::Break => Ok(Statement::Break(self.parse_break_statement()?)),
+            Token::Continue => Ok(Statement::Continue(self.parse_continue_statement()?)),
             Token::Assert | Token::AssertEq | Token::AssertNeq => Ok(self.parse_assert_statement()?),
             Token::Let => Ok(Statement::Definition(self.parse_definition_statement()?)),
             Token::Const => Ok(Statement::Const(self.parse_const_declaration_statement()?)),
             Token::LeftCurly => Ok(Statement::Block(self.parse_block()?)),
-            Token::Console => Err(ParserError::console_statements_are_not_yet_supported(self.token.span).into()),
+            Token::Console => Ok(Statement::Console(self.parse_console_statement()?)),
             Token::Finalize => Err(ParserError::finalize_statements_are_deprecated(self.token.span).into()),
             _ => Ok(self.parse_assign_statement()?),
         }
@@ -116,9 +119,8 @@ impl ParserContext<'_> {
             // Construct the span for the statement.
             let span = place.span() + value.span();
 
-            // Construct a copy of the lhs with a unique id.
-            let mut left = place.clone();
-            left.set_id(self.node_builder.next_id());
+            // Construct a copy of the lhs with unique ids.
+            let left = self.copy_place(&place);
 
             // Simplify complex assignments into simple assignments.
             // For example, `x += 1` becomes `x = x + 1`, while simple assignments like `x = y` remain unchanged.
@@ -166,6 +168,34 @@ impl ParserContext<'_> {
         }
     }
 
+    /// Returns a copy of the place of an assignment, in which every node is given a fresh id.
+    fn copy_place(&self, place: &Expression) -> Expression {
+        match place {
+            Expression::Access(AccessExpression::Member(access)) => {
+                Expression::Access(AccessExpression::Member(MemberAccess {
+                    inner: Box::new(self.copy_place(&access.inner)),
+                    name: Identifier { id: self.node_builder.next_id(), ..access.name },
+                    span: access.span,
+                    id: self.node_builder.next_id(),
+                }))
+            }
+            Expression::Access(AccessExpression::Array(access)) => {
+                Expression::Access(AccessExpression::Array(ArrayAccess {
+                    array: Box::new(self.copy_place(&access.array)),
+                    index: Box::new(self.copy_place(&access.index)),
+                    span: access.span,
+                    id: self.node_builder.next_id(),
+                }))
+            }
+            // Note that the remaining expressions are not valid places, so only the outermost id is refreshed.
+            expression => {
+                let mut expression = expression.clone();
+                expression.set_id(self.node_builder.next_id());
+                expression
+            }
+        }
+    }
+
     /// Returns a [`Block`] AST node if the next tokens represent a block of statements.
     pub(super) fn parse_block(&mut self) -> Result<Block> {
         self.parse_list(Delimiter::Brace, None, |p| p.parse_statement().map(Some)).map(|(statements, _, span)| Block {
@@ -208,6 +238,20 @@ impl ParserContext<'_> {
         Ok(ReturnStatement { span, expression, finalize_arguments: finalize_args, id: self.node_builder.next_id() })
     }
 
+    /// Returns a [`BreakStatement`] AST node if the next tokens represent a break statement.
+    fn parse_break_statement(&mut self) -> Result<BreakStatement> {
+        let span = self.expect(&Token::Break)?;
+        self.expect(&Token::Semicolon)?;
+        Ok(BreakStatement { span, id: self.node_builder.next_id() })
+    }
+
+    /// Returns a [`ContinueStatement`] AST node if the next tokens represent a continue statement.
+    fn parse_continue_statement(&mut self) -> Result<ContinueStatement> {
+        let span = self.expect(&Token::Continue)?;
+        self.expect(&Token::Semicolon)?;
+        Ok(ContinueStatement { span, id: self.node_builder.next_id() })
+    }
+
     /// Returns a [`ConditionalStatement`] AST node if the next tokens represent a conditional statement.
     fn parse_conditional_statement(&mut self) -> Result<ConditionalStatement> {
         let start = self.expect(&Token::If)?;
@@ -234,27 +278,58 @@ impl ParserContext<'_> {
         })
     }
 
-    /// Returns an [`IterationStatement`] AST node if the next tokens represent an iteration statement.
-    fn parse_loop_statement(&mut self) -> Result<IterationStatement> {
+    /// Returns a [`MatchStatement`] AST node if the next tokens represent a match statement.
+    fn parse_match_statement(&mut self) -> Result<MatchStatement> {
+        let start = self.expect(&Token::Match)?;
+        let (expression, arms, end) = self.parse_match_arms(None, |p| p.parse_block())?;
+        Ok(MatchStatement { expression, arms, span: start + end, id: self.node_builder.next_id() })
+    }
+
+    /// Returns an [`IterationStatement`] or [`ArrayIterationStatement`] AST node if the next tokens represent an iteration statement.
+    fn parse_loop_statement(&mut self) -> Result<Statement> {
         let start_span = self.expect(&Token::For)?;
         let ident = self.expect_identifier()?;
-        self.expect(&Token::Colon)?;
-        let type_ = self.parse_type()?;
+        // The type of the loop variable may be omitted when iterating over an array.
+        let type_ = match self.eat(&Token::Colon) {
+            true => Some(self.parse_type()?.0),
+            false => None,
+        };
         self.expect(&Token::In)?;
 
-        // Parse iteration range.
-        let start = self.parse_expression()?;
-        self.expect(&Token::DotDot)?;
+        // Parse the start of the iteration range, or the array to iterate over.
+        self.disallow_struct_construction = true;
+        let start = self.parse_conditional_expression()?;
+        self.disallow_struct_construction = false;
+
+        // If the expression is not followed by `..`, the loop iterates over the elements of an array.
+        if !self.eat(&Token::DotDot) {
+            let block = self.parse_block()?;
+            return Ok(Statement::ArrayIteration(Box::new(ArrayIterationStatement {
+                span: start_span + block.span,
+                variable: ident,
+                type_,
+                array: start,
+                block,
+                id: self.node_builder.next_id(),
+            })));
+        }
+
+        // Parse the end of the iteration range.
         self.disallow_struct_construction = true;
         let stop = self.parse_conditional_expression()?;
         self.disallow_struct_construction = false;
 
+        let type_ = match type_ {
+            Some(type_) => type_,
+            None => return Err(ParserError::range_loop_variable_must_have_type(ident, ident.span).into()),
+        };
+
         let block = self.parse_block()?;
 
-        Ok(IterationStatement {
+        Ok(Statement::Iteration(Box::new(IterationStatement {
             span: start_span + block.span,
             variable: ident,
-            type_: type_.0,
+            type_,
             start,
             start_value: Default::default(),
             stop,
@@ -262,16 +337,26 @@ impl ParserContext<'_> {
             inclusive: false,
             block,
             id: self.node_builder.next_id(),
-        })
+        })))
     }
 
     /// Returns a [`ConsoleStatement`] AST node if the next tokens represent a console statement.
-    #[allow(dead_code)]
     fn parse_console_statement(&mut self) -> Result<ConsoleStatement> {
         let keyword = self.expect(&Token::Console)?;
         self.expect(&Token::Dot)?;
-        let identifier = self.expect_identifier()?;
-        let (span, function) = match identifier.name {
+        // Note that `assert`, `assert_eq`, and `assert_neq` are keywords.
+        let (name, name_span) = match &self.token.token {
+            Token::Assert | Token::AssertEq | Token::AssertNeq => {
+                let name = self.token.token.keyword_to_symbol().unwrap();
+                self.bump();
+                (name, self.prev_token.span)
+            }
+            _ => {
+                let identifier = self.expect_identifier()?;
+                (identifier.name, identifier.span)
+            }
+        };
+        let (span, function) = match name {
             sym::assert => {
                 self.expect(&Token::LeftParen)?;
                 let expr = self.parse_expression()?;
@@ -294,12 +379,26 @@ impl ParserContext<'_> {
                 self.expect(&Token::RightParen)?;
                 (left.span() + right.span(), ConsoleFunction::AssertNeq(left, right))
             }
+            sym::log => {
+                self.expect(&Token::LeftParen)?;
+                let format = match &self.token.token {
+                    Token::StaticString(format) => format.clone(),
+                    _ => return self.unexpected("a format string"),
+                };
+                self.bump();
+                let mut args = Vec::new();
+                while self.eat(&Token::Comma) {
+                    args.push(self.parse_expression()?);
+                }
+                let span = self.expect(&Token::RightParen)?;
+                (span, ConsoleFunction::Log(format, args))
+            }
             symbol => {
-                // Not sure what it is, assume it's `log`.
+                // Not sure what it is, assume it's `assert`.
                 self.emit_err(ParserError::unexpected_ident(
                     symbol,
-                    &["assert", "assert_eq", "assert_neq"],
-                    identifier.span,
+                    &["assert", "assert_eq", "assert_neq", "log"],
+                    name_span,
                 ));
                 (
                     Default::default(),
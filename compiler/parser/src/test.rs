@@ -214,6 +214,25 @@ impl Namespace for InputNamespace {
     }
 }
 
+struct FormatNamespace;
+
+impl Namespace for FormatNamespace {
+    fn parse_type(&self) -> ParseType {
+        ParseType::Whole
+    }
+
+    fn run_test(&self, test: Test) -> Result<Value, String> {
+        create_session_if_not_set_then(|s| {
+            let sf = s.source_map.new_source(&test.content, FileName::Custom("test".into()));
+            let (handler, buf) = Handler::new_with_buf();
+            handler
+                .extend_if_error(crate::format_source(&handler, &NodeBuilder::default(), &sf.src, sf.start_pos))
+                .map(Value::String)
+                .map_err(|_| buf.extract_errs().to_string() + &buf.extract_warnings().to_string())
+        })
+    }
+}
+
 struct TestRunner;
 
 impl Runner for TestRunner {
@@ -224,6 +243,7 @@ impl Runner for TestRunner {
             "ParseStatement" => Box::new(ParseStatementNamespace),
             "Serialize" => Box::new(SerializeNamespace),
             "Input" => Box::new(InputNamespace),
+            "Format" => Box::new(FormatNamespace),
             "Token" => Box::new(TokenNamespace),
             _ => return None,
         })
@@ -385,10 +385,13 @@ impl Token {
                     "assert_neq" => Token::AssertNeq,
                     "block" => Token::Block,
                     "bool" => Token::Bool,
+                    "break" => Token::Break,
                     "console" => Token::Console,
                     "const" => Token::Const,
                     "constant" => Token::Constant,
+                    "continue" => Token::Continue,
                     "else" => Token::Else,
+                    "enum" => Token::Enum,
                     "false" => Token::False,
                     "field" => Token::Field,
                     "finalize" => Token::Finalize,
@@ -407,6 +410,7 @@ impl Token {
                     "let" => Token::Let,
                     "leo" => Token::Leo,
                     "mapping" => Token::Mapping,
+                    "match" => Token::Match,
                     "private" => Token::Private,
                     "program" => Token::Program,
                     "public" => Token::Public,
@@ -420,6 +424,7 @@ impl Token {
                     "then" => Token::Then,
                     "transition" => Token::Transition,
                     "true" => Token::True,
+                    "type" => Token::Type,
                     "u8" => Token::U8,
                     "u16" => Token::U16,
                     "u32" => Token::U32,
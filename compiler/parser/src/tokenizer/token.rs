@@ -113,10 +113,13 @@ pub enum Token {
     Assert,
     AssertEq,
     AssertNeq,
+    Break,
     Console,
     Const,
     Constant,
+    Continue,
     Else,
+    Enum,
     Finalize,
     For,
     Function,
@@ -126,6 +129,7 @@ pub enum Token {
     Inline,
     Let,
     Mapping,
+    Match,
     Private,
     Program,
 
@@ -136,6 +140,7 @@ pub enum Token {
     Struct,
     Then,
     Transition,
+    Type,
 
     // Meta Tokens
     Block,
@@ -154,10 +159,13 @@ pub const KEYWORD_TOKENS: &[Token] = &[
     Token::AssertEq,
     Token::AssertNeq,
     Token::Bool,
+    Token::Break,
     Token::Console,
     Token::Const,
     Token::Constant,
+    Token::Continue,
     Token::Else,
+    Token::Enum,
     Token::False,
     Token::Field,
     Token::Finalize,
@@ -175,6 +183,7 @@ pub const KEYWORD_TOKENS: &[Token] = &[
     Token::Inline,
     Token::Let,
     Token::Mapping,
+    Token::Match,
     Token::Private,
     Token::Program,
     Token::Public,
@@ -188,6 +197,7 @@ pub const KEYWORD_TOKENS: &[Token] = &[
     Token::Then,
     Token::Transition,
     Token::True,
+    Token::Type,
     Token::U8,
     Token::U16,
     Token::U32,
@@ -211,10 +221,13 @@ impl Token {
             Token::AssertNeq => sym::assert_neq,
             Token::Block => sym::block,
             Token::Bool => sym::bool,
+            Token::Break => sym::Break,
             Token::Console => sym::console,
             Token::Const => sym::Const,
             Token::Constant => sym::constant,
+            Token::Continue => sym::Continue,
             Token::Else => sym::Else,
+            Token::Enum => sym::Enum,
             Token::False => sym::False,
             Token::Field => sym::field,
             Token::Finalize => sym::finalize,
@@ -233,6 +246,7 @@ impl Token {
             Token::Let => sym::Let,
             Token::Leo => sym::leo,
             Token::Mapping => sym::mapping,
+            Token::Match => sym::Match,
             Token::Private => sym::private,
             Token::Program => sym::program,
             Token::Public => sym::public,
@@ -246,6 +260,7 @@ impl Token {
             Token::Then => sym::then,
             Token::Transition => sym::transition,
             Token::True => sym::True,
+            Token::Type => sym::Type,
             Token::U8 => sym::u8,
             Token::U16 => sym::u16,
             Token::U32 => sym::u32,
@@ -345,10 +360,13 @@ impl fmt::Display for Token {
             Assert => write!(f, "assert"),
             AssertEq => write!(f, "assert_eq"),
             AssertNeq => write!(f, "assert_neq"),
+            Break => write!(f, "break"),
             Console => write!(f, "console"),
             Const => write!(f, "const"),
             Constant => write!(f, "constant"),
+            Continue => write!(f, "continue"),
             Else => write!(f, "else"),
+            Enum => write!(f, "enum"),
             Finalize => write!(f, "finalize"),
             For => write!(f, "for"),
             Function => write!(f, "function"),
@@ -358,6 +376,7 @@ impl fmt::Display for Token {
             Inline => write!(f, "inline"),
             Let => write!(f, "let"),
             Mapping => write!(f, "mapping"),
+            Match => write!(f, "match"),
             Private => write!(f, "private"),
             Program => write!(f, "program"),
             Public => write!(f, "public"),
@@ -366,6 +385,7 @@ impl fmt::Display for Token {
             Struct => write!(f, "struct"),
             Then => write!(f, "then"),
             Transition => write!(f, "transition"),
+            Type => write!(f, "type"),
             Block => write!(f, "block"),
             Leo => write!(f, "leo"),
             Eof => write!(f, "<eof>"),
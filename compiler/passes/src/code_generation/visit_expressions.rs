@@ -59,6 +59,12 @@ impl<'a> CodeGenerator<'a> {
             Expression::Err(expr) => self.visit_err(expr),
             Expression::Identifier(expr) => self.visit_identifier(expr),
             Expression::Literal(expr) => self.visit_value(expr),
+            Expression::Match(_) => {
+                unreachable!("`MatchExpression`s should not be in the AST at this phase of compilation.")
+            }
+            Expression::Repeat(_) => {
+                unreachable!("`RepeatExpression`s should not be in the AST at this phase of compilation.")
+            }
             Expression::Ternary(expr) => self.visit_ternary(expr),
             Expression::Tuple(expr) => self.visit_tuple(expr),
             Expression::Unary(expr) => self.visit_unary(expr),
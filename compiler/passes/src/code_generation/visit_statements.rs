@@ -39,17 +39,29 @@ use std::fmt::Write as _;
 impl<'a> CodeGenerator<'a> {
     fn visit_statement(&mut self, input: &'a Statement) -> String {
         match input {
+            Statement::ArrayIteration(_) => {
+                unreachable!("`ArrayIterationStatement`s should not be in the AST at this phase of compilation.")
+            }
             Statement::Assert(stmt) => self.visit_assert(stmt),
             Statement::Assign(stmt) => self.visit_assign(stmt),
             Statement::Block(stmt) => self.visit_block(stmt),
+            Statement::Break(_) => {
+                unreachable!("`BreakStatement`s should not be in the AST at this phase of compilation.")
+            }
             Statement::Conditional(stmt) => self.visit_conditional(stmt),
             Statement::Console(stmt) => self.visit_console(stmt),
             Statement::Const(_) => {
                 unreachable!("`ConstStatement`s should not be in the AST at this phase of compilation.")
             }
+            Statement::Continue(_) => {
+                unreachable!("`ContinueStatement`s should not be in the AST at this phase of compilation.")
+            }
             Statement::Definition(stmt) => self.visit_definition(stmt),
             Statement::Expression(stmt) => self.visit_expression_statement(stmt),
             Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Match(_) => {
+                unreachable!("`MatchStatement`s should not be in the AST at this phase of compilation.")
+            }
             Statement::Return(stmt) => self.visit_return(stmt),
         }
     }
@@ -241,7 +253,7 @@ impl<'a> CodeGenerator<'a> {
     }
 
     fn visit_console(&mut self, _: &'a ConsoleStatement) -> String {
-        unreachable!("Parsing guarantees that `ConsoleStatement`s are not present in the AST.")
+        unreachable!("The console removal pass guarantees that `ConsoleStatement`s are not present in the AST.")
     }
 
     pub(crate) fn visit_block(&mut self, input: &'a Block) -> String {
@@ -25,9 +25,15 @@ pub type StructGraph = DiGraph<Symbol>;
 /// A call graph.
 pub type CallGraph = DiGraph<Symbol>;
 
+/// A dependency graph of the program-scope consts.
+pub type ConstGraph = DiGraph<Symbol>;
+
 /// An import dependency graph.
 pub type ImportGraph = DiGraph<Symbol>;
 
+/// A dependency graph of the type aliases.
+pub type TypeAliasGraph = DiGraph<Symbol>;
+
 /// A node in a graph.
 pub trait Node: Copy + 'static + Eq + PartialEq + Debug + Hash {}
 
@@ -40,7 +40,7 @@ pub struct FunctionSymbol {
     /// Is this function a transition, inlined, or a regular function?.
     pub variant: Variant,
     /// The `Span` associated with the function.
-    pub(crate) _span: Span,
+    pub span: Span,
     /// The inputs to the function.
     pub(crate) input: Vec<Input>,
     /// Metadata associated with the finalize block.
@@ -53,7 +53,7 @@ impl SymbolTable {
             id,
             output_type: func.output_type.clone(),
             variant: func.variant,
-            _span: func.span,
+            span: func.span,
             input: func.input.clone(),
             finalize: func.finalize.as_ref().map(|finalize| FinalizeData {
                 input: finalize.input.clone(),
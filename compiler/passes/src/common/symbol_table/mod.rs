@@ -22,7 +22,7 @@ pub use variable_symbol::*;
 
 use std::cell::RefCell;
 
-use leo_ast::{normalize_json_value, remove_key_from_json, Function, Struct};
+use leo_ast::{normalize_json_value, remove_key_from_json, Enum, Function, Struct, TypeAlias};
 use leo_errors::{AstError, Result};
 use leo_span::{Span, Symbol};
 
@@ -43,6 +43,12 @@ pub struct SymbolTable {
     /// Maps struct names to struct definitions.
     /// This field is populated at a first pass.
     pub structs: IndexMap<Symbol, Struct>,
+    /// Maps enum names to enum definitions.
+    /// This field is populated at a first pass.
+    pub enums: IndexMap<Symbol, Enum>,
+    /// Maps type alias names to type alias definitions.
+    /// This field is populated at a first pass, and the aliases are resolved before type checking.
+    pub type_aliases: IndexMap<Symbol, TypeAlias>,
     /// The variables defined in a scope.
     /// This field is populated as necessary.
     pub(crate) variables: IndexMap<Symbol, VariableSymbol>,
@@ -65,6 +71,10 @@ impl SymbolTable {
                 true => Err(AstError::shadowed_record(symbol, span).into()),
                 false => Err(AstError::shadowed_struct(symbol, span).into()),
             }
+        } else if self.enums.contains_key(&symbol) {
+            Err(AstError::shadowed_enum(symbol, span).into())
+        } else if self.type_aliases.contains_key(&symbol) {
+            Err(AstError::shadowed_type_alias(symbol, span).into())
         } else if let Some(parent) = self.parent.as_ref() {
             parent.check_shadowing(symbol, span)
         } else {
@@ -96,6 +106,20 @@ impl SymbolTable {
         Ok(())
     }
 
+    /// Inserts an enum into the symbol table.
+    pub fn insert_enum(&mut self, symbol: Symbol, insert: &Enum) -> Result<()> {
+        self.check_shadowing(symbol, insert.span)?;
+        self.enums.insert(symbol, insert.clone());
+        Ok(())
+    }
+
+    /// Inserts a type alias into the symbol table.
+    pub fn insert_type_alias(&mut self, symbol: Symbol, insert: &TypeAlias) -> Result<()> {
+        self.check_shadowing(symbol, insert.span)?;
+        self.type_aliases.insert(symbol, insert.clone());
+        Ok(())
+    }
+
     /// Inserts a variable into the symbol table.
     pub fn insert_variable(&mut self, symbol: Symbol, insert: VariableSymbol) -> Result<()> {
         self.check_shadowing(symbol, insert.span)?;
@@ -136,6 +160,28 @@ impl SymbolTable {
         }
     }
 
+    /// Attempts to lookup an enum in the symbol table.
+    pub fn lookup_enum(&self, symbol: Symbol) -> Option<&Enum> {
+        if let Some(enum_) = self.enums.get(&symbol) {
+            Some(enum_)
+        } else if let Some(parent) = self.parent.as_ref() {
+            parent.lookup_enum(symbol)
+        } else {
+            None
+        }
+    }
+
+    /// Attempts to lookup a type alias in the symbol table.
+    pub fn lookup_type_alias(&self, symbol: Symbol) -> Option<&TypeAlias> {
+        if let Some(type_alias) = self.type_aliases.get(&symbol) {
+            Some(type_alias)
+        } else if let Some(parent) = self.parent.as_ref() {
+            parent.lookup_type_alias(symbol)
+        } else {
+            None
+        }
+    }
+
     /// Attempts to lookup a variable in the symbol table.
     pub fn lookup_variable(&self, symbol: Symbol) -> Option<&VariableSymbol> {
         if let Some(var) = self.variables.get(&symbol) {
@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{AccessExpression, BinaryOperation, Expression, Identifier, Type, UnaryOperation};
+use leo_span::{sym, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+
+/// An operand of a flattened expression.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum Operand {
+    Identifier(Symbol),
+    Literal(String),
+}
+
+impl Operand {
+    fn new(expression: &Expression) -> Option<Self> {
+        match expression {
+            Expression::Identifier(identifier) => Some(Operand::Identifier(identifier.name)),
+            Expression::Literal(literal) => Some(Operand::Literal(literal.to_string())),
+            _ => None,
+        }
+    }
+}
+
+/// The structure of a pure expression, ignoring spans and node IDs.
+/// Two expressions with the same key are guaranteed to evaluate to the same value.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum ExpressionKey {
+    ArrayAccess(Operand, Operand),
+    AssociatedFunction(Symbol, Symbol, Vec<Operand>),
+    Binary(BinaryOperation, Operand, Operand),
+    Cast(Operand, Type),
+    MemberAccess(Operand, Symbol),
+    Ternary(Operand, Operand, Operand),
+    TupleAccess(Operand, usize),
+    Unary(UnaryOperation, Operand),
+}
+
+impl ExpressionKey {
+    /// Returns the key of the expression, if it is a pure expression over identifiers and literals.
+    pub(crate) fn new(expression: &Expression) -> Option<Self> {
+        use BinaryOperation::*;
+
+        Some(match expression {
+            Expression::Access(AccessExpression::Array(access)) => {
+                ExpressionKey::ArrayAccess(Operand::new(&access.array)?, Operand::new(&access.index)?)
+            }
+            Expression::Access(AccessExpression::AssociatedFunction(function)) => {
+                // Mapping operations and random number generation depend on state, so they are never merged.
+                if matches!(function.ty, Type::Identifier(Identifier { name: sym::Mapping | sym::ChaCha, .. })) {
+                    return None;
+                }
+                // Identifiers are compared by their spans as well, so the type is keyed by its name.
+                let ty = match &function.ty {
+                    Type::Identifier(identifier) => identifier.name,
+                    _ => return None,
+                };
+                let arguments = function.arguments.iter().map(Operand::new).collect::<Option<Vec<_>>>()?;
+                ExpressionKey::AssociatedFunction(ty, function.name.name, arguments)
+            }
+            Expression::Access(AccessExpression::Member(access)) => {
+                ExpressionKey::MemberAccess(Operand::new(&access.inner)?, access.name.name)
+            }
+            Expression::Access(AccessExpression::Tuple(access)) => {
+                ExpressionKey::TupleAccess(Operand::new(&access.tuple)?, access.index.value())
+            }
+            Expression::Binary(binary) => {
+                let mut left = Operand::new(&binary.left)?;
+                let mut right = Operand::new(&binary.right)?;
+                // Order the operands of commutative operations, so that `a + b` and `b + a` are merged.
+                if matches!(
+                    binary.op,
+                    Add | AddWrapped
+                        | And
+                        | BitwiseAnd
+                        | BitwiseOr
+                        | Eq
+                        | Mul
+                        | MulWrapped
+                        | Nand
+                        | Neq
+                        | Nor
+                        | Or
+                        | Xor
+                ) && right < left
+                {
+                    std::mem::swap(&mut left, &mut right);
+                }
+                ExpressionKey::Binary(binary.op, left, right)
+            }
+            Expression::Cast(cast) => ExpressionKey::Cast(Operand::new(&cast.expression)?, cast.type_.clone()),
+            Expression::Ternary(ternary) => ExpressionKey::Ternary(
+                Operand::new(&ternary.condition)?,
+                Operand::new(&ternary.if_true)?,
+                Operand::new(&ternary.if_false)?,
+            ),
+            Expression::Unary(unary) => ExpressionKey::Unary(unary.op, Operand::new(&unary.receiver)?),
+            // Function calls may have side effects, and the remaining expressions do not produce instructions.
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct CommonSubexpressionEliminator {
+    /// A mapping from pure expressions to the variables holding their values in the current function body.
+    pub(crate) expressions: IndexMap<ExpressionKey, Identifier>,
+    /// A mapping from eliminated variables to the variables that replace them in the current function body.
+    pub(crate) replacements: IndexMap<Symbol, Identifier>,
+    /// The variables returned by the current function body, which must be held in distinct registers.
+    pub(crate) outputs: IndexSet<Symbol>,
+}
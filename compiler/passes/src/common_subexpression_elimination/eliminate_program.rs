@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::CommonSubexpressionEliminator;
+
+use leo_ast::{Block, Expression, Finalize, Function, ProgramReconstructor, Statement, StatementReconstructor};
+use leo_span::Symbol;
+
+use indexmap::IndexSet;
+
+impl ProgramReconstructor for CommonSubexpressionEliminator {
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        // Reset the state of the eliminator.
+        self.expressions.clear();
+        self.replacements.clear();
+        self.outputs = returned_variables(&input.block);
+
+        // Traverse the function body.
+        let block = self.reconstruct_block(input.block).0;
+
+        // Reconstruct the finalize block, if it exists.
+        let finalize = input.finalize.map(|finalize| {
+            // Reset the state of the eliminator, since finalize blocks do not share variables with the function body.
+            self.expressions.clear();
+            self.replacements.clear();
+            self.outputs = returned_variables(&finalize.block);
+
+            // Traverse the finalize block.
+            let block = self.reconstruct_block(finalize.block).0;
+
+            Finalize {
+                identifier: finalize.identifier,
+                input: finalize.input,
+                output: finalize.output,
+                output_type: finalize.output_type,
+                block,
+                span: finalize.span,
+                id: finalize.id,
+            }
+        });
+
+        Function {
+            annotations: input.annotations,
+            variant: input.variant,
+            identifier: input.identifier,
+            input: input.input,
+            output: input.output,
+            output_type: input.output_type,
+            block,
+            finalize,
+            span: input.span,
+            id: input.id,
+        }
+    }
+}
+
+/// Returns the variables returned by the block. Flattening leaves a single return statement at the end of the block.
+fn returned_variables(block: &Block) -> IndexSet<Symbol> {
+    let elements = match block.statements.last() {
+        Some(Statement::Return(statement)) => match &statement.expression {
+            Expression::Tuple(tuple) => tuple.elements.iter().collect(),
+            expression => vec![expression],
+        },
+        _ => vec![],
+    };
+
+    elements
+        .into_iter()
+        .filter_map(|element| match element {
+            Expression::Identifier(identifier) => Some(identifier.name),
+            _ => None,
+        })
+        .collect()
+}
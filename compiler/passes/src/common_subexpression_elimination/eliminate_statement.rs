@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{CommonSubexpressionEliminator, ExpressionKey};
+
+use leo_ast::{
+    AssignStatement,
+    ConditionalStatement,
+    Expression,
+    ExpressionReconstructor,
+    Identifier,
+    IterationStatement,
+    MatchStatement,
+    Node,
+    Statement,
+    StatementReconstructor,
+};
+
+impl StatementReconstructor for CommonSubexpressionEliminator {
+    /// Reconstructs an assignment statement, reusing an earlier assignment of the same expression if one exists.
+    fn reconstruct_assign(&mut self, input: AssignStatement) -> (Statement, Self::AdditionalOutput) {
+        let mut value = self.reconstruct_expression(input.value).0;
+
+        // Tuple destinations are only assigned by function calls, which are never eliminated.
+        if let Expression::Identifier(place) = &input.place {
+            match &value {
+                // The variable is a copy of another variable, so it is replaced by that variable.
+                Expression::Identifier(identifier) => {
+                    self.replacements.insert(place.name, *identifier);
+                }
+                _ => {
+                    if let Some(key) = ExpressionKey::new(&value) {
+                        match self.expressions.get(&key) {
+                            // The expression was already computed, so the variable becomes a copy of the result.
+                            // The copy does not produce an instruction and is removed by dead code elimination.
+                            // Returned variables are kept, since an output register may only be output once.
+                            Some(existing) if !self.outputs.contains(&place.name) => {
+                                self.replacements.insert(place.name, *existing);
+                                value = Expression::Identifier(Identifier {
+                                    name: existing.name,
+                                    span: value.span(),
+                                    id: value.id(),
+                                });
+                            }
+                            Some(_) => {}
+                            None => {
+                                self.expressions.insert(key, *place);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        (Statement::Assign(Box::new(AssignStatement { place: input.place, value, span: input.span, id: input.id })), ())
+    }
+
+    /// Flattening removes conditional statements from the program.
+    fn reconstruct_conditional(&mut self, _: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
+        unreachable!("`ConditionalStatement`s should not be in the AST at this phase of compilation.")
+    }
+
+    /// Loop unrolling unrolls and removes iteration statements from the program.
+    fn reconstruct_iteration(&mut self, _: IterationStatement) -> (Statement, Self::AdditionalOutput) {
+        unreachable!("`IterationStatement`s should not be in the AST at this phase of compilation.");
+    }
+
+    fn reconstruct_match_statement(&mut self, _: MatchStatement) -> (Statement, Self::AdditionalOutput) {
+        unreachable!("`MatchStatement`s should not be in the AST at this phase of compilation.");
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Common Subexpression Elimination pass traverses the AST and reuses the results of pure expressions
+//! that were already computed, within the boundary of `transition`s, `function`s, and `finalize` blocks.
+//! The pass is run after the Function Inlining pass and before the Dead Code Elimination pass.
+//! Function calls, mapping operations, and random number generation are never merged.
+//! Returned variables keep their own computation, since a register may only be output once.
+//!
+//! See https://en.wikipedia.org/wiki/Common_subexpression_elimination for more information.
+//!
+//! Consider the following flattened Leo code.
+//! ```leo
+//! function main(a: u8, b: u8, flag: bool) -> u8 {
+//!     $var$0 = a + b;
+//!     $var$1 = $var$0 * 2u8;
+//!     $var$2 = b + a;
+//!     $var$3 = $var$2 * 2u8;
+//!     $var$4 = flag ? $var$1 : $var$3;
+//!     return $var$4;
+//! }
+//! ```
+//!
+//! The common subexpression elimination pass produces the following code.
+//! ```leo
+//! function main(a: u8, b: u8, flag: bool) -> u8 {
+//!     $var$0 = a + b;
+//!     $var$1 = $var$0 * 2u8;
+//!     $var$2 = $var$0;
+//!     $var$3 = $var$1;
+//!     $var$4 = flag ? $var$1 : $var$1;
+//!     return $var$4;
+//! }
+//! ```
+//! The copies `$var$2` and `$var$3` are no longer used, and are removed by the Dead Code Elimination pass.
+//!
+//! Note this pass relies on the following invariants:
+//! - Unique variable names (provided by SSA)
+//! - Flattened code (provided by the flattening pass)
+
+mod eliminate_expression;
+
+mod eliminate_statement;
+
+mod eliminate_program;
+
+pub mod common_subexpression_eliminator;
+pub use common_subexpression_eliminator::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::Result;
+
+impl Pass for CommonSubexpressionEliminator {
+    type Input = Ast;
+    type Output = Result<Ast>;
+
+    fn do_pass(ast: Self::Input) -> Self::Output {
+        let mut reconstructor = CommonSubexpressionEliminator::default();
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The console removal pass removes `console` statements from the program.
+//! `console.log`, `console.assert`, `console.assert_eq`, and `console.assert_neq` are debug-only statements,
+//! which are honored by the interpreter but have no counterpart in Aleo instructions.
+//! The pass is run after type checking, so that the arguments of the removed statements are still checked.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u32) -> u32 {
+//!     console.log("a is {}", a);
+//!     console.assert_neq(a, 0u32);
+//!     return a + 1u32;
+//! }
+//! ```
+//!
+//! The console removal pass produces the following code.
+//! ```leo
+//! transition main(a: u32) -> u32 {
+//!     return a + 1u32;
+//! }
+//! ```
+
+mod remove_statements;
+
+pub mod console_remover;
+pub use console_remover::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::Result;
+
+impl Pass for ConsoleRemover {
+    type Input = Ast;
+    type Output = Result<Ast>;
+
+    fn do_pass(ast: Self::Input) -> Self::Output {
+        let mut reconstructor = ConsoleRemover;
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
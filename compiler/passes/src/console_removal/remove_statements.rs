@@ -0,0 +1,33 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ConsoleRemover;
+
+use leo_ast::{Block, Statement, StatementReconstructor};
+
+impl StatementReconstructor for ConsoleRemover {
+    /// Removes the console statements of the block, and of its nested blocks.
+    fn reconstruct_block(&mut self, block: Block) -> (Block, Self::AdditionalOutput) {
+        let statements = block
+            .statements
+            .into_iter()
+            .filter(|statement| !matches!(statement, Statement::Console(_)))
+            .map(|statement| self.reconstruct_statement(statement).0)
+            .collect();
+
+        (Block { statements, span: block.span, id: block.id }, Default::default())
+    }
+}
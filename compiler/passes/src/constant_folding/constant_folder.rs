@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::TypeTable;
+
+use leo_ast::{Expression, Literal, NodeBuilder, Type, Value};
+use leo_errors::emitter::Handler;
+
+pub struct ConstantFolder<'a> {
+    /// The error handler.
+    pub(crate) handler: &'a Handler,
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// A mapping between node IDs and their types.
+    pub(crate) type_table: &'a TypeTable,
+}
+
+impl<'a> ConstantFolder<'a> {
+    pub(crate) fn new(handler: &'a Handler, node_builder: &'a NodeBuilder, type_table: &'a TypeTable) -> Self {
+        Self { handler, node_builder, type_table }
+    }
+
+    /// Constructs a literal expression for a folded value, recording its type in the type table.
+    /// Returns `None` if the value has no literal form.
+    pub(crate) fn literal_expression(&self, value: &Value) -> Option<Expression> {
+        let id = self.node_builder.next_id();
+        let literal = Literal::from_value(value.clone(), id)?;
+        self.type_table.insert(id, Type::from(value));
+        Some(Expression::Literal(literal))
+    }
+}
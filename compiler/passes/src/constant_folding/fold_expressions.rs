@@ -0,0 +1,329 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ConstantFolder;
+
+use leo_ast::*;
+use leo_errors::{ConstantFolderError, Result};
+use leo_span::Span;
+
+impl ExpressionReconstructor for ConstantFolder<'_> {
+    /// The constant value of the reconstructed expression, if it is known at compile time.
+    type AdditionalOutput = Option<Value>;
+
+    fn reconstruct_array_access(&mut self, input: ArrayAccess) -> (Expression, Self::AdditionalOutput) {
+        let array = self.reconstruct_expression(*input.array).0;
+        let (index, index_value) = self.reconstruct_expression(*input.index);
+
+        // A constant index, including one exposed by loop unrolling, must be within the bounds of the array.
+        if let (Some(Type::Array(array_type)), Some(digits)) =
+            (self.type_table.get(&array.id()), index_value.as_ref().and_then(integer_digits))
+        {
+            if digits.parse::<usize>().map_or(true, |index| index >= array_type.length()) {
+                self.handler.emit_err(ConstantFolderError::array_index_out_of_bounds(
+                    digits,
+                    array_type.length(),
+                    input.span,
+                ));
+            }
+        }
+
+        // Accessing a constant array at a constant index reduces to the element.
+        if is_constant(&array) {
+            if let (Expression::Array(array), Some(digits)) = (&array, index_value.as_ref().and_then(integer_digits)) {
+                if let Some(element) = digits.parse::<usize>().ok().and_then(|index| array.elements.get(index)) {
+                    return (element.clone(), literal_value(element));
+                }
+            }
+        }
+
+        (
+            Expression::Access(AccessExpression::Array(ArrayAccess {
+                array: Box::new(array),
+                index: Box::new(index),
+                span: input.span,
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_member_access(&mut self, input: MemberAccess) -> (Expression, Self::AdditionalOutput) {
+        let inner = self.reconstruct_expression(*input.inner).0;
+
+        // Accessing a member of a constant struct reduces to the member.
+        if is_constant(&inner) {
+            if let Expression::Struct(struct_) = &inner {
+                let member = struct_.members.iter().find(|member| member.identifier.name == input.name.name);
+                if let Some(expression) = member.and_then(|member| member.expression.as_ref()) {
+                    return (expression.clone(), literal_value(expression));
+                }
+            }
+        }
+
+        (
+            Expression::Access(AccessExpression::Member(MemberAccess {
+                inner: Box::new(inner),
+                name: input.name,
+                span: input.span,
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_tuple_access(&mut self, input: TupleAccess) -> (Expression, Self::AdditionalOutput) {
+        let tuple = self.reconstruct_expression(*input.tuple).0;
+
+        // Accessing a constant tuple reduces to the element.
+        if is_constant(&tuple) {
+            if let Expression::Tuple(tuple) = &tuple {
+                if let Some(element) = tuple.elements.get(input.index.value()) {
+                    return (element.clone(), literal_value(element));
+                }
+            }
+        }
+
+        (
+            Expression::Access(AccessExpression::Tuple(TupleAccess {
+                tuple: Box::new(tuple),
+                index: input.index,
+                span: input.span,
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_binary(&mut self, input: BinaryExpression) -> (Expression, Self::AdditionalOutput) {
+        let (left, left_value) = self.reconstruct_expression(*input.left);
+        let (right, right_value) = self.reconstruct_expression(*input.right);
+
+        // Only fold the expression if both operands are constants supporting const operations.
+        if let (Some(left_value), Some(right_value)) = (left_value, right_value) {
+            if left_value.is_supported_const_fold_type() && right_value.is_supported_const_fold_type() {
+                match fold_binary(input.op, left_value, right_value, input.span) {
+                    Ok(value) => {
+                        if let Some(literal) = self.literal_expression(&value) {
+                            return (literal, Some(value));
+                        }
+                    }
+                    Err(err) => self.handler.emit_err(err),
+                }
+            }
+        }
+
+        (
+            Expression::Binary(BinaryExpression {
+                left: Box::new(left),
+                right: Box::new(right),
+                op: input.op,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_cast(&mut self, input: CastExpression) -> (Expression, Self::AdditionalOutput) {
+        let (expression, value) = self.reconstruct_expression(*input.expression);
+
+        // Only casts between integer types are folded.
+        if let (Some(value), Type::Integer(integer_type)) = (value, &input.type_) {
+            if let Some(digits) = integer_digits(&value) {
+                match cast_integer(&digits, integer_type, input.span) {
+                    Some(value) => {
+                        if let Some(literal) = self.literal_expression(&value) {
+                            return (literal, Some(value));
+                        }
+                    }
+                    None => self.handler.emit_err(ConstantFolderError::cast_overflow(
+                        format!("{value}{}", Type::from(&value)),
+                        &input.type_,
+                        input.span,
+                    )),
+                }
+            }
+        }
+
+        (
+            Expression::Cast(CastExpression {
+                expression: Box::new(expression),
+                type_: input.type_,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_literal(&mut self, input: Literal) -> (Expression, Self::AdditionalOutput) {
+        let value = Value::try_from(&input).ok();
+        (Expression::Literal(input), value)
+    }
+
+    fn reconstruct_ternary(&mut self, input: TernaryExpression) -> (Expression, Self::AdditionalOutput) {
+        let (condition, condition_value) = self.reconstruct_expression(*input.condition);
+
+        // If the condition is a constant, the ternary expression reduces to the selected branch.
+        // The other branch is discarded without being folded, so that it cannot raise errors.
+        match condition_value {
+            Some(Value::Boolean(true, _)) => self.reconstruct_expression(*input.if_true),
+            Some(Value::Boolean(false, _)) => self.reconstruct_expression(*input.if_false),
+            _ => (
+                Expression::Ternary(TernaryExpression {
+                    condition: Box::new(condition),
+                    if_true: Box::new(self.reconstruct_expression(*input.if_true).0),
+                    if_false: Box::new(self.reconstruct_expression(*input.if_false).0),
+                    span: input.span,
+                    id: input.id,
+                }),
+                Default::default(),
+            ),
+        }
+    }
+
+    fn reconstruct_unary(&mut self, input: UnaryExpression) -> (Expression, Self::AdditionalOutput) {
+        let (receiver, value) = self.reconstruct_expression(*input.receiver);
+
+        if let Some(value) = value.filter(|value| value.is_supported_const_fold_type()) {
+            let result = match input.op {
+                UnaryOperation::Abs => Some(("abs", value.clone().abs(input.span))),
+                UnaryOperation::AbsWrapped => Some(("abs_wrapped", value.clone().abs_wrapped(input.span))),
+                UnaryOperation::Negate => Some(("neg", value.clone().neg(input.span))),
+                UnaryOperation::Not => Some(("not", value.clone().not(input.span))),
+                // The remaining operations are only defined on fields and groups.
+                _ => None,
+            };
+            match result {
+                Some((_, Ok(folded))) => {
+                    if let Some(literal) = self.literal_expression(&folded) {
+                        return (literal, Some(folded));
+                    }
+                }
+                // The value operations only fail on overflow.
+                Some((method, Err(_))) => {
+                    let operation = format!("{value}{}.{method}()", Type::from(&value));
+                    self.handler.emit_err(ConstantFolderError::operation_overflow(operation, input.span))
+                }
+                None => {}
+            }
+        }
+
+        (
+            Expression::Unary(UnaryExpression {
+                receiver: Box::new(receiver),
+                op: input.op,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+}
+
+/// Returns `true` if the expression is a literal, or an array, struct, or tuple of constant expressions.
+fn is_constant(expression: &Expression) -> bool {
+    match expression {
+        Expression::Literal(_) => true,
+        Expression::Array(array) => array.elements.iter().all(is_constant),
+        Expression::Struct(struct_) => {
+            struct_.members.iter().all(|member| member.expression.as_ref().map_or(false, is_constant))
+        }
+        Expression::Tuple(tuple) => tuple.elements.iter().all(is_constant),
+        _ => false,
+    }
+}
+
+/// Returns the value of the expression, if it is a literal.
+fn literal_value(expression: &Expression) -> Option<Value> {
+    match expression {
+        Expression::Literal(literal) => Value::try_from(literal).ok(),
+        _ => None,
+    }
+}
+
+/// Evaluates a binary operation over two constant booleans or integers.
+fn fold_binary(op: BinaryOperation, left: Value, right: Value, span: Span) -> Result<Value> {
+    use BinaryOperation::*;
+
+    let operation = format!("{left}{} {op} {right}{}", Type::from(&left), Type::from(&right));
+
+    // The checked operations report division by zero as an overflow, so it is caught here with a dedicated error.
+    if matches!(op, Div | DivWrapped | Mod | Rem | RemWrapped) && integer_digits(&right).as_deref() == Some("0") {
+        return Err(ConstantFolderError::division_by_zero(operation, span).into());
+    }
+
+    let result = match op {
+        Add => left.add(right, span),
+        AddWrapped => left.add_wrapped(right, span),
+        And | BitwiseAnd => left.bitand(right, span),
+        Div => left.div(right, span),
+        DivWrapped => left.div_wrapped(right, span),
+        Eq => left.eq(right, span),
+        Gte => left.ge(right, span),
+        Gt => left.gt(right, span),
+        Lte => left.le(right, span),
+        Lt => left.lt(right, span),
+        // `mod` is only defined on unsigned integers, for which it coincides with `%`.
+        Mod | Rem => left.rem(right, span),
+        Mul => left.mul(right, span),
+        MulWrapped => left.mul_wrapped(right, span),
+        Nand => left.bitand(right, span)?.not(span),
+        Neq => left.eq(right, span)?.not(span),
+        Nor => left.bitor(right, span)?.not(span),
+        Or | BitwiseOr => left.bitor(right, span),
+        Pow => left.pow(right, span),
+        PowWrapped => left.pow_wrapped(right, span),
+        RemWrapped => left.rem_wrapped(right, span),
+        Shl => left.shl(right, span),
+        ShlWrapped => left.shl_wrapped(right, span),
+        Shr => left.shr(right, span),
+        ShrWrapped => left.shr_wrapped(right, span),
+        Sub => left.sub(right, span),
+        SubWrapped => left.sub_wrapped(right, span),
+        Xor => left.xor(right, span),
+    };
+    // The value operations only fail on overflow.
+    result.map_err(|_| ConstantFolderError::operation_overflow(operation, span).into())
+}
+
+/// Returns the decimal digits of a constant integer.
+fn integer_digits(value: &Value) -> Option<String> {
+    use Value::*;
+    match value {
+        I8(..) | I16(..) | I32(..) | I64(..) | I128(..) | U8(..) | U16(..) | U32(..) | U64(..) | U128(..) => {
+            Some(value.to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Casts the decimal digits of a constant integer to the given integer type, if the value fits.
+fn cast_integer(digits: &str, type_: &IntegerType, span: Span) -> Option<Value> {
+    Some(match type_ {
+        IntegerType::I8 => Value::I8(digits.parse().ok()?, span),
+        IntegerType::I16 => Value::I16(digits.parse().ok()?, span),
+        IntegerType::I32 => Value::I32(digits.parse().ok()?, span),
+        IntegerType::I64 => Value::I64(digits.parse().ok()?, span),
+        IntegerType::I128 => Value::I128(digits.parse().ok()?, span),
+        IntegerType::U8 => Value::U8(digits.parse().ok()?, span),
+        IntegerType::U16 => Value::U16(digits.parse().ok()?, span),
+        IntegerType::U32 => Value::U32(digits.parse().ok()?, span),
+        IntegerType::U64 => Value::U64(digits.parse().ok()?, span),
+        IntegerType::U128 => Value::U128(digits.parse().ok()?, span),
+    })
+}
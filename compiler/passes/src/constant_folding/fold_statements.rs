@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ConstantFolder;
+
+use leo_ast::*;
+use leo_errors::ConstantFolderError;
+
+impl StatementReconstructor for ConstantFolder<'_> {
+    fn reconstruct_assign(&mut self, input: AssignStatement) -> (Statement, Self::AdditionalOutput) {
+        // Reconstruct the place, so that array indices in the place are folded as well.
+        let place = match input.place {
+            Expression::Identifier(identifier) => Expression::Identifier(identifier),
+            place => self.reconstruct_expression(place).0,
+        };
+
+        // Writes into an array are resolved statically, so the indices in the place must be constant.
+        let mut inner = &place;
+        loop {
+            match inner {
+                Expression::Access(AccessExpression::Array(access)) => {
+                    if !matches!(*access.index, Expression::Literal(_)) {
+                        self.handler.emit_err(ConstantFolderError::variable_array_write(access.span));
+                    }
+                    inner = &access.array;
+                }
+                Expression::Access(AccessExpression::Member(access)) => inner = &access.inner,
+                _ => break,
+            }
+        }
+
+        (
+            Statement::Assign(Box::new(AssignStatement {
+                place,
+                value: self.reconstruct_expression(input.value).0,
+                span: input.span,
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_return(&mut self, input: ReturnStatement) -> (Statement, Self::AdditionalOutput) {
+        let expression = match input.expression {
+            Expression::Tuple(tuple) => Expression::Tuple(TupleExpression {
+                elements: tuple.elements.into_iter().map(|element| self.fold_output(element)).collect(),
+                span: tuple.span,
+                id: tuple.id,
+            }),
+            expression => self.fold_output(expression),
+        };
+
+        (
+            Statement::Return(ReturnStatement {
+                expression,
+                finalize_arguments: input.finalize_arguments.map(|arguments| {
+                    arguments.into_iter().map(|argument| self.reconstruct_expression(argument).0).collect()
+                }),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+}
+
+impl ConstantFolder<'_> {
+    /// Folds a function output. Aleo instructions cannot output a literal, so an output that would
+    /// fold into one is left as written, after any errors it raises have been reported.
+    fn fold_output(&mut self, output: Expression) -> Expression {
+        match self.reconstruct_expression(output.clone()).0 {
+            Expression::Literal(_) if !matches!(output, Expression::Literal(_)) => output,
+            folded => folded,
+        }
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The constant folding pass evaluates constant subexpressions at compile time.
+//! Arithmetic, comparisons, and logical operations over integers and booleans, casts between integer types,
+//! ternary expressions with a constant condition, and accesses into constant arrays, structs, and tuples
+//! are replaced by their result.
+//! Overflows, divisions by zero, and out-of-bounds array indices are reported as compile errors.
+//! Since array writes are resolved statically, their indices must fold to constants.
+//! The pass is run after loop unrolling, so that constants and loop variables have been replaced by literals.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! const SIZE: u32 = 4u32;
+//!
+//! transition main(a: u32) -> u32 {
+//!     let b: u32 = 2u32 * SIZE + 1u32;
+//!     return SIZE > 2u32 ? a + b : a;
+//! }
+//! ```
+//!
+//! The constant folding pass produces the following code.
+//! ```leo
+//! transition main(a: u32) -> u32 {
+//!     let b: u32 = 9u32;
+//!     return a + b;
+//! }
+//! ```
+
+mod fold_expressions;
+
+mod fold_program;
+
+mod fold_statements;
+
+pub mod constant_folder;
+pub use constant_folder::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for ConstantFolder<'a> {
+    type Input = (Ast, &'a Handler, &'a NodeBuilder, &'a TypeTable);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, handler, node_builder, tt): Self::Input) -> Self::Output {
+        let mut reconstructor = ConstantFolder::new(handler, node_builder, tt);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(Ast::new(program))
+    }
+}
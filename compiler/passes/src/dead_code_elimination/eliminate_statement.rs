@@ -29,6 +29,7 @@ use leo_ast::{
     ExpressionReconstructor,
     ExpressionStatement,
     IterationStatement,
+    MatchStatement,
     ReturnStatement,
     Statement,
     StatementReconstructor,
@@ -121,7 +122,7 @@ impl StatementReconstructor for DeadCodeEliminator<'_> {
         unreachable!("`ConditionalStatement`s should not be in the AST at this phase of compilation.")
     }
 
-    /// Parsing guarantees that console statements are not present in the program.
+    /// The console removal pass guarantees that console statements are not present in the program.
     fn reconstruct_console(&mut self, _: ConsoleStatement) -> (Statement, Self::AdditionalOutput) {
         unreachable!("`ConsoleStatement`s should not be in the AST at this phase of compilation.")
     }
@@ -176,6 +177,10 @@ impl StatementReconstructor for DeadCodeEliminator<'_> {
         unreachable!("`IterationStatement`s should not be in the AST at this phase of compilation.");
     }
 
+    fn reconstruct_match_statement(&mut self, _: MatchStatement) -> (Statement, Self::AdditionalOutput) {
+        unreachable!("`MatchStatement`s should not be in the AST at this phase of compilation.");
+    }
+
     fn reconstruct_return(&mut self, input: ReturnStatement) -> (Statement, Self::AdditionalOutput) {
         // Set the `is_necessary` flag.
         self.is_necessary = true;
@@ -26,6 +26,7 @@ use leo_ast::{
     ExpressionReconstructor,
     Identifier,
     IterationStatement,
+    MatchStatement,
     Node,
     ReturnStatement,
     Statement,
@@ -236,6 +237,10 @@ impl StatementReconstructor for Destructurer<'_> {
         unreachable!("`IterationStatement`s should not be in the AST at this phase of compilation.");
     }
 
+    fn reconstruct_match_statement(&mut self, _: MatchStatement) -> (Statement, Self::AdditionalOutput) {
+        unreachable!("`MatchStatement`s should not be in the AST at this phase of compilation.");
+    }
+
     /// Reconstructs
     fn reconstruct_return(&mut self, input: ReturnStatement) -> (Statement, Self::AdditionalOutput) {
         // Note that SSA guarantees that `input.expression` is either a literal, identifier, or unit expression.
@@ -0,0 +1,402 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Assigner, SymbolTable, TypeTable};
+
+use leo_ast::{
+    AccessExpression,
+    ArrayAccess,
+    AssertStatement,
+    AssertVariant,
+    BinaryExpression,
+    BinaryOperation,
+    DeclarationType,
+    DefinitionStatement,
+    Enum,
+    Expression,
+    ExpressionReconstructor,
+    GroupLiteral,
+    Identifier,
+    IntegerType,
+    Literal,
+    MatchPattern,
+    MemberAccess,
+    Node,
+    NodeBuilder,
+    NodeID,
+    RepeatExpression,
+    Statement,
+    StructExpression,
+    StructVariableInitializer,
+    Type,
+};
+use leo_span::{sym, Span, Symbol};
+
+use indexmap::IndexMap;
+
+/// The address used as the default value of `address` payloads.
+const ZERO_ADDRESS: &str = "aleo1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq3ljyzc";
+
+/// The payload of the variant matched by an arm, which is bound to a variable.
+#[derive(Clone)]
+pub(crate) struct Binding {
+    /// The name of the variable holding the matched value.
+    pub(crate) matched: Symbol,
+    /// The type of the matched value.
+    pub(crate) enum_type: Type,
+    /// The name of the matched variant.
+    pub(crate) variant: Symbol,
+    /// The type of the payload.
+    pub(crate) payload_type: Type,
+}
+
+pub struct EnumLowerer<'a> {
+    /// The symbol table, in which each enum is replaced by the struct that represents it.
+    pub(crate) symbol_table: SymbolTable,
+    /// A mapping between node IDs and their types.
+    pub(crate) type_table: &'a TypeTable,
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// A struct used to construct (unique) variable names.
+    pub(crate) assigner: &'a Assigner,
+    /// The enums of the program.
+    pub(crate) enums: IndexMap<Symbol, Enum>,
+    /// The variables bound by the arms of the `match` expressions being lowered.
+    pub(crate) bindings: IndexMap<Symbol, Binding>,
+    /// The definitions of the matched values, which are inserted before the statement being lowered.
+    pub(crate) definitions: Vec<Statement>,
+}
+
+impl<'a> EnumLowerer<'a> {
+    pub(crate) fn new(
+        mut symbol_table: SymbolTable,
+        type_table: &'a TypeTable,
+        node_builder: &'a NodeBuilder,
+        assigner: &'a Assigner,
+    ) -> Self {
+        let enums = std::mem::take(&mut symbol_table.enums);
+        for (name, enum_) in enums.iter() {
+            symbol_table.structs.insert(*name, enum_.to_struct(node_builder));
+        }
+        Self {
+            symbol_table,
+            type_table,
+            node_builder,
+            assigner,
+            enums,
+            bindings: IndexMap::new(),
+            definitions: Vec::new(),
+        }
+    }
+
+    /// Returns a new node ID, recording the type of the node.
+    pub(crate) fn typed_id(&self, type_: Type) -> NodeID {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, type_);
+        id
+    }
+
+    /// Returns the name and the definition of the enum, if the type is an enum.
+    pub(crate) fn lookup_enum(&self, type_: &Type) -> Option<(Identifier, Enum)> {
+        match type_ {
+            Type::Identifier(identifier) => self.enums.get(&identifier.name).map(|enum_| (*identifier, enum_.clone())),
+            _ => None,
+        }
+    }
+
+    /// Returns the `u8` literal holding the tag.
+    fn tag_literal(&self, tag: usize, span: Span) -> Expression {
+        Expression::Literal(Literal::Integer(
+            IntegerType::U8,
+            tag.to_string(),
+            span,
+            self.typed_id(Type::Integer(IntegerType::U8)),
+        ))
+    }
+
+    /// Returns the access of the member of the matched value, e.g. `$match$0.tag`.
+    pub(crate) fn member_access(
+        &self,
+        matched: Symbol,
+        enum_type: &Type,
+        member: Symbol,
+        type_: Type,
+        span: Span,
+    ) -> Expression {
+        Expression::Access(AccessExpression::Member(MemberAccess {
+            inner: Box::new(Expression::Identifier(Identifier {
+                name: matched,
+                span,
+                id: self.typed_id(enum_type.clone()),
+            })),
+            name: Identifier { name: member, span, id: self.node_builder.next_id() },
+            span,
+            id: self.typed_id(type_),
+        }))
+    }
+
+    /// Returns the struct expression for the variant with the given tag, e.g. `Phase { tag: 1u8, Settled: amount }`.
+    /// The payloads of the other variants hold default values.
+    pub(crate) fn variant_struct(
+        &self,
+        name: Identifier,
+        enum_: &Enum,
+        tag: usize,
+        payload: Option<Expression>,
+        span: Span,
+        id: NodeID,
+    ) -> Expression {
+        let mut payload = payload;
+        let mut members = vec![StructVariableInitializer {
+            identifier: Identifier { name: sym::tag, span, id: self.node_builder.next_id() },
+            expression: Some(self.tag_literal(tag, span)),
+            span,
+            id: self.node_builder.next_id(),
+        }];
+        for (index, variant) in enum_.variants.iter().enumerate() {
+            if let Some(type_) = &variant.payload {
+                let expression = if index == tag { payload.take() } else { None };
+                members.push(StructVariableInitializer {
+                    identifier: Identifier { name: variant.name(), span, id: self.node_builder.next_id() },
+                    expression: Some(expression.unwrap_or_else(|| self.default_value(type_, span))),
+                    span,
+                    id: self.node_builder.next_id(),
+                });
+            }
+        }
+        Expression::Struct(StructExpression {
+            name: Identifier { name: name.name, span: name.span, id: self.node_builder.next_id() },
+            members,
+            span,
+            id,
+        })
+    }
+
+    /// Returns the default value of the type, which fills the payloads of the inactive variants.
+    fn default_value(&self, type_: &Type, span: Span) -> Expression {
+        let id = self.typed_id(type_.clone());
+        match type_ {
+            Type::Address => Expression::Literal(Literal::Address(ZERO_ADDRESS.to_string(), span, id)),
+            Type::Boolean => Expression::Literal(Literal::Boolean(false, span, id)),
+            Type::Field => Expression::Literal(Literal::Field("0".to_string(), span, id)),
+            Type::Group => {
+                Expression::Literal(Literal::Group(Box::new(GroupLiteral::Single("0".to_string(), span, id))))
+            }
+            Type::Integer(integer_type) => {
+                Expression::Literal(Literal::Integer(*integer_type, "0".to_string(), span, id))
+            }
+            Type::Scalar => Expression::Literal(Literal::Scalar("0".to_string(), span, id)),
+            Type::Array(array_type) => Expression::Repeat(RepeatExpression {
+                expr: Box::new(self.default_value(array_type.element_type(), span)),
+                count: array_type.length().into(),
+                span,
+                id,
+            }),
+            Type::Identifier(name) => {
+                // Note that this unwrap is safe since type checking guarantees that the struct exists.
+                // Enums have already been replaced by their structs, whose default value has the tag `0u8`.
+                let struct_ = self.symbol_table.lookup_struct(name.name).unwrap().clone();
+                Expression::Struct(StructExpression {
+                    name: Identifier { name: name.name, span, id: self.node_builder.next_id() },
+                    members: struct_
+                        .members
+                        .iter()
+                        .map(|member| StructVariableInitializer {
+                            identifier: Identifier { name: member.name(), span, id: self.node_builder.next_id() },
+                            expression: Some(self.default_value(&member.type_, span)),
+                            span,
+                            id: self.node_builder.next_id(),
+                        })
+                        .collect(),
+                    span,
+                    id,
+                })
+            }
+            _ => unreachable!("Type checking guarantees that the payload of a variant has a default value."),
+        }
+    }
+
+    /// Replaces the variants in the pattern with their tags, e.g. `Phase::Open | Phase::Closed` with `0u8 | 1u8`.
+    pub(crate) fn tag_pattern(&self, enum_: &Enum, pattern: MatchPattern) -> MatchPattern {
+        match pattern {
+            MatchPattern::Variant(pattern) => {
+                // Note that this unwrap is safe since type checking guarantees that the variant exists.
+                let (tag, _) = enum_.variant(pattern.variant.name).unwrap();
+                MatchPattern::Constant(self.tag_literal(tag, pattern.span))
+            }
+            MatchPattern::Or(patterns, span) => {
+                MatchPattern::Or(patterns.into_iter().map(|pattern| self.tag_pattern(enum_, pattern)).collect(), span)
+            }
+            pattern => pattern,
+        }
+    }
+
+    /// Returns the binding introduced by the pattern, if any.
+    pub(crate) fn binding(
+        &self,
+        matched: Symbol,
+        enum_type: &Type,
+        enum_: &Enum,
+        pattern: &MatchPattern,
+    ) -> Option<(Identifier, Binding)> {
+        match pattern {
+            MatchPattern::Variant(pattern) => match (pattern.binding, enum_.variant(pattern.variant.name)) {
+                (Some(binding), Some((_, variant))) => variant.payload.clone().map(|payload_type| {
+                    (binding, Binding { matched, enum_type: enum_type.clone(), variant: variant.name(), payload_type })
+                }),
+                _ => None,
+            },
+            MatchPattern::Constant(_) | MatchPattern::Or(..) | MatchPattern::Wildcard(_) => None,
+        }
+    }
+
+    /// Returns the name of a variable holding the matched value.
+    /// Unless the matched expression is a variable, it is assigned to a new variable,
+    /// whose definition is inserted before the statement being lowered.
+    pub(crate) fn matched_variable(&mut self, expression: Expression, type_: &Type) -> Symbol {
+        match self.reconstruct_expression(expression).0 {
+            Expression::Identifier(identifier) => identifier.name,
+            expression => self.bind("$match", expression, type_),
+        }
+    }
+
+    /// Assigns the expression to a new variable, whose definition is inserted before the statement being lowered.
+    pub(crate) fn bind(&mut self, prefix: &str, expression: Expression, type_: &Type) -> Symbol {
+        let name = self.assigner.unique_symbol(prefix, "$");
+        self.definitions.push(Statement::Definition(DefinitionStatement {
+            declaration_type: DeclarationType::Let,
+            place: Expression::Identifier(Identifier {
+                name,
+                span: Default::default(),
+                id: self.typed_id(type_.clone()),
+            }),
+            type_: type_.clone(),
+            value: expression,
+            span: Default::default(),
+            id: self.node_builder.next_id(),
+        }));
+        name
+    }
+
+    /// Returns the assertions that the tags of the enums in the value at the place are in range, e.g. `assert(phase.tag < 2u8);`.
+    /// A value given as an input or read from a mapping may carry any `u8` as its tag,
+    /// which would otherwise select the last arm of a `match`.
+    /// The enums in the members of structs and records, the payloads of enums, and the elements of arrays are checked as well.
+    pub(crate) fn tag_checks(&self, place: &Expression, type_: &Type) -> Vec<Statement> {
+        let span = place.span();
+        let mut checks = Vec::new();
+        match type_ {
+            Type::Array(array_type) if self.contains_enum(array_type.element_type()) => {
+                for index in 0..array_type.length() {
+                    let element = Expression::Access(AccessExpression::Array(ArrayAccess {
+                        array: Box::new(self.copy_place(place)),
+                        index: Box::new(Expression::Literal(Literal::Integer(
+                            IntegerType::U32,
+                            index.to_string(),
+                            span,
+                            self.typed_id(Type::Integer(IntegerType::U32)),
+                        ))),
+                        span,
+                        id: self.typed_id(array_type.element_type().clone()),
+                    }));
+                    checks.extend(self.tag_checks(&element, array_type.element_type()));
+                }
+            }
+            Type::Identifier(name) => {
+                // An enum with 256 variants has a variant for every tag.
+                let enum_ = self.enums.get(&name.name);
+                if let Some(enum_) = enum_.filter(|enum_| enum_.variants.len() <= u8::MAX as usize) {
+                    let tag = Expression::Access(AccessExpression::Member(MemberAccess {
+                        inner: Box::new(self.copy_place(place)),
+                        name: Identifier { name: sym::tag, span, id: self.node_builder.next_id() },
+                        span,
+                        id: self.typed_id(Type::Integer(IntegerType::U8)),
+                    }));
+                    checks.push(Statement::Assert(AssertStatement {
+                        variant: AssertVariant::Assert(Expression::Binary(BinaryExpression {
+                            left: Box::new(tag),
+                            right: Box::new(self.tag_literal(enum_.variants.len(), span)),
+                            op: BinaryOperation::Lt,
+                            span,
+                            id: self.typed_id(Type::Boolean),
+                        })),
+                        span,
+                        id: self.node_builder.next_id(),
+                    }));
+                }
+                if let Some(struct_) = self.symbol_table.lookup_struct(name.name) {
+                    for member in struct_.members.iter().filter(|member| self.contains_enum(&member.type_)) {
+                        let access = Expression::Access(AccessExpression::Member(MemberAccess {
+                            inner: Box::new(self.copy_place(place)),
+                            name: Identifier { name: member.name(), span, id: self.node_builder.next_id() },
+                            span,
+                            id: self.typed_id(member.type_.clone()),
+                        }));
+                        checks.extend(self.tag_checks(&access, &member.type_));
+                    }
+                }
+            }
+            _ => {}
+        }
+        checks
+    }
+
+    /// Returns whether a value of the type contains an enum.
+    pub(crate) fn contains_enum(&self, type_: &Type) -> bool {
+        match type_ {
+            Type::Array(array_type) => self.contains_enum(array_type.element_type()),
+            Type::Identifier(name) => {
+                self.enums.contains_key(&name.name)
+                    || self
+                        .symbol_table
+                        .lookup_struct(name.name)
+                        .map_or(false, |struct_| struct_.members.iter().any(|member| self.contains_enum(&member.type_)))
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns a copy of the place with new node IDs, so that each use of the place is a distinct node.
+    fn copy_place(&self, place: &Expression) -> Expression {
+        // Note that these unwraps are safe since the type of each node of a place is recorded when it is created.
+        match place {
+            Expression::Identifier(identifier) => Expression::Identifier(Identifier {
+                id: self.typed_id(self.type_table.get(&identifier.id).unwrap()),
+                ..*identifier
+            }),
+            Expression::Access(AccessExpression::Member(access)) => {
+                Expression::Access(AccessExpression::Member(MemberAccess {
+                    inner: Box::new(self.copy_place(&access.inner)),
+                    name: Identifier { id: self.node_builder.next_id(), ..access.name },
+                    span: access.span,
+                    id: self.typed_id(self.type_table.get(&access.id).unwrap()),
+                }))
+            }
+            Expression::Access(AccessExpression::Array(access)) => {
+                Expression::Access(AccessExpression::Array(ArrayAccess {
+                    array: Box::new(self.copy_place(&access.array)),
+                    index: Box::new(self.copy_place(&access.index)),
+                    span: access.span,
+                    id: self.typed_id(self.type_table.get(&access.id).unwrap()),
+                }))
+            }
+            Expression::Literal(Literal::Integer(integer_type, value, span, id)) => Expression::Literal(
+                Literal::Integer(*integer_type, value.clone(), *span, self.typed_id(self.type_table.get(id).unwrap())),
+            ),
+            _ => unreachable!("A place is a variable, or an access of a member or an element of a place."),
+        }
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::EnumLowerer;
+
+use leo_ast::{
+    AccessExpression,
+    AssociatedConstant,
+    AssociatedFunction,
+    Expression,
+    ExpressionReconstructor,
+    Identifier,
+    IntegerType,
+    MatchArm,
+    MatchExpression,
+    Node,
+    Type,
+};
+use leo_span::sym;
+
+impl ExpressionReconstructor for EnumLowerer<'_> {
+    type AdditionalOutput = ();
+
+    /// Replaces a variant without a payload, e.g. `Phase::Open`, with the struct that represents it.
+    fn reconstruct_associated_constant(&mut self, input: AssociatedConstant) -> (Expression, Self::AdditionalOutput) {
+        match self.lookup_enum(&input.ty) {
+            Some((name, enum_)) => {
+                // Note that this unwrap is safe since type checking guarantees that the variant exists.
+                let (tag, _) = enum_.variant(input.name.name).unwrap();
+                (self.variant_struct(name, &enum_, tag, None, input.span, input.id), Default::default())
+            }
+            None => (Expression::Access(AccessExpression::AssociatedConstant(input)), Default::default()),
+        }
+    }
+
+    /// Replaces a variant with a payload, e.g. `Phase::Settled(amount)`, with the struct that represents it.
+    fn reconstruct_associated_function(&mut self, input: AssociatedFunction) -> (Expression, Self::AdditionalOutput) {
+        let arguments = input.arguments.into_iter().map(|arg| self.reconstruct_expression(arg).0).collect::<Vec<_>>();
+        match self.lookup_enum(&input.ty) {
+            Some((name, enum_)) => {
+                // Note that this unwrap is safe since type checking guarantees that the variant exists.
+                let (tag, _) = enum_.variant(input.name.name).unwrap();
+                let payload = arguments.into_iter().next();
+                (self.variant_struct(name, &enum_, tag, payload, input.span, input.id), Default::default())
+            }
+            None => {
+                let is_mapping_read = matches!(&input.ty, Type::Identifier(identifier) if identifier.name == sym::Mapping)
+                    && matches!(input.name.name, sym::get | sym::get_or_use);
+                let expression = Expression::Access(AccessExpression::AssociatedFunction(AssociatedFunction {
+                    ty: input.ty,
+                    name: input.name,
+                    arguments,
+                    span: input.span,
+                    id: input.id,
+                }));
+                // The tags of the enums in a value read from a mapping are checked before the value is used.
+                let type_ = match self.type_table.get(&input.id) {
+                    Some(type_) if is_mapping_read && self.contains_enum(&type_) => type_,
+                    _ => return (expression, Default::default()),
+                };
+                let name = self.bind("$value", expression, &type_);
+                let place =
+                    Expression::Identifier(Identifier { name, span: input.span, id: self.typed_id(type_.clone()) });
+                let mut checks = self.tag_checks(&place, &type_);
+                self.definitions.append(&mut checks);
+                (place, Default::default())
+            }
+        }
+    }
+
+    /// Replaces a variable bound by an arm with the access of the payload, e.g. `phase.Settled`.
+    fn reconstruct_identifier(&mut self, input: Identifier) -> (Expression, Self::AdditionalOutput) {
+        match self.bindings.get(&input.name).cloned() {
+            Some(binding) => (
+                self.member_access(
+                    binding.matched,
+                    &binding.enum_type,
+                    binding.variant,
+                    binding.payload_type,
+                    input.span,
+                ),
+                Default::default(),
+            ),
+            None => (Expression::Identifier(input), Default::default()),
+        }
+    }
+
+    /// Replaces a `match` expression on an enum with a `match` expression on its tag,
+    /// e.g. `match phase.tag { 0u8 => 0u64, 1u8 => phase.Settled }`, which is lowered by the flattener.
+    fn reconstruct_match(&mut self, input: MatchExpression) -> (Expression, Self::AdditionalOutput) {
+        // Note that this unwrap is safe since type checking guarantees that the matched value has a type.
+        let type_ = self.type_table.get(&input.expression.id()).unwrap();
+        let enum_ = match self.lookup_enum(&type_) {
+            Some((_, enum_)) => enum_,
+            // Matches on integers and booleans are left to the flattener.
+            None => {
+                return (
+                    Expression::Match(MatchExpression {
+                        expression: Box::new(self.reconstruct_expression(*input.expression).0),
+                        arms: input
+                            .arms
+                            .into_iter()
+                            .map(|arm| MatchArm {
+                                pattern: self.reconstruct_match_pattern(arm.pattern),
+                                body: self.reconstruct_expression(arm.body).0,
+                                span: arm.span,
+                            })
+                            .collect(),
+                        span: input.span,
+                        id: input.id,
+                    }),
+                    Default::default(),
+                );
+            }
+        };
+        let matched = self.matched_variable(*input.expression, &type_);
+
+        let mut arms = Vec::with_capacity(input.arms.len());
+        for arm in input.arms {
+            let binding = self.binding(matched, &type_, &enum_, &arm.pattern);
+            if let Some((identifier, binding)) = &binding {
+                self.bindings.insert(identifier.name, binding.clone());
+            }
+            let body = self.reconstruct_expression(arm.body).0;
+            if let Some((identifier, _)) = binding {
+                self.bindings.shift_remove(&identifier.name);
+            }
+            arms.push(MatchArm { pattern: self.tag_pattern(&enum_, arm.pattern), body, span: arm.span });
+        }
+
+        let expression = self.member_access(matched, &type_, sym::tag, Type::Integer(IntegerType::U8), input.span);
+        (
+            Expression::Match(MatchExpression {
+                expression: Box::new(expression),
+                arms,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::EnumLowerer;
+
+use leo_ast::{
+    Expression,
+    Finalize,
+    Function,
+    Identifier,
+    Input,
+    ProgramReconstructor,
+    ProgramScope,
+    Statement,
+    StatementReconstructor,
+    Variant,
+};
+
+impl ProgramReconstructor for EnumLowerer<'_> {
+    /// Replaces each enum with the struct that represents it.
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        let mut structs: Vec<_> = input.structs.into_iter().map(|(i, c)| (i, self.reconstruct_struct(c))).collect();
+        // Note that this unwrap is safe since the struct of each enum is inserted into the symbol table on construction.
+        structs.extend(input.enums.into_iter().map(|(i, _)| (i, self.symbol_table.lookup_struct(i).unwrap().clone())));
+
+        ProgramScope {
+            program_id: input.program_id,
+            modules: input.modules,
+            type_aliases: input.type_aliases,
+            structs,
+            enums: Vec::new(),
+            mappings: input.mappings.into_iter().map(|(id, mapping)| (id, self.reconstruct_mapping(mapping))).collect(),
+            functions: input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect(),
+            consts: input
+                .consts
+                .into_iter()
+                .map(|(i, c)| match self.reconstruct_const(c) {
+                    (Statement::Const(declaration), _) => (i, declaration),
+                    _ => unreachable!("`reconstruct_const` can only return `Statement::Const`"),
+                })
+                .collect(),
+            span: input.span,
+        }
+    }
+
+    /// Checks that the tags of the enums given as inputs to a transition are in range, before the body of the transition.
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        let mut checks = Vec::new();
+        if input.variant == Variant::Transition {
+            for input in input.input.iter() {
+                match input {
+                    Input::Internal(input) if self.contains_enum(&input.type_) => {
+                        let place = Expression::Identifier(Identifier {
+                            name: input.identifier.name,
+                            span: input.identifier.span,
+                            id: self.typed_id(input.type_.clone()),
+                        });
+                        checks.extend(self.tag_checks(&place, &input.type_));
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut block = self.reconstruct_block(input.block).0;
+        block.statements.splice(0..0, checks);
+
+        Function {
+            annotations: input.annotations,
+            variant: input.variant,
+            identifier: input.identifier,
+            input: input.input,
+            output: input.output,
+            output_type: input.output_type,
+            block,
+            finalize: input.finalize.map(|finalize| Finalize {
+                identifier: finalize.identifier,
+                input: finalize.input,
+                output: finalize.output,
+                output_type: finalize.output_type,
+                block: self.reconstruct_block(finalize.block).0,
+                span: finalize.span,
+                id: finalize.id,
+            }),
+            span: input.span,
+            id: input.id,
+        }
+    }
+}
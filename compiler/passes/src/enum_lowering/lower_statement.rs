@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::EnumLowerer;
+
+use leo_ast::{
+    Block,
+    DeclarationType,
+    DefinitionStatement,
+    Expression,
+    ExpressionReconstructor,
+    Identifier,
+    IntegerType,
+    MatchArm,
+    MatchStatement,
+    Node,
+    Statement,
+    StatementReconstructor,
+    Type,
+};
+use leo_span::sym;
+
+impl StatementReconstructor for EnumLowerer<'_> {
+    /// Reconstructs the statements of the block, inserting the definitions of the matched values before each statement.
+    fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
+        // The definitions made by the enclosing statement belong before it, not inside the block.
+        let definitions = std::mem::take(&mut self.definitions);
+        let mut statements = Vec::with_capacity(input.statements.len());
+        for statement in input.statements {
+            let statement = self.reconstruct_statement(statement).0;
+            statements.append(&mut self.definitions);
+            statements.push(statement);
+        }
+        self.definitions = definitions;
+
+        (Block { statements, span: input.span, id: input.id }, Default::default())
+    }
+
+    /// Replaces a `match` statement on an enum with a `match` statement on its tag,
+    /// e.g. `match phase.tag { 0u8 => { .. } _ => { .. } }`, which is lowered by the flattener.
+    /// The payload of the matched variant is bound at the start of its arm, e.g. `let amount: u64 = phase.Settled;`.
+    fn reconstruct_match_statement(&mut self, input: MatchStatement) -> (Statement, Self::AdditionalOutput) {
+        // Note that this unwrap is safe since type checking guarantees that the matched value has a type.
+        let type_ = self.type_table.get(&input.expression.id()).unwrap();
+        let enum_ = match self.lookup_enum(&type_) {
+            Some((_, enum_)) => enum_,
+            // Matches on integers and booleans are left to the flattener.
+            None => {
+                return (
+                    Statement::Match(MatchStatement {
+                        expression: self.reconstruct_expression(input.expression).0,
+                        arms: input
+                            .arms
+                            .into_iter()
+                            .map(|arm| MatchArm {
+                                pattern: self.reconstruct_match_pattern(arm.pattern),
+                                body: self.reconstruct_block(arm.body).0,
+                                span: arm.span,
+                            })
+                            .collect(),
+                        span: input.span,
+                        id: input.id,
+                    }),
+                    Default::default(),
+                );
+            }
+        };
+        let matched = self.matched_variable(input.expression, &type_);
+
+        let mut arms = Vec::with_capacity(input.arms.len());
+        for arm in input.arms {
+            let mut body = arm.body;
+            if let Some((identifier, binding)) = self.binding(matched, &type_, &enum_, &arm.pattern) {
+                let value = self.member_access(
+                    binding.matched,
+                    &binding.enum_type,
+                    binding.variant,
+                    binding.payload_type.clone(),
+                    identifier.span,
+                );
+                body.statements.insert(
+                    0,
+                    Statement::Definition(DefinitionStatement {
+                        declaration_type: DeclarationType::Let,
+                        place: Expression::Identifier(Identifier {
+                            name: identifier.name,
+                            span: identifier.span,
+                            id: self.typed_id(binding.payload_type.clone()),
+                        }),
+                        type_: binding.payload_type,
+                        value,
+                        span: identifier.span,
+                        id: self.node_builder.next_id(),
+                    }),
+                );
+            }
+            let body = self.reconstruct_block(body).0;
+            arms.push(MatchArm { pattern: self.tag_pattern(&enum_, arm.pattern), body, span: arm.span });
+        }
+
+        let expression = self.member_access(matched, &type_, sym::tag, Type::Integer(IntegerType::U8), input.span);
+        (Statement::Match(MatchStatement { expression, arms, span: input.span, id: input.id }), Default::default())
+    }
+}
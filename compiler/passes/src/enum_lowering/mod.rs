@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The enum lowering pass replaces each enum with a struct that carries a `tag: u8` member,
+//! since Aleo instructions have no counterpart to enums.
+//! The struct has a member named after each variant that carries a payload;
+//! the payloads of the inactive variants hold default values.
+//! `match` statements and expressions on enums become `match` statements and expressions on the tag,
+//! which are lowered by the flattener, along with those on integers and booleans.
+//! The payload of the variant matched by an arm of a `match` statement is bound at the start of the arm.
+//! The pass is run directly after type checking, so that the rest of the pipeline only sees structs.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! enum Phase {
+//!     Open,
+//!     Settled(u64),
+//! }
+//!
+//! transition main(phase: Phase) -> u64 {
+//!     return match phase {
+//!         Phase::Open => 0u64,
+//!         Phase::Settled(amount) => amount,
+//!     };
+//! }
+//!
+//! transition settle(amount: u64) -> Phase {
+//!     return Phase::Settled(amount);
+//! }
+//! ```
+//!
+//! The enum lowering pass produces the following code.
+//! ```leo
+//! struct Phase {
+//!     tag: u8,
+//!     Settled: u64,
+//! }
+//!
+//! transition main(phase: Phase) -> u64 {
+//!     assert(phase.tag < 2u8);
+//!     return match phase.tag {
+//!         0u8 => 0u64,
+//!         1u8 => phase.Settled,
+//!     };
+//! }
+//!
+//! transition settle(amount: u64) -> Phase {
+//!     return Phase { tag: 1u8, Settled: amount };
+//! }
+//! ```
+//!
+//! Matched expressions that are not variables are first assigned to a new variable, e.g. `let $match$0: Phase = ...;`.
+//!
+//! Since an enum given as an input to a transition, or read from a mapping, may carry any `u8` as its tag,
+//! the tags of such values are asserted to be in range before they are used, e.g. `assert(phase.tag < 2u8);`.
+
+mod lower_expression;
+
+mod lower_program;
+
+mod lower_statement;
+
+pub mod enum_lowerer;
+pub use enum_lowerer::*;
+
+use crate::{Assigner, Pass, SymbolTable, TypeTable};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for EnumLowerer<'a> {
+    type Input = (Ast, &'a NodeBuilder, &'a Assigner, SymbolTable, &'a TypeTable);
+    type Output = Result<(Ast, SymbolTable)>;
+
+    fn do_pass((ast, node_builder, assigner, st, tt): Self::Input) -> Self::Output {
+        let mut reconstructor = EnumLowerer::new(st, tt, node_builder, assigner);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok((Ast::new(program), reconstructor.symbol_table))
+    }
+}
@@ -17,19 +17,183 @@
 use crate::Flattener;
 
 use leo_ast::{
+    AccessExpression,
+    ArrayAccess,
+    AssertStatement,
+    AssertVariant,
+    BinaryOperation,
     Expression,
     ExpressionReconstructor,
+    IntegerType,
+    MatchExpression,
     Node,
     Statement,
+    StatementReconstructor,
     StructExpression,
     StructVariableInitializer,
     TernaryExpression,
     Type,
 };
+use leo_errors::FlattenWarning;
 
 impl ExpressionReconstructor for Flattener<'_> {
     type AdditionalOutput = Vec<Statement>;
 
+    /// Reconstructs an array access with a non-constant index into a chain of ternary expressions over the elements of the array.
+    /// This is necessary because Aleo instructions only support array accesses at constant indices.
+    /// For example, the access `arr[i]`, where `arr` has type `[u8; 3]` and `i` has type `u32`, is flattened into the following:
+    /// ```leo
+    /// $var$0 = i < 3u32;
+    /// assert($var$0);
+    /// $var$1 = arr[2u32];
+    /// $var$2 = arr[1u32];
+    /// $var$3 = i == 1u32;
+    /// $var$4 = $var$3 ? $var$2 : $var$1;
+    /// $var$5 = arr[0u32];
+    /// $var$6 = i == 0u32;
+    /// $var$7 = $var$6 ? $var$5 : $var$4;
+    /// $var$7
+    /// ```
+    /// Accesses at constant indices are left unchanged.
+    fn reconstruct_array_access(&mut self, input: ArrayAccess) -> (Expression, Self::AdditionalOutput) {
+        if matches!(*input.index, Expression::Literal(_)) {
+            return (Expression::Access(AccessExpression::Array(input)), Default::default());
+        }
+
+        let array_type = match self.type_table.get(&input.array.id()) {
+            Some(Type::Array(array_type)) => array_type,
+            _ => unreachable!("Type checking guarantees that the inner expression of an array access is an array."),
+        };
+        let index_type = match self.type_table.get(&input.index.id()) {
+            Some(Type::Integer(index_type)) => index_type,
+            _ => unreachable!("Type checking guarantees that the index of an array access is an integer."),
+        };
+
+        // Each element of the array is selected separately, so warn the user about the cost of the access.
+        self.handler.emit_warning(FlattenWarning::non_constant_array_index(array_type.length(), input.span).into());
+
+        let mut statements = Vec::new();
+
+        // Check that the index is within the bounds of the array.
+        // Note that this check is conditional on the execution path being valid.
+        let length = self.integer_literal(index_type, array_type.length());
+        let less_than_length = self.binary_expression(BinaryOperation::Lt, *input.index.clone(), length);
+        let (in_bounds, stmt) = self.unique_simple_assign_statement(less_than_length);
+        statements.push(stmt);
+        let in_bounds = match index_type.is_signed() {
+            true => {
+                let zero = self.integer_literal(index_type, 0);
+                let at_least_zero = self.binary_expression(BinaryOperation::Gte, *input.index.clone(), zero);
+                let (non_negative, stmt) = self.unique_simple_assign_statement(at_least_zero);
+                statements.push(stmt);
+                let both = self.binary_expression(
+                    BinaryOperation::And,
+                    Expression::Identifier(non_negative),
+                    Expression::Identifier(in_bounds),
+                );
+                let (in_bounds, stmt) = self.unique_simple_assign_statement(both);
+                statements.push(stmt);
+                in_bounds
+            }
+            false => in_bounds,
+        };
+        let (assert, stmts) = self.reconstruct_assert(AssertStatement {
+            variant: AssertVariant::Assert(Expression::Identifier(in_bounds)),
+            span: input.span,
+            id: self.node_builder.next_id(),
+        });
+        statements.extend(stmts);
+        statements.push(assert);
+
+        // Select the accessed element, starting from the last element of the array.
+        // Note that type checking guarantees that arrays are not empty.
+        let mut selection = None;
+        for i in (0..array_type.length()).rev() {
+            let position = self.integer_literal(IntegerType::U32, i);
+            let (element, stmt) =
+                self.unique_simple_assign_statement(Expression::Access(AccessExpression::Array(ArrayAccess {
+                    array: input.array.clone(),
+                    index: Box::new(position),
+                    span: Default::default(),
+                    id: {
+                        // Create a new node ID for the access expression.
+                        let id = self.node_builder.next_id();
+                        // Set the type of the node ID.
+                        self.type_table.insert(id, array_type.element_type().clone());
+                        id
+                    },
+                })));
+            statements.push(stmt);
+
+            selection = Some(match selection {
+                None => Expression::Identifier(element),
+                Some(otherwise) => {
+                    let position = self.integer_literal(index_type, i);
+                    let is_position = self.binary_expression(BinaryOperation::Eq, *input.index.clone(), position);
+                    let (condition, stmt) = self.unique_simple_assign_statement(is_position);
+                    statements.push(stmt);
+
+                    // Note that this produces an identifier, since the ternary expression is over identifiers.
+                    let (expression, stmts) = self.reconstruct_ternary(TernaryExpression {
+                        condition: Box::new(Expression::Identifier(condition)),
+                        if_true: Box::new(Expression::Identifier(element)),
+                        if_false: Box::new(otherwise),
+                        span: Default::default(),
+                        id: {
+                            // Create a new node ID for the ternary expression.
+                            let id = self.node_builder.next_id();
+                            // Set the type of the node ID.
+                            self.type_table.insert(id, array_type.element_type().clone());
+                            id
+                        },
+                    });
+                    statements.extend(stmts);
+                    expression
+                }
+            });
+        }
+
+        (selection.unwrap(), statements)
+    }
+
+    /// Reconstructs a `match` expression into a balanced tree of ternary expressions over its arms.
+    /// For example, `match x { 0u8 => a, 1u8 | 2u8 => b, 3u8 => c, _ => d }` is flattened into the following:
+    /// ```leo
+    /// $var$0 = x == 0u8;
+    /// $var$1 = x == 1u8;
+    /// $var$2 = x == 2u8;
+    /// $var$3 = $var$1 || $var$2;
+    /// $var$4 = x == 3u8;
+    /// $var$5 = $var$0 ? a : b;
+    /// $var$6 = $var$0 || $var$3;
+    /// $var$7 = $var$4 ? c : d;
+    /// $var$8 = $var$6 ? $var$5 : $var$7;
+    /// $var$8
+    /// ```
+    /// The last reachable arm is selected when none of the previous arms are, so its condition is not checked.
+    fn reconstruct_match(&mut self, input: MatchExpression) -> (Expression, Self::AdditionalOutput) {
+        // Note that this unwrap is safe since type checking guarantees that the match expression has a type.
+        let type_ = self.type_table.get(&input.id).unwrap();
+        let mut statements = Vec::new();
+
+        // Construct the condition of each arm, dropping the arms that follow a wildcard, since they are never selected.
+        let mut arms = Vec::with_capacity(input.arms.len());
+        for arm in input.arms {
+            let condition = self.match_condition(&input.expression, arm.pattern, &mut statements);
+            let (body, stmts) = self.reconstruct_expression(arm.body);
+            statements.extend(stmts);
+            arms.push((condition, body));
+            if condition.is_none() {
+                break;
+            }
+        }
+
+        // Note that type checking guarantees that the match has at least one arm.
+        let (expression, _) = self.select_arm(&arms, &type_, false, &mut statements);
+
+        (expression, statements)
+    }
+
     /// Reconstructs a struct init expression, flattening any tuples in the expression.
     fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
         let mut statements = Vec::new();
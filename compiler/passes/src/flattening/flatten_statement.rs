@@ -23,12 +23,15 @@ use leo_ast::{
     BinaryExpression,
     BinaryOperation,
     Block,
+    BreakStatement,
     ConditionalStatement,
     ConsoleStatement,
+    ContinueStatement,
     DefinitionStatement,
     Expression,
     ExpressionReconstructor,
     IterationStatement,
+    MatchStatement,
     Node,
     ReturnStatement,
     Statement,
@@ -218,6 +221,10 @@ impl StatementReconstructor for Flattener<'_> {
         (Block { span: block.span, statements, id: self.node_builder.next_id() }, Default::default())
     }
 
+    fn reconstruct_break(&mut self, _input: BreakStatement) -> (Statement, Self::AdditionalOutput) {
+        unreachable!("`BreakStatement`s should not be in the AST at this phase of compilation.");
+    }
+
     /// Flatten a conditional statement into a list of statements.
     fn reconstruct_conditional(&mut self, conditional: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
         let mut statements = Vec::with_capacity(conditional.then.statements.len());
@@ -258,6 +265,10 @@ impl StatementReconstructor for Flattener<'_> {
         unreachable!("`ConsoleStatement`s should not be in the AST at this phase of compilation.")
     }
 
+    fn reconstruct_continue(&mut self, _input: ContinueStatement) -> (Statement, Self::AdditionalOutput) {
+        unreachable!("`ContinueStatement`s should not be in the AST at this phase of compilation.");
+    }
+
     fn reconstruct_definition(&mut self, _definition: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
         unreachable!("`DefinitionStatement`s should not exist in the AST at this phase of compilation.")
     }
@@ -266,6 +277,53 @@ impl StatementReconstructor for Flattener<'_> {
         unreachable!("`IterationStatement`s should not be in the AST at this phase of compilation.");
     }
 
+    /// Flattens a `match` statement into a list of statements.
+    /// Each arm is flattened under the guard that its pattern is the first to match the matched value.
+    /// If every arm returns, the returns of the arms are combined into a single return, whose value is selected
+    /// from those of the arms in the same way as for a `match` expression, rather than by a chain of ternary expressions.
+    fn reconstruct_match_statement(&mut self, input: MatchStatement) -> (Statement, Self::AdditionalOutput) {
+        let mut statements = Vec::new();
+        let every_arm_returns = input.arms.iter().all(|arm| always_returns(&arm.body));
+
+        // Construct the condition of each arm, and the guard under which the arm is selected.
+        let (patterns, bodies): (Vec<_>, Vec<_>) = input.arms.into_iter().map(|arm| (arm.pattern, arm.body)).unzip();
+        let conditions = patterns
+            .into_iter()
+            .map(|pattern| self.match_condition(&input.expression, pattern, &mut statements))
+            .collect::<Vec<_>>();
+        let guards = self.arm_guards(&conditions, &mut statements);
+
+        // Flatten each arm, collecting its returns apart from the ones that precede the statement.
+        let returns = self.clear_early_returns();
+        let mut arms = Vec::with_capacity(bodies.len());
+        for ((condition, guard), body) in conditions.into_iter().zip_eq(guards).zip_eq(bodies) {
+            if let Some(guard) = guard {
+                self.condition_stack.push(Expression::Identifier(guard));
+            }
+            statements.extend(self.reconstruct_block(body).0.statements);
+            if guard.is_some() {
+                self.condition_stack.pop();
+            }
+            arms.push((condition, self.clear_early_returns()));
+        }
+        self.returns = returns;
+
+        // Returns of tuples are left to `fold_returns`, since a ternary expression over tuples is flattened into a tuple.
+        let returns_tuple = arms.iter().flat_map(|(_, returns)| returns).next().map_or(false, |(_, statement)| {
+            matches!(self.type_table.get(&statement.expression.id()), Some(Type::Tuple(_)))
+        });
+        match every_arm_returns && !returns_tuple {
+            true => {
+                let statement = self.select_returns(arms, &mut statements);
+                let guard = self.construct_guard();
+                self.returns.push((guard, statement));
+            }
+            false => self.returns.extend(arms.into_iter().flat_map(|(_, returns)| returns)),
+        }
+
+        (Statement::dummy(Default::default(), self.node_builder.next_id()), statements)
+    }
+
     /// Transforms a return statement into an empty block statement.
     /// Stores the arguments to the return statement, which are later folded into a single return statement at the end of the function.
     fn reconstruct_return(&mut self, input: ReturnStatement) -> (Statement, Self::AdditionalOutput) {
@@ -280,3 +338,17 @@ impl StatementReconstructor for Flattener<'_> {
         (Statement::dummy(Default::default(), self.node_builder.next_id()), Default::default())
     }
 }
+
+/// Returns `true` if every path through the block ends in a `return` statement.
+fn always_returns(block: &Block) -> bool {
+    block.statements.iter().any(|statement| match statement {
+        Statement::Return(_) => true,
+        Statement::Block(block) => always_returns(block),
+        Statement::Conditional(conditional) => {
+            always_returns(&conditional.then)
+                && matches!(conditional.otherwise.as_deref(), Some(Statement::Block(block)) if always_returns(block))
+        }
+        Statement::Match(match_) => match_.arms.iter().all(|arm| always_returns(&arm.body)),
+        _ => false,
+    })
+}
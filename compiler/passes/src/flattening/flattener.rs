@@ -29,6 +29,7 @@ use leo_ast::{
     Identifier,
     IntegerType,
     Literal,
+    MatchPattern,
     Member,
     MemberAccess,
     Node,
@@ -44,10 +45,15 @@ use leo_ast::{
     TupleExpression,
     TupleType,
     Type,
+    UnaryExpression,
+    UnaryOperation,
     UnitExpression,
 };
+use leo_errors::emitter::Handler;
 
 pub struct Flattener<'a> {
+    /// The error handler.
+    pub(crate) handler: &'a Handler,
     /// The symbol table associated with the program.
     pub(crate) symbol_table: &'a SymbolTable,
     /// A mapping between node IDs and their types.
@@ -67,12 +73,21 @@ pub struct Flattener<'a> {
 
 impl<'a> Flattener<'a> {
     pub(crate) fn new(
+        handler: &'a Handler,
         symbol_table: &'a SymbolTable,
         type_table: &'a TypeTable,
         node_builder: &'a NodeBuilder,
         assigner: &'a Assigner,
     ) -> Self {
-        Self { symbol_table, type_table, node_builder, assigner, condition_stack: Vec::new(), returns: Vec::new() }
+        Self {
+            handler,
+            symbol_table,
+            type_table,
+            node_builder,
+            assigner,
+            condition_stack: Vec::new(),
+            returns: Vec::new(),
+        }
     }
 
     /// Clears the state associated with `ReturnStatements`, returning the ones that were previously stored.
@@ -175,6 +190,260 @@ impl<'a> Flattener<'a> {
         }
     }
 
+    /// Constructs a binary expression that evaluates to a boolean, tracking its type.
+    pub(crate) fn binary_expression(&mut self, op: BinaryOperation, left: Expression, right: Expression) -> Expression {
+        Expression::Binary(BinaryExpression {
+            left: Box::new(left),
+            op,
+            right: Box::new(right),
+            span: Default::default(),
+            id: {
+                // Create a new node ID for the binary expression.
+                let id = self.node_builder.next_id();
+                // Set the type of the node ID.
+                self.type_table.insert(id, Type::Boolean);
+                id
+            },
+        })
+    }
+
+    /// Constructs an integer literal of the given type, tracking its type.
+    pub(crate) fn integer_literal(&mut self, type_: IntegerType, value: usize) -> Expression {
+        Expression::Literal(Literal::Integer(type_, value.to_string(), Default::default(), {
+            // Create a new node ID for the literal.
+            let id = self.node_builder.next_id();
+            // Set the type of the node ID.
+            self.type_table.insert(id, Type::Integer(type_));
+            id
+        }))
+    }
+
+    /// Constructs the condition under which the pattern matches the matched value, accumulating any statements that are generated.
+    /// A wildcard always matches, so it has no condition.
+    pub(crate) fn match_condition(
+        &mut self,
+        matched: &Expression,
+        pattern: MatchPattern,
+        statements: &mut Vec<Statement>,
+    ) -> Option<Identifier> {
+        match pattern {
+            MatchPattern::Constant(expression) => {
+                let is_equal = self.binary_expression(BinaryOperation::Eq, matched.clone(), expression);
+                let (condition, statement) = self.unique_simple_assign_statement(is_equal);
+                statements.push(statement);
+                Some(condition)
+            }
+            MatchPattern::Or(patterns, _) => {
+                let conditions = patterns
+                    .into_iter()
+                    .map(|pattern| self.match_condition(matched, pattern, statements))
+                    .collect::<Option<Vec<_>>>()?;
+                conditions.into_iter().reduce(|left, right| {
+                    let either = self.binary_expression(
+                        BinaryOperation::Or,
+                        Expression::Identifier(left),
+                        Expression::Identifier(right),
+                    );
+                    let (condition, statement) = self.unique_simple_assign_statement(either);
+                    statements.push(statement);
+                    condition
+                })
+            }
+            MatchPattern::Wildcard(_) => None,
+            MatchPattern::Variant(_) => unreachable!("Enum lowering replaces variants with their tags."),
+        }
+    }
+
+    /// Selects the value of the first arm whose condition holds, assuming that one of the conditions holds.
+    /// The arms are split in half, so that the number of ternary expressions on any path is logarithmic in the number of arms.
+    /// If `needs_condition` is set, the condition under which one of the arms holds is returned as well.
+    pub(crate) fn select_arm(
+        &mut self,
+        arms: &[(Option<Identifier>, Expression)],
+        type_: &Type,
+        needs_condition: bool,
+        statements: &mut Vec<Statement>,
+    ) -> (Expression, Option<Identifier>) {
+        if let [(condition, value)] = arms {
+            return (value.clone(), *condition);
+        }
+
+        let (first, second) = arms.split_at(arms.len() / 2);
+        let (if_true, first_condition) = self.select_arm(first, type_, true, statements);
+        let (if_false, second_condition) = self.select_arm(second, type_, needs_condition, statements);
+
+        let value = match first_condition {
+            Some(condition) => {
+                // Note that this produces an identifier, since the ternary expression is over identifiers.
+                let (value, stmts) = self.reconstruct_ternary(TernaryExpression {
+                    condition: Box::new(Expression::Identifier(condition)),
+                    if_true: Box::new(if_true),
+                    if_false: Box::new(if_false),
+                    span: Default::default(),
+                    id: {
+                        // Create a new node ID for the ternary expression.
+                        let id = self.node_builder.next_id();
+                        // Set the type of the node ID.
+                        self.type_table.insert(id, type_.clone());
+                        id
+                    },
+                });
+                statements.extend(stmts);
+                value
+            }
+            // The first half always holds, so the second half is never selected.
+            None => if_true,
+        };
+
+        let condition = match (needs_condition, first_condition, second_condition) {
+            (true, Some(first), Some(second)) => {
+                let either = self.binary_expression(
+                    BinaryOperation::Or,
+                    Expression::Identifier(first),
+                    Expression::Identifier(second),
+                );
+                let (condition, statement) = self.unique_simple_assign_statement(either);
+                statements.push(statement);
+                Some(condition)
+            }
+            _ => None,
+        };
+
+        (value, condition)
+    }
+
+    /// Constructs the guard of each arm of a `match` statement from the conditions of the arms, accumulating any statements that are generated.
+    /// The guard of an arm holds if its condition is the first to hold, e.g. `$var$4 = $var$1 && !$var$0` for the second arm.
+    /// The first arm is unguarded if it is a wildcard.
+    pub(crate) fn arm_guards(
+        &mut self,
+        conditions: &[Option<Identifier>],
+        statements: &mut Vec<Statement>,
+    ) -> Vec<Option<Identifier>> {
+        let mut guards = Vec::with_capacity(conditions.len());
+        // Whether the condition of one of the previous arms holds.
+        let mut matched: Option<Identifier> = None;
+        for (i, condition) in conditions.iter().enumerate() {
+            let unmatched = matched.map(|matched| {
+                let not_matched = Expression::Unary(UnaryExpression {
+                    op: UnaryOperation::Not,
+                    receiver: Box::new(Expression::Identifier(matched)),
+                    span: Default::default(),
+                    id: {
+                        // Create a new node ID for the unary expression.
+                        let id = self.node_builder.next_id();
+                        // Set the type of the node ID.
+                        self.type_table.insert(id, Type::Boolean);
+                        id
+                    },
+                });
+                let (unmatched, statement) = self.unique_simple_assign_statement(not_matched);
+                statements.push(statement);
+                unmatched
+            });
+            let guard = match (*condition, unmatched) {
+                (Some(condition), Some(unmatched)) => {
+                    let both = self.binary_expression(
+                        BinaryOperation::And,
+                        Expression::Identifier(condition),
+                        Expression::Identifier(unmatched),
+                    );
+                    let (guard, statement) = self.unique_simple_assign_statement(both);
+                    statements.push(statement);
+                    Some(guard)
+                }
+                (condition, None) => condition,
+                (None, unmatched) => unmatched,
+            };
+            guards.push(guard);
+
+            // The conditions of the previous arms are only needed by the arms that follow.
+            if i + 1 < conditions.len() {
+                matched = match (matched, *condition) {
+                    (Some(matched), Some(condition)) => {
+                        let either = self.binary_expression(
+                            BinaryOperation::Or,
+                            Expression::Identifier(matched),
+                            Expression::Identifier(condition),
+                        );
+                        let (matched, statement) = self.unique_simple_assign_statement(either);
+                        statements.push(statement);
+                        Some(matched)
+                    }
+                    (matched, condition) => condition.or(matched),
+                };
+            }
+        }
+        guards
+    }
+
+    /// Combines the returns of the arms of a `match` statement, each of which always returns, into a single return statement,
+    /// accumulating any statements that are generated.
+    /// The returns of each arm are folded into the value of the arm, and the value of the arm whose condition is the first to hold
+    /// is selected with `select_arm`, as for a `match` expression.
+    pub(crate) fn select_returns(
+        &mut self,
+        arms: Vec<(Option<Identifier>, Vec<(Option<Expression>, ReturnStatement)>)>,
+        statements: &mut Vec<Statement>,
+    ) -> ReturnStatement {
+        // Note that type checking guarantees that every return of the function has the same number of finalize arguments.
+        let number_of_finalize_arguments = arms
+            .first()
+            .and_then(|(_, returns)| returns.first())
+            .and_then(|(_, return_statement)| return_statement.finalize_arguments.as_ref().map(Vec::len));
+
+        let mut values = Vec::with_capacity(arms.len());
+        let mut finalize_arguments = vec![Vec::with_capacity(arms.len()); number_of_finalize_arguments.unwrap_or(0)];
+        for (condition, mut returns) in arms {
+            // The returns after an unguarded return are never reached.
+            if let Some(index) = returns.iter().position(|(guard, _)| guard.is_none()) {
+                returns.truncate(index + 1);
+            }
+
+            // Aggregate the return expressions and finalize arguments of the arm and their respective guards.
+            let mut expressions = Vec::with_capacity(returns.len());
+            let mut arguments = vec![Vec::with_capacity(returns.len()); finalize_arguments.len()];
+            for (guard, return_statement) in returns {
+                for (i, argument) in return_statement.finalize_arguments.into_iter().flatten().enumerate() {
+                    arguments[i].push((guard.clone(), argument));
+                }
+                expressions.push((guard, return_statement.expression));
+            }
+
+            // Fold the returns of the arm into its value.
+            let (value, stmts) = self.fold_guards("$ret", expressions);
+            statements.extend(stmts);
+            values.push((condition, value));
+            for (i, arguments) in arguments.into_iter().enumerate() {
+                let (argument, stmts) = self.fold_guards(&format!("finalize${i}$"), arguments);
+                statements.extend(stmts);
+                finalize_arguments[i].push((condition, argument));
+            }
+        }
+
+        let expression = self.select_value(&values, statements);
+        let finalize_arguments = number_of_finalize_arguments
+            .map(|_| finalize_arguments.iter().map(|arguments| self.select_value(arguments, statements)).collect());
+
+        ReturnStatement { expression, finalize_arguments, span: Default::default(), id: self.node_builder.next_id() }
+    }
+
+    /// Selects the value of the arm whose condition is the first to hold, unless the values are unit expressions.
+    fn select_value(
+        &mut self,
+        arms: &[(Option<Identifier>, Expression)],
+        statements: &mut Vec<Statement>,
+    ) -> Expression {
+        match &arms[0].1 {
+            Expression::Unit(_) => arms[0].1.clone(),
+            value => {
+                // Note that this unwrap is safe since type checking guarantees that all expressions have a type.
+                let type_ = self.type_table.get(&value.id()).unwrap();
+                self.select_arm(arms, &type_, false, statements).0
+            }
+        }
+    }
+
     /// A wrapper around `assigner.unique_simple_assign_statement` that updates `self.structs`.
     pub(crate) fn unique_simple_assign_statement(&mut self, expr: Expression) -> (Identifier, Statement) {
         // Create a new variable for the expression.
@@ -200,7 +469,13 @@ impl<'a> Flattener<'a> {
     }
 
     /// Folds a list of return statements into a single return statement and adds the produced statements to the block.
-    pub(crate) fn fold_returns(&mut self, block: &mut Block, returns: Vec<(Option<Expression>, ReturnStatement)>) {
+    pub(crate) fn fold_returns(&mut self, block: &mut Block, mut returns: Vec<(Option<Expression>, ReturnStatement)>) {
+        // The returns after an unguarded return are never reached.
+        // This happens when a loop body always returns, since its first iteration is unguarded.
+        if let Some(index) = returns.iter().position(|(guard, _)| guard.is_none()) {
+            returns.truncate(index + 1);
+        }
+
         // If the list of returns is not empty, then fold them into a single return statement.
         if !returns.is_empty() {
             let mut return_expressions = Vec::with_capacity(returns.len());
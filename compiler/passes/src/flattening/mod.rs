@@ -16,8 +16,11 @@
 
 //! The flattening pass traverses the AST after the SSA pass and converts into a sequential code.
 //! The pass flattens `ConditionalStatement`s into a sequence of `AssignStatement`s.
+//! The pass flattens `MatchStatement`s in the same way, guarding each arm by the condition that its pattern is the first to match.
+//! If every arm of a `MatchStatement` returns, the returned value is selected by a balanced tree of ternary expressions, as for a `match` expression.
 //! The pass rewrites `ReturnStatement`s into `AssignStatement`s and consolidates the returned values as a single `ReturnStatement` at the end of the function.
 //! The pass rewrites ternary expressions over composite data types, into ternary expressions over the individual fields of the composite data type, followed by an expression constructing the composite data type.
+//! The pass rewrites array accesses with non-constant indices into a bounds check, followed by a chain of ternary expressions over the elements of the array.
 //!
 //! Consider the following Leo code, output by the SSA pass.
 //! ```leo
@@ -62,14 +65,14 @@ pub use flattener::*;
 use crate::{Assigner, Pass, SymbolTable, TypeTable};
 
 use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
-use leo_errors::Result;
+use leo_errors::{emitter::Handler, Result};
 
 impl<'a> Pass for Flattener<'a> {
-    type Input = (Ast, &'a SymbolTable, &'a TypeTable, &'a NodeBuilder, &'a Assigner);
+    type Input = (Ast, &'a Handler, &'a SymbolTable, &'a TypeTable, &'a NodeBuilder, &'a Assigner);
     type Output = Result<Ast>;
 
-    fn do_pass((ast, st, tt, node_builder, assigner): Self::Input) -> Self::Output {
-        let mut reconstructor = Flattener::new(st, tt, node_builder, assigner);
+    fn do_pass((ast, handler, st, tt, node_builder, assigner): Self::Input) -> Self::Output {
+        let mut reconstructor = Flattener::new(handler, st, tt, node_builder, assigner);
         let program = reconstructor.reconstruct_program(ast.into_repr());
 
         Ok(Ast::new(program))
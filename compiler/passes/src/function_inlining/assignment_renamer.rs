@@ -24,6 +24,7 @@ use leo_ast::{
     ExpressionReconstructor,
     Identifier,
     IterationStatement,
+    MatchStatement,
     NodeID,
     ProgramReconstructor,
     Statement,
@@ -134,7 +135,7 @@ impl StatementReconstructor for AssignmentRenamer<'_> {
         unreachable!("`ConditionalStatement`s should not be in the AST at this phase of compilation.")
     }
 
-    /// Parsing guarantees that console statements are not present in the program.
+    /// The console removal pass guarantees that console statements are not present in the program.
     fn reconstruct_console(&mut self, _: ConsoleStatement) -> (Statement, Self::AdditionalOutput) {
         unreachable!("`ConsoleStatement`s should not be in the AST at this phase of compilation.")
     }
@@ -148,6 +149,10 @@ impl StatementReconstructor for AssignmentRenamer<'_> {
     fn reconstruct_iteration(&mut self, _: IterationStatement) -> (Statement, Self::AdditionalOutput) {
         unreachable!("`IterationStatement`s should not be in the AST at this phase of compilation.");
     }
+
+    fn reconstruct_match_statement(&mut self, _: MatchStatement) -> (Statement, Self::AdditionalOutput) {
+        unreachable!("`MatchStatement`s should not be in the AST at this phase of compilation.");
+    }
 }
 
 impl ProgramReconstructor for AssignmentRenamer<'_> {}
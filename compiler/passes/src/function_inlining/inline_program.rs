@@ -16,10 +16,10 @@
 
 use crate::FunctionInliner;
 
-use leo_ast::{Function, ProgramReconstructor, ProgramScope};
+use leo_ast::{Block, Expression, Function, ProgramReconstructor, ProgramScope, Statement, Variant};
 use leo_span::Symbol;
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 
 impl ProgramReconstructor for FunctionInliner<'_> {
     fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
@@ -37,7 +37,14 @@ impl ProgramReconstructor for FunctionInliner<'_> {
             // TODO: Check that this is indeed an external function. Requires a redesign of the symbol table.
             if let Some(function) = function_map.remove(function_name) {
                 // Reconstruct the function.
-                let reconstructed_function = self.reconstruct_function(function);
+                let mut reconstructed_function = self.reconstruct_function(function);
+                // An Aleo closure must have at least one instruction, which a closure may lack once its loops are
+                // unrolled and folded. Such a closure only forwards its inputs or literals, so it is inlined instead.
+                if reconstructed_function.variant == Variant::Standard
+                    && !has_instructions(&reconstructed_function.block)
+                {
+                    reconstructed_function.variant = Variant::Inline;
+                }
                 // Add the reconstructed function to the mapping.
                 self.reconstructed_functions.push((*function_name, reconstructed_function));
             }
@@ -50,7 +57,10 @@ impl ProgramReconstructor for FunctionInliner<'_> {
 
         ProgramScope {
             program_id: input.program_id,
+            modules: input.modules,
+            type_aliases: input.type_aliases,
             structs: input.structs,
+            enums: input.enums,
             mappings: input.mappings,
             functions,
             consts: input.consts,
@@ -58,3 +68,51 @@ impl ProgramReconstructor for FunctionInliner<'_> {
         }
     }
 }
+
+/// Returns whether code generation produces any instructions for the given block, once dead code is eliminated.
+fn has_instructions(block: &Block) -> bool {
+    has_live_instructions(block, &mut IndexSet::new())
+}
+
+/// Traverses the statements of the block in reverse, collecting the variables whose values are used.
+/// Assignments to unused variables are skipped, since dead code elimination removes them.
+fn has_live_instructions(block: &Block, used: &mut IndexSet<Symbol>) -> bool {
+    for statement in block.statements.iter().rev() {
+        let has_instructions = match statement {
+            Statement::Assign(assign) => match &assign.place {
+                Expression::Identifier(identifier) if used.contains(&identifier.name) => {
+                    !use_operand(&assign.value, used)
+                }
+                Expression::Identifier(_) => false,
+                Expression::Tuple(tuple) => tuple.elements.iter().any(|element| match element {
+                    Expression::Identifier(identifier) => used.contains(&identifier.name),
+                    _ => true,
+                }),
+                _ => true,
+            },
+            Statement::Block(block) => has_live_instructions(block, used),
+            Statement::Return(return_) => match &return_.expression {
+                Expression::Tuple(tuple) => !tuple.elements.iter().all(|element| use_operand(element, used)),
+                Expression::Unit(_) => false,
+                expression => !use_operand(expression, used),
+            },
+            _ => true,
+        };
+        if has_instructions {
+            return true;
+        }
+    }
+    false
+}
+
+/// Returns whether the expression is an operand, i.e. a variable or a literal, marking the variable as used.
+fn use_operand(expression: &Expression, used: &mut IndexSet<Symbol>) -> bool {
+    match expression {
+        Expression::Identifier(identifier) => {
+            used.insert(identifier.name);
+            true
+        }
+        Expression::Literal(_) => true,
+        _ => false,
+    }
+}
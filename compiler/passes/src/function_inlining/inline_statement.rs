@@ -26,6 +26,7 @@ use leo_ast::{
     ExpressionReconstructor,
     ExpressionStatement,
     IterationStatement,
+    MatchStatement,
     Statement,
     StatementReconstructor,
 };
@@ -74,7 +75,7 @@ impl StatementReconstructor for FunctionInliner<'_> {
         unreachable!("`ConditionalStatement`s should not be in the AST at this phase of compilation.")
     }
 
-    /// Parsing guarantees that console statements are not present in the program.
+    /// The console removal pass guarantees that console statements are not present in the program.
     fn reconstruct_console(&mut self, _: ConsoleStatement) -> (Statement, Self::AdditionalOutput) {
         unreachable!("`ConsoleStatement`s should not be in the AST at this phase of compilation.")
     }
@@ -103,4 +104,8 @@ impl StatementReconstructor for FunctionInliner<'_> {
     fn reconstruct_iteration(&mut self, _: IterationStatement) -> (Statement, Self::AdditionalOutput) {
         unreachable!("`IterationStatement`s should not be in the AST at this phase of compilation.");
     }
+
+    fn reconstruct_match_statement(&mut self, _: MatchStatement) -> (Statement, Self::AdditionalOutput) {
+        unreachable!("`MatchStatement`s should not be in the AST at this phase of compilation.");
+    }
 }
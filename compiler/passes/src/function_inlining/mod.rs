@@ -51,6 +51,9 @@
 //!     return value$3;
 //! }
 //! ```
+//!
+//! A function whose body produces no instructions, e.g. one that only returns a literal once its loops are unrolled,
+//! cannot be expressed as an Aleo closure. Such a function is treated as an `inline` function and inlined at its call sites.
 
 pub mod assignment_renamer;
 pub use assignment_renamer::*;
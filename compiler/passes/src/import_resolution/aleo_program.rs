@@ -0,0 +1,322 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use leo_ast::{
+    ArrayType,
+    Block,
+    External,
+    Finalize,
+    Function,
+    FunctionInput,
+    FunctionOutput,
+    Identifier,
+    Input,
+    IntegerType,
+    Mapping,
+    Member,
+    Mode,
+    NodeBuilder,
+    NonNegativeNumber,
+    Output,
+    Program,
+    ProgramId,
+    ProgramScope,
+    Struct,
+    Type,
+    Variant,
+};
+use leo_errors::{CompilerError, LeoError, Result};
+use leo_span::{sym, Span, Symbol};
+
+use snarkvm::prelude::{
+    EntryType,
+    FinalizeType,
+    LiteralType,
+    Locator,
+    PlaintextType,
+    Program as AleoProgram,
+    Testnet3,
+    ValueType,
+};
+
+use indexmap::IndexMap;
+use std::{fmt::Display, path::Path, str::FromStr};
+
+/// Builds the Leo program standing for a program compiled into Aleo instructions.
+///
+/// The program declares the structs, records, mappings and transition signatures of the Aleo program,
+/// so that they can be used by the programs that import it.
+/// The bodies of its transitions are left empty, and its closures are omitted, since other programs cannot call them.
+pub(crate) struct AleoProgramBuilder<'a> {
+    /// A counter used to generate unique node IDs.
+    node_builder: &'a NodeBuilder,
+    /// The name of the program.
+    name: Symbol,
+    /// The span of the import of the program, to which every node of the program points.
+    span: Span,
+}
+
+impl<'a> AleoProgramBuilder<'a> {
+    /// Parses the Aleo instructions read from `file_path` into the Leo program `name`, imported at `span`.
+    pub(crate) fn build(
+        node_builder: &'a NodeBuilder,
+        name: Symbol,
+        span: Span,
+        file_path: &Path,
+        source: &str,
+    ) -> Result<Program> {
+        let program = AleoProgram::<Testnet3>::from_str(source)
+            .map_err(|e| CompilerError::failed_to_parse_aleo_import(file_path.display(), e, span))?;
+
+        // Check that the name of the program matches the name of the import.
+        if Symbol::intern(&program.id().name().to_string()) != name {
+            return Err(CompilerError::program_scope_name_does_not_match(program.id().name(), name, span).into());
+        }
+
+        Self { node_builder, name, span }.program(&program)
+    }
+
+    fn program(&self, program: &AleoProgram<Testnet3>) -> Result<Program> {
+        // The programs imported by the program are loaded by the import resolver, like those of a Leo program.
+        let imports =
+            program.imports().keys().map(|id| (self.symbol(id.name()), (Program::default(), self.span))).collect();
+
+        let structs = program
+            .structs()
+            .iter()
+            .map(|(name, struct_)| {
+                let members = struct_
+                    .members()
+                    .iter()
+                    .map(|(identifier, type_)| self.member(Mode::None, identifier, type_))
+                    .collect::<Result<_>>()?;
+                Ok((self.symbol(name), self.struct_(name, members, false)))
+            })
+            .chain(program.records().iter().map(|(name, record)| {
+                let owner_mode = if record.owner().is_public() { Mode::Public } else { Mode::Private };
+                let owner = Member {
+                    mode: owner_mode,
+                    identifier: self.identifier(sym::owner),
+                    type_: Type::Address,
+                    span: self.span,
+                    id: self.node_builder.next_id(),
+                };
+                let entries = record.entries().iter().map(|(identifier, entry)| match entry {
+                    EntryType::Constant(type_) => self.member(Mode::Constant, identifier, type_),
+                    EntryType::Public(type_) => self.member(Mode::Public, identifier, type_),
+                    EntryType::Private(type_) => self.member(Mode::Private, identifier, type_),
+                });
+                let members = std::iter::once(Ok(owner)).chain(entries).collect::<Result<_>>()?;
+                Ok((self.symbol(name), self.struct_(name, members, true)))
+            }))
+            .collect::<Result<_>>()?;
+
+        let mappings = program
+            .mappings()
+            .iter()
+            .map(|(name, mapping)| {
+                let mapping = Mapping {
+                    identifier: self.identifier(self.symbol(name)),
+                    key_type: self.type_(mapping.key().plaintext_type())?,
+                    value_type: self.type_(mapping.value().plaintext_type())?,
+                    span: self.span,
+                    id: self.node_builder.next_id(),
+                };
+                Ok((self.symbol(name), mapping))
+            })
+            .collect::<Result<_>>()?;
+
+        let functions = program
+            .functions()
+            .iter()
+            .map(|(name, function)| {
+                let identifier = self.identifier(self.symbol(name));
+                let input = function
+                    .inputs()
+                    .iter()
+                    .map(|input| self.input(input.register(), input.value_type()))
+                    .collect::<Result<_>>()?;
+                // Note that the futures of finalize blocks are implicit in Leo, so they are not declared.
+                let output = function
+                    .outputs()
+                    .iter()
+                    .filter(|output| !matches!(output.value_type(), ValueType::Future(_)))
+                    .map(|output| self.output(output.value_type()))
+                    .collect::<Result<_>>()?;
+                let finalize = match function.finalize_logic() {
+                    Some(finalize) => {
+                        let input = finalize
+                            .inputs()
+                            .iter()
+                            .filter_map(|input| match input.finalize_type() {
+                                FinalizeType::Plaintext(type_) => Some(self.type_(type_).map(|type_| {
+                                    Input::Internal(FunctionInput {
+                                        identifier: self.identifier(self.symbol(input.register())),
+                                        mode: Mode::Public,
+                                        type_,
+                                        span: self.span,
+                                        id: self.node_builder.next_id(),
+                                    })
+                                })),
+                                FinalizeType::Future(_) => None,
+                            })
+                            .collect::<Result<_>>()?;
+                        Some(Finalize::new(
+                            identifier,
+                            input,
+                            Vec::new(),
+                            self.block(),
+                            self.span,
+                            self.node_builder.next_id(),
+                        ))
+                    }
+                    None => None,
+                };
+                let function = Function::new(
+                    Vec::new(),
+                    Variant::Transition,
+                    identifier,
+                    input,
+                    output,
+                    self.block(),
+                    finalize,
+                    self.span,
+                    self.node_builder.next_id(),
+                );
+                Ok((self.symbol(name), function))
+            })
+            .collect::<Result<_>>()?;
+
+        let program_scope = ProgramScope {
+            program_id: ProgramId { name: self.identifier(self.name), network: self.identifier(sym::aleo) },
+            modules: Vec::new(),
+            consts: Vec::new(),
+            type_aliases: Vec::new(),
+            structs,
+            enums: Vec::new(),
+            mappings,
+            functions,
+            span: self.span,
+        };
+
+        Ok(Program { imports, program_scopes: IndexMap::from([(self.name, program_scope)]) })
+    }
+
+    /// Returns the Leo type of the given Aleo type.
+    fn type_(&self, type_: &PlaintextType<Testnet3>) -> Result<Type> {
+        Ok(match type_ {
+            PlaintextType::Literal(literal) => match literal {
+                LiteralType::Address => Type::Address,
+                LiteralType::Boolean => Type::Boolean,
+                LiteralType::Field => Type::Field,
+                LiteralType::Group => Type::Group,
+                LiteralType::I8 => Type::Integer(IntegerType::I8),
+                LiteralType::I16 => Type::Integer(IntegerType::I16),
+                LiteralType::I32 => Type::Integer(IntegerType::I32),
+                LiteralType::I64 => Type::Integer(IntegerType::I64),
+                LiteralType::I128 => Type::Integer(IntegerType::I128),
+                LiteralType::U8 => Type::Integer(IntegerType::U8),
+                LiteralType::U16 => Type::Integer(IntegerType::U16),
+                LiteralType::U32 => Type::Integer(IntegerType::U32),
+                LiteralType::U64 => Type::Integer(IntegerType::U64),
+                LiteralType::U128 => Type::Integer(IntegerType::U128),
+                LiteralType::Scalar => Type::Scalar,
+                LiteralType::Signature => Type::Signature,
+                LiteralType::String => Type::String,
+            },
+            PlaintextType::Struct(name) => Type::Identifier(self.identifier(self.symbol(name))),
+            PlaintextType::Array(array) => Type::Array(ArrayType::new(
+                self.type_(array.next_element_type())?,
+                NonNegativeNumber::from(**array.length() as usize),
+            )),
+        })
+    }
+
+    /// Returns the input of a transition, named after its register.
+    fn input(&self, register: impl Display, type_: &ValueType<Testnet3>) -> Result<Input> {
+        let identifier = self.identifier(self.symbol(register));
+        let (mode, type_) = match type_ {
+            ValueType::Public(type_) => (Mode::Public, self.type_(type_)?),
+            ValueType::Private(type_) => (Mode::Private, self.type_(type_)?),
+            ValueType::Record(record) => (Mode::None, Type::Identifier(self.identifier(self.symbol(record)))),
+            ValueType::ExternalRecord(locator) => return Ok(Input::External(self.external(identifier, locator))),
+            // Note that transitions in Leo cannot take constant inputs.
+            ValueType::Constant(_) | ValueType::Future(_) => return Err(self.unsupported(format!("input {type_}"))),
+        };
+        Ok(Input::Internal(FunctionInput { identifier, mode, type_, span: self.span, id: self.node_builder.next_id() }))
+    }
+
+    /// Returns the output of a transition.
+    fn output(&self, type_: &ValueType<Testnet3>) -> Result<Output> {
+        let (mode, type_) = match type_ {
+            ValueType::Public(type_) => (Mode::Public, self.type_(type_)?),
+            ValueType::Private(type_) => (Mode::Private, self.type_(type_)?),
+            ValueType::Record(record) => (Mode::None, Type::Identifier(self.identifier(self.symbol(record)))),
+            ValueType::ExternalRecord(locator) => {
+                return Ok(Output::External(self.external(self.identifier(Symbol::intern("dummy")), locator)));
+            }
+            // Note that transitions in Leo cannot have constant outputs.
+            ValueType::Constant(_) | ValueType::Future(_) => return Err(self.unsupported(format!("output {type_}"))),
+        };
+        Ok(Output::Internal(FunctionOutput { mode, type_, span: self.span, id: self.node_builder.next_id() }))
+    }
+
+    /// Returns the input or output of a transition that is a record of another program.
+    fn external(&self, identifier: Identifier, locator: &Locator<Testnet3>) -> External {
+        External {
+            identifier,
+            program_name: self.identifier(self.symbol(locator.name())),
+            record: self.identifier(self.symbol(locator.resource())),
+            span: self.span,
+            id: self.node_builder.next_id(),
+        }
+    }
+
+    fn member(&self, mode: Mode, identifier: impl Display, type_: &PlaintextType<Testnet3>) -> Result<Member> {
+        Ok(Member {
+            mode,
+            identifier: self.identifier(self.symbol(identifier)),
+            type_: self.type_(type_)?,
+            span: self.span,
+            id: self.node_builder.next_id(),
+        })
+    }
+
+    fn struct_(&self, name: impl Display, members: Vec<Member>, is_record: bool) -> Struct {
+        Struct {
+            identifier: self.identifier(self.symbol(name)),
+            members,
+            is_record,
+            span: self.span,
+            id: self.node_builder.next_id(),
+        }
+    }
+
+    fn block(&self) -> Block {
+        Block { statements: Vec::new(), span: self.span, id: self.node_builder.next_id() }
+    }
+
+    fn identifier(&self, name: Symbol) -> Identifier {
+        Identifier { name, span: self.span, id: self.node_builder.next_id() }
+    }
+
+    fn symbol(&self, name: impl Display) -> Symbol {
+        Symbol::intern(&name.to_string())
+    }
+
+    fn unsupported(&self, item: impl Display) -> LeoError {
+        CompilerError::unsupported_aleo_import(format!("{}.aleo", self.name), item, self.span).into()
+    }
+}
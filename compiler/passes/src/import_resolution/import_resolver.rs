@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use crate::{AleoProgramBuilder, DiGraphError, ImportGraph, ModuleResolver};
+
+use leo_ast::{NodeBuilder, Program};
+use leo_errors::{emitter::Handler, CompilerError, Result};
+use leo_span::{source_map::FileName, symbol::with_session_globals, Span, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The name of the directory of a package that contains the Leo source or the Aleo instructions of its imports.
+const IMPORTS_DIRECTORY_NAME: &str = "imports";
+
+/// The extension of the files of programs compiled into Aleo instructions.
+const ALEO_FILE_EXTENSION: &str = "aleo";
+
+pub struct ImportResolver<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// A counter used to generate unique node IDs.
+    node_builder: &'a NodeBuilder,
+    /// The root directory of the package, whose imports directory is searched for the imported programs.
+    package_root: PathBuf,
+    /// The paths to the imported programs which are not in the imports directory.
+    import_paths: &'a IndexMap<Symbol, PathBuf>,
+    /// The programs loaded so far, by name, along with the span of the first import of each.
+    programs: IndexMap<Symbol, (Program, Span)>,
+    /// The dependency graph of the programs, with an edge from each program to each program it imports.
+    import_graph: ImportGraph,
+}
+
+impl<'a> ImportResolver<'a> {
+    /// Returns a new import resolver, which looks up imports in the given paths, or in the given package otherwise.
+    pub(crate) fn new(
+        handler: &'a Handler,
+        node_builder: &'a NodeBuilder,
+        package_root: &Path,
+        import_paths: &'a IndexMap<Symbol, PathBuf>,
+    ) -> Self {
+        Self {
+            handler,
+            node_builder,
+            package_root: package_root.to_path_buf(),
+            import_paths,
+            programs: IndexMap::new(),
+            import_graph: ImportGraph::new(IndexSet::new()),
+        }
+    }
+
+    /// Loads the programs imported by the program, transitively, and flattens them into its imports.
+    pub(crate) fn resolve(mut self, mut program: Program) -> Result<Program> {
+        // Note that parsing guarantees that there is exactly one program scope.
+        let name = *program.program_scopes.keys().next().unwrap();
+        self.import_graph = ImportGraph::new(IndexSet::from([name]));
+        self.load_imports(name, &program)?;
+
+        // Order the programs so that each program comes after the programs it imports.
+        let order = match self.import_graph.post_order() {
+            Ok(order) => order,
+            Err(DiGraphError::CycleDetected(path)) => return Err(CompilerError::cyclic_imports(path).into()),
+        };
+
+        program.imports = order
+            .into_iter()
+            .filter(|import| *import != name)
+            .filter_map(|import| self.programs.swap_remove(&import).map(|program| (import, program)))
+            .collect();
+        Ok(program)
+    }
+
+    /// Loads the programs imported by `program`, named `name`, and the programs they import in turn.
+    fn load_imports(&mut self, name: Symbol, program: &Program) -> Result<()> {
+        for (import, (_, span)) in program.imports.iter() {
+            // Each program is only loaded the first time it is imported.
+            let is_loaded = self.import_graph.contains_node(*import);
+            self.import_graph.add_edge(name, *import);
+            if !is_loaded {
+                let mut imported_program = self.load(*import, *span)?;
+                self.load_imports(*import, &imported_program)?;
+                // The imports of the imported program are flattened into the imports of the main program.
+                imported_program.imports.clear();
+                self.programs.insert(*import, (imported_program, *span));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the path to the file of the imported program `name`.
+    /// It is the path given for the program if any.
+    /// Otherwise, it is its Leo source in the imports directory of the package, or else its Aleo instructions.
+    pub fn import_file_path(name: Symbol, package_root: &Path, import_paths: &IndexMap<Symbol, PathBuf>) -> PathBuf {
+        if let Some(file_path) = import_paths.get(&name) {
+            return file_path.clone();
+        }
+        let imports_directory = package_root.join(IMPORTS_DIRECTORY_NAME);
+        let leo_file_path = imports_directory.join(format!("{name}.leo"));
+        let aleo_file_path = imports_directory.join(format!("{name}.{ALEO_FILE_EXTENSION}"));
+        match !leo_file_path.exists() && aleo_file_path.exists() {
+            true => aleo_file_path,
+            false => leo_file_path,
+        }
+    }
+
+    /// Returns whether the file of an imported program holds Aleo instructions, rather than Leo source.
+    pub fn is_aleo_file(file_path: &Path) -> bool {
+        file_path.extension().map_or(false, |extension| extension == ALEO_FILE_EXTENSION)
+    }
+
+    /// Parses the Leo source of the imported program `name`, imported at `span`.
+    /// Programs imported from their Aleo instructions are parsed into a program declaring their items, without bodies.
+    fn load(&self, name: Symbol, span: Span) -> Result<Program> {
+        let file_path = Self::import_file_path(name, &self.package_root, self.import_paths);
+
+        // Throw an error if the import file doesn't exist.
+        if !file_path.exists() {
+            return Err(CompilerError::import_not_found(file_path.display(), span).into());
+        }
+
+        let source = fs::read_to_string(&file_path).map_err(|e| CompilerError::file_read_error(&file_path, e))?;
+        if Self::is_aleo_file(&file_path) {
+            return AleoProgramBuilder::build(self.node_builder, name, span, &file_path, &source);
+        }
+
+        // Register the source of the imported program in the source map, and parse it.
+        let source_file = with_session_globals(|s| s.source_map.new_source(&source, FileName::Real(file_path.clone())));
+        let program = leo_parser::parse(self.handler, self.node_builder, &source_file.src, source_file.start_pos)?;
+
+        // The modules of the imported program are in the directory of its file.
+        // Note that the path of an existing file always has a parent.
+        let program =
+            ModuleResolver::new(self.handler, self.node_builder, file_path.parent().unwrap()).resolve(program)?;
+
+        // Check that the name of the program scope matches the name of the import.
+        // Note that parsing guarantees that there is exactly one program scope.
+        let program_id = program.program_scopes.values().next().unwrap().program_id;
+        if program_id.name.name != name {
+            return Err(
+                CompilerError::program_scope_name_does_not_match(program_id.name, name, program_id.name.span).into()
+            );
+        }
+
+        Ok(program)
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+//! The import resolution pass loads the programs imported by the program from the `imports/` directory of its package,
+//! or from the paths given for the dependencies declared in the manifest of the package.
+//! An imported program is either Leo source, in a `.leo` file, or compiled to Aleo instructions, in a `.aleo` file.
+//! The latter is turned into a Leo program that declares its structs, records, mappings and transitions,
+//! so that the importing programs can use them.
+//! Imports are transitive: an imported program may itself import other programs.
+//! Each program is parsed once, no matter how many programs import it, and cyclic imports are rejected.
+//!
+//! The imports of the program are flattened, so that it holds every program it depends on, directly or not,
+//! ordered so that each program comes after the programs it imports.
+//!
+//! Consider a package with the following programs.
+//! ```leo
+//! // src/main.leo
+//! import token.leo;
+//! program main.aleo { ... }
+//!
+//! // imports/token.leo
+//! import math.leo;
+//! program token.aleo { ... }
+//!
+//! // imports/math.leo
+//! program math.aleo { ... }
+//! ```
+//!
+//! After the import resolution pass, `main.aleo` imports `math.aleo` followed by `token.aleo`.
+
+mod aleo_program;
+pub(crate) use aleo_program::*;
+
+pub mod import_resolver;
+pub use import_resolver::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, NodeBuilder};
+use leo_errors::{emitter::Handler, Result};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+use std::path::{Path, PathBuf};
+
+impl<'a> Pass for ImportResolver<'a> {
+    type Input = (Ast, &'a Handler, &'a NodeBuilder, &'a Path, &'a IndexMap<Symbol, PathBuf>);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, handler, node_builder, package_root, import_paths): Self::Input) -> Self::Output {
+        let resolver = ImportResolver::new(handler, node_builder, package_root, import_paths);
+        Ok(Ast::new(resolver.resolve(ast.into_repr())?))
+    }
+}
@@ -23,21 +23,39 @@ pub use code_generation::*;
 pub mod common;
 pub use common::*;
 
+pub mod common_subexpression_elimination;
+pub use common_subexpression_elimination::*;
+
+pub mod console_removal;
+pub use console_removal::*;
+
+pub mod constant_folding;
+pub use constant_folding::*;
+
 pub mod dead_code_elimination;
 pub use dead_code_elimination::*;
 
 pub mod destructuring;
 pub use destructuring::*;
 
+pub mod enum_lowering;
+pub use enum_lowering::*;
+
 pub mod flattening;
 pub use flattening::*;
 
 pub mod function_inlining;
 pub use function_inlining::*;
 
+pub mod import_resolution;
+pub use import_resolution::*;
+
 pub mod loop_unrolling;
 pub use self::loop_unrolling::*;
 
+pub mod module_resolution;
+pub use module_resolution::*;
+
 pub mod pass;
 pub use self::pass::*;
 
@@ -47,5 +65,8 @@ pub use static_single_assignment::*;
 pub mod symbol_table_creation;
 pub use symbol_table_creation::*;
 
+pub mod type_alias_resolution;
+pub use type_alias_resolution::*;
+
 pub mod type_checking;
 pub use type_checking::*;
@@ -29,17 +29,17 @@ pub use unroll_program::*;
 pub mod unroll_statement;
 pub use unroll_statement::*;
 
-use crate::{Pass, SymbolTable, TypeTable};
+use crate::{Assigner, Pass, SymbolTable, TypeTable};
 
 use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
 use leo_errors::{emitter::Handler, Result};
 
 impl<'a> Pass for Unroller<'a> {
-    type Input = (Ast, &'a Handler, &'a NodeBuilder, SymbolTable, &'a TypeTable);
+    type Input = (Ast, &'a Handler, &'a NodeBuilder, &'a Assigner, SymbolTable, &'a TypeTable);
     type Output = Result<(Ast, SymbolTable)>;
 
-    fn do_pass((ast, handler, node_builder, st, tt): Self::Input) -> Self::Output {
-        let mut reconstructor = Self::new(st, tt, handler, node_builder);
+    fn do_pass((ast, handler, node_builder, assigner, st, tt): Self::Input) -> Self::Output {
+        let mut reconstructor = Self::new(st, tt, handler, node_builder, assigner);
         let program = reconstructor.reconstruct_program(ast.into_repr());
         handler.last_err().map_err(|e| *e)?;
 
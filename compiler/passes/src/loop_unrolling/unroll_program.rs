@@ -15,18 +15,27 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use leo_ast::*;
+use leo_span::Symbol;
 
 use crate::Unroller;
 
 impl ProgramReconstructor for Unroller<'_> {
     fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
-        // Don't need to reconstructed consts, just need to add them to constant propagation table
-        input.consts.into_iter().for_each(|(_, c)| {
-            self.reconstruct_const(c);
-        });
+        // Don't need to reconstructed consts, just need to add them to constant propagation table.
+        // Consts may reference consts declared after them, which are evaluated on demand.
+        let names: Vec<Symbol> = input.consts.iter().map(|(name, _)| *name).collect();
+        self.pending_consts = input.consts.into_iter().collect();
+        for name in names {
+            if let Some(c) = self.pending_consts.shift_remove(&name) {
+                self.reconstruct_const(c);
+            }
+        }
         ProgramScope {
             program_id: input.program_id,
+            modules: input.modules,
+            type_aliases: input.type_aliases,
             structs: input.structs,
+            enums: input.enums,
             mappings: input.mappings,
             functions: input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect(),
             consts: Vec::new(),
@@ -44,14 +53,18 @@ impl ProgramReconstructor for Unroller<'_> {
 
         let previous_scope_index = self.enter_scope(self.scope_index);
 
+        self.enter_function_body(&function.block);
         let block = self.reconstruct_block(function.block).0;
+        let block = self.exit_function_body(block);
 
         self.exit_scope(previous_scope_index);
 
         let finalize = function.finalize.map(|finalize| {
             let previous_scope_index = self.enter_scope(self.scope_index);
 
+            self.enter_function_body(&finalize.block);
             let block = self.reconstruct_block(finalize.block).0;
+            let block = self.exit_function_body(block);
 
             self.exit_scope(previous_scope_index);
 
@@ -19,30 +19,38 @@ use leo_ast::{Expression::Literal, Type::Integer, *};
 use leo_errors::loop_unroller::LoopUnrollerError;
 use leo_span::{Span, Symbol};
 
-use crate::{unroller::Unroller, VariableSymbol, VariableType};
+use crate::{unroller::Unroller, ConstantFolder, VariableSymbol, VariableType};
 
 impl StatementReconstructor for Unroller<'_> {
+    fn reconstruct_array_iteration(&mut self, input: ArrayIterationStatement) -> (Statement, Self::AdditionalOutput) {
+        (self.unroll_array_iteration_statement(input), Default::default())
+    }
+
+    fn reconstruct_assign(&mut self, input: AssignStatement) -> (Statement, Self::AdditionalOutput) {
+        // Reconstruct the place, so that array indices in the place are propagated as well.
+        let place = match input.place {
+            Expression::Identifier(identifier) => Expression::Identifier(identifier),
+            place => self.reconstruct_expression(place).0,
+        };
+
+        (
+            Statement::Assign(Box::new(AssignStatement {
+                place,
+                value: self.reconstruct_expression(input.value).0,
+                span: input.span,
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
         let scope_index = self.current_scope_index();
 
         // Enter the block scope.
         let previous_scope_index = self.enter_scope(scope_index);
 
-        // Filter out the statements that have additional output = true
-        let filtered_statements: Vec<_> = input
-            .statements
-            .into_iter()
-            .filter_map(|s| {
-                let (reconstructed_statement, additional_output) = self.reconstruct_statement(s);
-                if additional_output {
-                    None // Exclude this statement from the block since it is a constant variable definition
-                } else {
-                    Some(reconstructed_statement)
-                }
-            })
-            .collect();
-
-        let block = Block { statements: filtered_statements, span: input.span, id: input.id };
+        let block = Block { statements: self.reconstruct_statements(input.statements), span: input.span, id: input.id };
 
         // Exit the block scope.
         self.exit_scope(previous_scope_index);
@@ -51,11 +59,19 @@ impl StatementReconstructor for Unroller<'_> {
     }
 
     fn reconstruct_const(&mut self, input: ConstDeclaration) -> (Statement, Self::AdditionalOutput) {
-        // Reconstruct the RHS expression to allow for constant propagation
-        let reconstructed_value_expression = self.reconstruct_expression(input.value.clone()).0;
-
-        // Add to constant propagation table. Since TC completed we know that the RHS is a literal or tuple of literals.
-        if let Err(err) = self.constant_propagation_table.borrow_mut().insert_constant(input.place.name, input.value) {
+        // Reconstruct the RHS expression to allow for constant propagation.
+        let reconstructed_value_expression = self.reconstruct_expression(input.value).0;
+
+        // Evaluate the RHS expression. Since TC completed we know that it only depends on literals and other consts.
+        let mut folder = ConstantFolder::new(self.handler, self.node_builder, self.type_table);
+        let reconstructed_value_expression = folder.reconstruct_expression(reconstructed_value_expression).0;
+
+        // Add to constant propagation table.
+        if let Err(err) = self
+            .constant_propagation_table
+            .borrow_mut()
+            .insert_constant(input.place.name, reconstructed_value_expression.clone())
+        {
             self.handler.emit_err(err);
         }
 
@@ -15,23 +15,45 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use leo_ast::{
+    AccessExpression,
+    ArrayAccess,
+    ArrayIterationStatement,
+    ArrayType,
+    BinaryExpression,
+    BinaryOperation,
     Block,
+    ConditionalStatement,
+    ConstDeclaration,
+    DeclarationType,
+    DefinitionStatement,
     Expression,
+    ExpressionReconstructor,
+    Identifier,
     IntegerType,
     IterationStatement,
     Literal,
+    MatchArm,
+    MatchStatement,
+    Node,
     NodeBuilder,
+    NodeID,
     Statement,
     StatementReconstructor,
     Type,
+    UnaryExpression,
+    UnaryOperation,
     Value,
 };
 use std::cell::RefCell;
 
 use leo_errors::{emitter::Handler, loop_unroller::LoopUnrollerError};
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexMap;
 
 use crate::{
     constant_propagation_table::ConstantPropagationTable,
+    Assigner,
     Clusivity,
     LoopBound,
     RangeIterator,
@@ -39,6 +61,22 @@ use crate::{
     TypeTable,
 };
 
+/// The flags that record whether the body of a loop being unrolled was exited early.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct LoopExitFlags {
+    /// Set by a `break` or `return` statement, after which the remaining iterations are skipped.
+    break_flag: Option<Symbol>,
+    /// Set by a `continue` statement, after which the rest of the current iteration is skipped.
+    continue_flag: Option<Symbol>,
+}
+
+impl LoopExitFlags {
+    /// Returns the flags that are used by the loop.
+    fn iter(&self) -> impl Iterator<Item = Symbol> {
+        self.break_flag.into_iter().chain(self.continue_flag)
+    }
+}
+
 pub struct Unroller<'a> {
     /// A table of constant variables.
     pub(crate) constant_propagation_table: RefCell<ConstantPropagationTable>,
@@ -52,8 +90,16 @@ pub struct Unroller<'a> {
     pub(crate) handler: &'a Handler,
     /// A counter used to generate unique node IDs.
     pub(crate) node_builder: &'a NodeBuilder,
+    /// A struct used to construct (unique) assignment statements.
+    pub(crate) assigner: &'a Assigner,
+    /// The exit flags of the loops being unrolled, from the outermost to the innermost loop.
+    pub(crate) loop_exit_flags: Vec<LoopExitFlags>,
+    /// Set by a `return` statement inside a loop, after which the rest of the function body is skipped.
+    pub(crate) returned_flag: Option<Symbol>,
     /// Are we in the midst of unrolling a loop?
     pub(crate) is_unrolling: bool,
+    /// The program-scope constants that have not been evaluated yet.
+    pub(crate) pending_consts: IndexMap<Symbol, ConstDeclaration>,
 }
 
 impl<'a> Unroller<'a> {
@@ -62,6 +108,7 @@ impl<'a> Unroller<'a> {
         type_table: &'a TypeTable,
         handler: &'a Handler,
         node_builder: &'a NodeBuilder,
+        assigner: &'a Assigner,
     ) -> Self {
         Self {
             constant_propagation_table: RefCell::new(ConstantPropagationTable::default()),
@@ -70,7 +117,11 @@ impl<'a> Unroller<'a> {
             scope_index: 0,
             handler,
             node_builder,
+            assigner,
+            loop_exit_flags: Vec::new(),
+            returned_flag: None,
             is_unrolling: false,
+            pending_consts: IndexMap::new(),
         }
     }
 
@@ -142,6 +193,9 @@ impl<'a> Unroller<'a> {
             Err(s) => return s,
         };
 
+        // Lower the `break`, `continue`, and `return` statements in the loop body.
+        let input = IterationStatement { block: self.enter_loop(input.block), ..input };
+
         // Get the index of the current scope.
         let scope_index = self.current_scope_index();
 
@@ -154,29 +208,165 @@ impl<'a> Unroller<'a> {
         self.symbol_table.borrow_mut().scopes.clear();
         self.symbol_table.borrow_mut().scope_index = 0;
 
-        // Create a block statement to replace the iteration statement.
-        // Creates a new block per iteration inside the outer block statement.
-        let iter_blocks = Statement::Block(Block {
-            span: input.span,
-            statements: match input.inclusive {
-                true => {
-                    let iter = RangeIterator::new(start, stop, Clusivity::Inclusive);
-                    iter.map(|iteration_count| self.unroll_single_iteration(&input, iteration_count)).collect()
-                }
-                false => {
-                    let iter = RangeIterator::new(start, stop, Clusivity::Exclusive);
-                    iter.map(|iteration_count| self.unroll_single_iteration(&input, iteration_count)).collect()
-                }
-            },
-            id: input.id,
+        // Create a new block per iteration.
+        let iterations = match input.inclusive {
+            true => {
+                let iter = RangeIterator::new(start, stop, Clusivity::Inclusive);
+                iter.map(|iteration_count| self.unroll_single_iteration(&input, iteration_count)).collect()
+            }
+            false => {
+                let iter = RangeIterator::new(start, stop, Clusivity::Exclusive);
+                iter.map(|iteration_count| self.unroll_single_iteration(&input, iteration_count)).collect()
+            }
+        };
+
+        // Create a block statement to replace the iteration statement, containing the blocks of each iteration.
+        let iter_blocks =
+            Statement::Block(Block { span: input.span, statements: self.exit_loop(iterations), id: input.id });
+
+        // Exit the scope of the loop body.
+        self.exit_scope(previous_scope_index);
+
+        iter_blocks
+    }
+
+    /// Unrolls an ArrayIterationStatement into a block per element of the array.
+    /// The array is first bound to a fresh variable, e.g. `let $array$0: [u8; 2] = arr;`, so that it is evaluated once,
+    /// before the loop body runs, as in the interpreter.
+    /// Each block binds the loop variable to the element, e.g. `let x: u8 = $array$0[0u32];`, followed by the loop body.
+    pub(crate) fn unroll_array_iteration_statement(&mut self, input: ArrayIterationStatement) -> Statement {
+        // Type checking guarantees that the iterated expression is an array.
+        let array_type = match self.type_table.get(&input.array.id()) {
+            Some(Type::Array(array_type)) => array_type,
+            _ => unreachable!("Type checking guarantees that the iterated expression is an array."),
+        };
+
+        // Bind the array to a fresh variable, so that the writes of the loop body do not change the iterated elements.
+        let array = self.assigner.unique_symbol("$array", "$");
+        let array_definition = Statement::Definition(DefinitionStatement {
+            declaration_type: DeclarationType::Let,
+            place: Expression::Identifier(self.array_identifier(array, &input.array, &array_type)),
+            type_: Type::Array(array_type.clone()),
+            value: self.reconstruct_expression(input.array.clone()).0,
+            span: input.array.span(),
+            id: self.node_builder.next_id(),
         });
 
+        // Lower the `break`, `continue`, and `return` statements in the loop body.
+        let input = ArrayIterationStatement { block: self.enter_loop(input.block), ..input };
+
+        // Get the index of the current scope.
+        let scope_index = self.current_scope_index();
+
+        // Enter the scope of the loop body.
+        let previous_scope_index = self.enter_scope(scope_index);
+
+        // Clear the symbol table and constant propagation table for the loop body.
+        // This is necessary because loop unrolling transforms the program, which requires reconstructing the tables
+        self.symbol_table.borrow_mut().variables.clear();
+        self.symbol_table.borrow_mut().scopes.clear();
+        self.symbol_table.borrow_mut().scope_index = 0;
+
+        // Create a new block per element.
+        let iterations = (0..array_type.length())
+            .map(|index| {
+                let array = self.array_identifier(array, &input.array, &array_type);
+                self.unroll_single_array_iteration(&input, array, array_type.element_type(), index)
+            })
+            .collect();
+
+        // Create a block statement to replace the iteration statement, containing the definition of the array,
+        // followed by the blocks of each iteration.
+        let statements = core::iter::once(array_definition).chain(self.exit_loop(iterations)).collect();
+        let iter_blocks = Statement::Block(Block { span: input.span, statements, id: input.id });
+
         // Exit the scope of the loop body.
         self.exit_scope(previous_scope_index);
 
         iter_blocks
     }
 
+    /// A helper function to unroll the iteration of an ArrayIterationStatement over a single element.
+    fn unroll_single_array_iteration(
+        &mut self,
+        input: &ArrayIterationStatement,
+        array: Identifier,
+        element_type: &Type,
+        index: usize,
+    ) -> Statement {
+        // Create a scope for a single unrolling of the `ArrayIterationStatement`.
+        let scope_index = self.symbol_table.borrow_mut().insert_block();
+        let previous_scope_index = self.enter_scope(scope_index);
+
+        let prior_is_unrolling = self.is_unrolling;
+        self.is_unrolling = true;
+
+        // Construct the access of the element, e.g. `$array$0[0u32]`.
+        let access = Expression::Access(AccessExpression::Array(ArrayAccess {
+            array: Box::new(Expression::Identifier(array)),
+            index: Box::new(Expression::Literal(Literal::Integer(
+                IntegerType::U32,
+                index.to_string(),
+                Default::default(),
+                {
+                    // Create a new node ID for the literal.
+                    let id = self.node_builder.next_id();
+                    // Set the type of the node ID.
+                    self.type_table.insert(id, Type::Integer(IntegerType::U32));
+                    id
+                },
+            ))),
+            span: input.array.span(),
+            id: {
+                // Create a new node ID for the access expression.
+                let id = self.node_builder.next_id();
+                // Set the type of the node ID.
+                self.type_table.insert(id, element_type.clone());
+                id
+            },
+        }));
+
+        // Bind the loop variable to the element.
+        let definition = DefinitionStatement {
+            declaration_type: DeclarationType::Let,
+            place: Expression::Identifier(Identifier {
+                name: input.variable.name,
+                span: input.variable.span,
+                id: {
+                    // Create a new node ID for the loop variable.
+                    let id = self.node_builder.next_id();
+                    // Set the type of the node ID.
+                    self.type_table.insert(id, element_type.clone());
+                    id
+                },
+            }),
+            type_: element_type.clone(),
+            value: access,
+            span: input.variable.span,
+            id: self.node_builder.next_id(),
+        };
+
+        // Reconstruct the definition, followed by the statements in the loop body.
+        let mut statements = vec![self.reconstruct_definition(definition).0];
+        statements.extend(input.block.statements.clone().into_iter().filter_map(|s| {
+            let (reconstructed_statement, additional_output) = self.reconstruct_statement(s);
+            if additional_output {
+                None // Exclude this statement from the block since it is a constant variable definition
+            } else {
+                Some(reconstructed_statement)
+            }
+        }));
+
+        let block = Statement::Block(Block { statements, span: input.block.span, id: input.block.id });
+
+        self.is_unrolling = prior_is_unrolling;
+
+        // Exit the scope.
+        self.exit_scope(previous_scope_index);
+
+        block
+    }
+
     /// A helper function to unroll a single iteration an IterationStatement.
     fn unroll_single_iteration<I: LoopBound>(&mut self, input: &IterationStatement, iteration_count: I) -> Statement {
         // Create a scope for a single unrolling of the `IterationStatement`.
@@ -259,4 +449,270 @@ impl<'a> Unroller<'a> {
 
         block
     }
+
+    /// Constructs a reference to the variable `array`, bound to the iterated array of an ArrayIterationStatement.
+    fn array_identifier(&self, array: Symbol, iterated: &Expression, array_type: &ArrayType) -> Identifier {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, Type::Array(array_type.clone()));
+        Identifier { name: array, span: iterated.span(), id }
+    }
+
+    /// Allocates the returned flag of a function body that is about to be unrolled, if a loop in the body may return.
+    /// The flag must be defined with `exit_function_body` once the body is unrolled.
+    pub(crate) fn enter_function_body(&mut self, block: &Block) {
+        self.returned_flag =
+            block.statements.iter().any(may_return_from_loop).then(|| self.assigner.unique_symbol("$returned", "$"));
+    }
+
+    /// Defines the returned flag, if any, at the start of a function body that has been unrolled.
+    pub(crate) fn exit_function_body(&mut self, mut block: Block) -> Block {
+        if let Some(flag) = self.returned_flag.take() {
+            block.statements.insert(0, self.flag_definition(flag));
+        }
+        block
+    }
+
+    /// Reconstructs the statements of a block, removing the constant definitions.
+    /// A `return` statement inside a loop only exits the loops, so outside of loops, the statements that follow a loop
+    /// that may return are only executed if the returned flag is not set.
+    pub(crate) fn reconstruct_statements(&mut self, statements: Vec<Statement>) -> Vec<Statement> {
+        let mut reconstructed = Vec::with_capacity(statements.len());
+        let mut statements = statements.into_iter();
+        while let Some(statement) = statements.next() {
+            let returned_flag = match self.loop_exit_flags.is_empty() && may_return_from_loop(&statement) {
+                true => self.returned_flag,
+                false => None,
+            };
+
+            let (statement, is_constant_definition) = self.reconstruct_statement(statement);
+            if !is_constant_definition {
+                reconstructed.push(statement);
+            }
+
+            if let Some(flag) = returned_flag {
+                let rest = self.reconstruct_statements(statements.collect());
+                if !rest.is_empty() {
+                    reconstructed.push(self.unless_exited([flag], rest));
+                }
+                break;
+            }
+        }
+
+        reconstructed
+    }
+
+    /// Lowers the `break`, `continue`, and `return` statements in the body of a loop that is about to be unrolled.
+    /// The exit flags of the loop are pushed onto `loop_exit_flags`, and must be popped with `exit_loop` once it is unrolled.
+    fn enter_loop(&mut self, block: Block) -> Block {
+        let flags = LoopExitFlags {
+            break_flag: block.statements.iter().any(may_break).then(|| self.assigner.unique_symbol("$break", "$")),
+            continue_flag: block
+                .statements
+                .iter()
+                .any(may_continue)
+                .then(|| self.assigner.unique_symbol("$continue", "$")),
+        };
+        self.loop_exit_flags.push(flags);
+
+        // The continue flag is defined at the start of the body, so that it is reset in each iteration.
+        let mut statements: Vec<_> = flags.continue_flag.map(|flag| self.flag_definition(flag)).into_iter().collect();
+        statements.extend(self.lower_loop_exits(block.statements));
+
+        Block { statements, ..block }
+    }
+
+    /// Pops the exit flags of a loop that has been unrolled into the given iterations.
+    /// If the loop can be exited early, the break flag is defined before the iterations, and each iteration after
+    /// the first is only executed if the break flag is not set.
+    fn exit_loop(&mut self, iterations: Vec<Statement>) -> Vec<Statement> {
+        let flags = self.loop_exit_flags.pop().expect("`exit_loop` is only called after `enter_loop`.");
+
+        match flags.break_flag {
+            None => iterations,
+            Some(flag) => core::iter::once(self.flag_definition(flag))
+                .chain(iterations.into_iter().enumerate().map(|(i, iteration)| match i {
+                    0 => iteration,
+                    _ => self.unless_exited([flag], vec![iteration]),
+                }))
+                .collect(),
+        }
+    }
+
+    /// Lowers the `break`, `continue`, and `return` statements in the body of the innermost loop being unrolled.
+    /// Each of these statements sets the corresponding exit flag, and the statements that follow a statement that
+    /// may exit the loop are only executed if no exit flag is set.
+    /// For example, `if c { break; } x = x + 1u8;` is lowered to `if c { $break$0 = true; } if !$break$0 { x = x + 1u8; }`.
+    /// Note that the loops nested in the body are lowered when they are unrolled.
+    fn lower_loop_exits(&self, statements: Vec<Statement>) -> Vec<Statement> {
+        let flags = *self.loop_exit_flags.last().expect("Loop exits are only lowered inside of a loop.");
+
+        let mut lowered = Vec::with_capacity(statements.len());
+        let mut statements = statements.into_iter();
+        while let Some(statement) = statements.next() {
+            let may_exit = may_break(&statement) || may_continue(&statement);
+
+            match statement {
+                Statement::Break(input) => lowered
+                    .push(self.set_flag(flags.break_flag.expect("The loop body contains a `break`."), input.span)),
+                Statement::Continue(input) => lowered.push(
+                    self.set_flag(flags.continue_flag.expect("The loop body contains a `continue`."), input.span),
+                ),
+                Statement::Return(input) => {
+                    // A `return` statement exits every loop that encloses it, and skips the rest of the function body.
+                    let break_flags = self.loop_exit_flags.iter().filter_map(|flags| flags.break_flag);
+                    lowered.extend(break_flags.chain(self.returned_flag).map(|flag| self.set_flag(flag, input.span)));
+                    lowered.push(Statement::Return(input));
+                }
+                statement => lowered.push(self.lower_nested_loop_exits(statement)),
+            }
+
+            // Type checking guarantees that no statements follow a `break`, `continue`, or `return` statement.
+            if may_exit {
+                let rest = self.lower_loop_exits(statements.collect());
+                if !rest.is_empty() {
+                    lowered.push(self.unless_exited(flags.iter(), rest));
+                }
+                break;
+            }
+        }
+
+        lowered
+    }
+
+    /// Lowers the loop exits in the blocks of a block, conditional, or `match` statement.
+    fn lower_nested_loop_exits(&self, statement: Statement) -> Statement {
+        match statement {
+            Statement::Block(block) => {
+                Statement::Block(Block { statements: self.lower_loop_exits(block.statements), ..block })
+            }
+            Statement::Conditional(conditional) => Statement::Conditional(ConditionalStatement {
+                then: Block { statements: self.lower_loop_exits(conditional.then.statements), ..conditional.then },
+                otherwise: conditional.otherwise.map(|otherwise| Box::new(self.lower_nested_loop_exits(*otherwise))),
+                ..conditional
+            }),
+            Statement::Match(match_) => Statement::Match(MatchStatement {
+                arms: match_
+                    .arms
+                    .into_iter()
+                    .map(|arm| MatchArm {
+                        body: Block { statements: self.lower_loop_exits(arm.body.statements), ..arm.body },
+                        ..arm
+                    })
+                    .collect(),
+                ..match_
+            }),
+            statement => statement,
+        }
+    }
+
+    /// Constructs the conditional statement `if !(flag_0 || ... || flag_n) { statements }`.
+    fn unless_exited(&self, flags: impl IntoIterator<Item = Symbol>, statements: Vec<Statement>) -> Statement {
+        let exited = flags
+            .into_iter()
+            .map(|flag| self.flag(flag))
+            .reduce(|left, right| {
+                Expression::Binary(BinaryExpression {
+                    left: Box::new(left),
+                    right: Box::new(right),
+                    op: BinaryOperation::Or,
+                    span: Default::default(),
+                    id: self.boolean_id(),
+                })
+            })
+            .expect("A loop that may be exited early has at least one exit flag.");
+
+        Statement::Conditional(ConditionalStatement {
+            condition: Expression::Unary(UnaryExpression {
+                receiver: Box::new(exited),
+                op: UnaryOperation::Not,
+                span: Default::default(),
+                id: self.boolean_id(),
+            }),
+            then: Block { statements, span: Default::default(), id: self.node_builder.next_id() },
+            otherwise: None,
+            span: Default::default(),
+            id: self.node_builder.next_id(),
+        })
+    }
+
+    /// Constructs the definition `let flag: bool = false;`.
+    fn flag_definition(&self, flag: Symbol) -> Statement {
+        Statement::Definition(DefinitionStatement {
+            declaration_type: DeclarationType::Let,
+            place: self.flag(flag),
+            type_: Type::Boolean,
+            value: Expression::Literal(Literal::Boolean(false, Default::default(), self.boolean_id())),
+            span: Default::default(),
+            id: self.node_builder.next_id(),
+        })
+    }
+
+    /// Constructs the assignment `flag = true;`.
+    fn set_flag(&self, flag: Symbol, span: Span) -> Statement {
+        let place = Identifier { name: flag, span, id: self.boolean_id() };
+        let value = Expression::Literal(Literal::Boolean(true, Default::default(), self.boolean_id()));
+        self.assigner.simple_assign_statement(place, value, self.node_builder.next_id())
+    }
+
+    /// Constructs a reference to an exit flag.
+    fn flag(&self, flag: Symbol) -> Expression {
+        Expression::Identifier(Identifier { name: flag, span: Default::default(), id: self.boolean_id() })
+    }
+
+    /// Creates a new node ID for an expression of type `bool`.
+    fn boolean_id(&self) -> NodeID {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, Type::Boolean);
+        id
+    }
+}
+
+/// Returns `true` if the statement, or a statement nested in one of its blocks, satisfies the predicate.
+/// The bodies of nested loops are only searched if `into_loops` is set.
+fn any_statement(statement: &Statement, into_loops: bool, predicate: fn(&Statement) -> bool) -> bool {
+    let any =
+        |statements: &[Statement]| statements.iter().any(|statement| any_statement(statement, into_loops, predicate));
+    predicate(statement)
+        || match statement {
+            Statement::Block(block) => any(&block.statements),
+            Statement::Conditional(conditional) => {
+                any(&conditional.then.statements)
+                    || conditional
+                        .otherwise
+                        .as_deref()
+                        .map_or(false, |otherwise| any_statement(otherwise, into_loops, predicate))
+            }
+            Statement::Match(match_) => match_.arms.iter().any(|arm| any(&arm.body.statements)),
+            Statement::ArrayIteration(iteration) if into_loops => any(&iteration.block.statements),
+            Statement::Iteration(iteration) if into_loops => any(&iteration.block.statements),
+            _ => false,
+        }
+}
+
+/// Returns `true` if the statement may exit the enclosing loop through a `break` or `return` statement.
+/// Note that the `break` statements of a nested loop only exit that loop, but its `return` statements exit every loop.
+fn may_break(statement: &Statement) -> bool {
+    any_statement(statement, false, |statement| match statement {
+        Statement::Break(_) | Statement::Return(_) => true,
+        Statement::ArrayIteration(iteration) => iteration.block.statements.iter().any(may_return),
+        Statement::Iteration(iteration) => iteration.block.statements.iter().any(may_return),
+        _ => false,
+    })
+}
+
+/// Returns `true` if the statement may skip the rest of the current iteration of the enclosing loop through a `continue` statement.
+fn may_continue(statement: &Statement) -> bool {
+    any_statement(statement, false, |statement| matches!(statement, Statement::Continue(_)))
+}
+
+/// Returns `true` if the statement contains a `return` statement.
+fn may_return(statement: &Statement) -> bool {
+    any_statement(statement, true, |statement| matches!(statement, Statement::Return(_)))
+}
+
+/// Returns `true` if the statement contains a loop with a `return` statement.
+fn may_return_from_loop(statement: &Statement) -> bool {
+    any_statement(statement, false, |statement| {
+        matches!(statement, Statement::ArrayIteration(_) | Statement::Iteration(_)) && may_return(statement)
+    })
 }
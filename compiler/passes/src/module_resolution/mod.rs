@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+//! The module resolution pass merges the modules declared by the program into its program scope.
+//! A module `mod math;` is defined in the file `math.leo`, in the directory of the file of the program.
+//! It holds items of the program scope, such as consts, structs and functions, without a `program` declaration around them.
+//! A module may declare modules in turn, which are looked up in the same directory.
+//!
+//! Consider a package with the following files.
+//! ```leo
+//! // src/main.leo
+//! program main.aleo {
+//!     mod math;
+//!
+//!     transition main(a: u32) -> u32 {
+//!         return square(a);
+//!     }
+//! }
+//!
+//! // src/math.leo
+//! inline square(a: u32) -> u32 {
+//!     return a * a;
+//! }
+//! ```
+//!
+//! After the module resolution pass, the program scope of `main.aleo` defines both `square` and `main`.
+//! The spans of the items of a module point into the file of the module.
+
+pub mod module_resolver;
+pub use module_resolver::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, NodeBuilder};
+use leo_errors::{emitter::Handler, Result};
+
+use std::path::Path;
+
+impl<'a> Pass for ModuleResolver<'a> {
+    type Input = (Ast, &'a Handler, &'a NodeBuilder, &'a Path);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, handler, node_builder, directory): Self::Input) -> Self::Output {
+        let resolver = ModuleResolver::new(handler, node_builder, directory);
+        Ok(Ast::new(resolver.resolve(ast.into_repr())?))
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use leo_ast::{Module, NodeBuilder, Program, ProgramId, ProgramScope};
+use leo_errors::{emitter::Handler, CompilerError, Result};
+use leo_span::{source_map::FileName, symbol::with_session_globals};
+
+use indexmap::IndexSet;
+use std::{fs, path::Path};
+
+pub struct ModuleResolver<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// A counter used to generate unique node IDs.
+    node_builder: &'a NodeBuilder,
+    /// The directory in which the files of the modules are looked up.
+    directory: &'a Path,
+}
+
+impl<'a> ModuleResolver<'a> {
+    /// Returns a new module resolver, which looks up modules in the given directory.
+    pub fn new(handler: &'a Handler, node_builder: &'a NodeBuilder, directory: &'a Path) -> Self {
+        Self { handler, node_builder, directory }
+    }
+
+    /// Merges the modules declared by the program scopes of the program, transitively, into the program scopes.
+    pub fn resolve(&self, mut program: Program) -> Result<Program> {
+        for program_scope in program.program_scopes.values_mut() {
+            self.resolve_program_scope(program_scope)?;
+        }
+        Ok(program)
+    }
+
+    fn resolve_program_scope(&self, program_scope: &mut ProgramScope) -> Result<()> {
+        let mut declared = IndexSet::new();
+
+        // Note that merging a module appends the modules it declares to those of the program scope.
+        let mut index = 0;
+        while let Some((name, module)) = program_scope.modules.get(index).cloned() {
+            index += 1;
+
+            if !declared.insert(name) {
+                return Err(CompilerError::module_declared_twice(name, module.span).into());
+            }
+
+            let module_scope = self.load(program_scope.program_id, &module)?;
+            program_scope.modules.extend(module_scope.modules);
+            program_scope.consts.extend(module_scope.consts);
+            program_scope.type_aliases.extend(module_scope.type_aliases);
+            program_scope.structs.extend(module_scope.structs);
+            program_scope.enums.extend(module_scope.enums);
+            program_scope.mappings.extend(module_scope.mappings);
+            program_scope.functions.extend(module_scope.functions);
+        }
+        Ok(())
+    }
+
+    /// Parses the items of `module`, declared in the program scope `program_id`.
+    fn load(&self, program_id: ProgramId, module: &Module) -> Result<ProgramScope> {
+        let file_path = self.directory.join(format!("{}.leo", module.name()));
+
+        // Throw an error if the module file doesn't exist.
+        if !file_path.exists() {
+            return Err(CompilerError::module_not_found(file_path.display(), module.span).into());
+        }
+
+        // Register the source of the module in the source map, so that the spans of its items point into its file.
+        let source = fs::read_to_string(&file_path).map_err(|e| CompilerError::file_read_error(&file_path, e))?;
+        let source_file = with_session_globals(|s| s.source_map.new_source(&source, FileName::Real(file_path)));
+        leo_parser::parse_module(self.handler, self.node_builder, &source_file.src, source_file.start_pos, program_id)
+    }
+}
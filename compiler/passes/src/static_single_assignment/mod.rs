@@ -17,6 +17,7 @@
 //! The Static Single Assignment pass traverses the AST and converts it into SSA form.
 //! See https://en.wikipedia.org/wiki/Static_single-assignment_form for more information.
 //! The pass also replaces `DefinitionStatement`s with `AssignmentStatement`s.
+//! The variables written in the arms of a `match` statement are merged by a `match` expression over the same patterns.
 //! The pass also simplifies complex expressions into a sequence of `AssignStatement`s. For example, `(a + b) * c` is rewritten into `$var$1 = a + b; $var$2 = $var$1 * c`.
 //!
 //! Consider the following Leo code.
@@ -28,7 +28,10 @@ use leo_ast::{
     ExpressionConsumer,
     Identifier,
     Literal,
+    MatchArm,
+    MatchExpression,
     MemberAccess,
+    RepeatExpression,
     Statement,
     Struct,
     StructExpression,
@@ -103,12 +106,22 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
                 )
             }
             AccessExpression::Array(input) => {
-                let (array, statements) = self.consume_expression(*input.array);
+                let (array, mut statements) = self.consume_expression(*input.array);
+
+                // Constant indices are kept as literals, since code generation requires them in place.
+                let index = match *input.index {
+                    Expression::Literal(literal) => Expression::Literal(literal),
+                    index => {
+                        let (index, stmts) = self.consume_expression(index);
+                        statements.extend(stmts);
+                        index
+                    }
+                };
 
                 (
                     AccessExpression::Array(ArrayAccess {
                         array: Box::new(array),
-                        index: input.index,
+                        index: Box::new(index),
                         span: input.span,
                         id: input.id,
                     }),
@@ -317,6 +330,52 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
         (Expression::Identifier(place), vec![statement])
     }
 
+    /// Consumes a `match` expression, accumulating any statements that are generated.
+    fn consume_match(&mut self, input: MatchExpression) -> Self::Output {
+        // Reconstruct the matched expression.
+        let (expression, mut statements) = self.consume_expression(*input.expression);
+
+        // Reconstruct the arms, accumulating any statements produced.
+        let arms = input
+            .arms
+            .into_iter()
+            .map(|arm| {
+                let pattern = self.consume_match_pattern(arm.pattern, &mut statements);
+                let (body, mut stmts) = self.consume_expression(arm.body);
+                statements.append(&mut stmts);
+                MatchArm { pattern, body, span: arm.span }
+            })
+            .collect();
+
+        // Construct and accumulate a unique assignment statement storing the result of the match expression.
+        let (place, statement) = self.unique_simple_assign_statement(Expression::Match(MatchExpression {
+            expression: Box::new(expression),
+            arms,
+            span: input.span,
+            id: input.id,
+        }));
+        statements.push(statement);
+
+        (Expression::Identifier(place), statements)
+    }
+
+    /// Consumes an array repeat expression, accumulating any statements that are generated.
+    /// The repeated element is evaluated once, e.g. `[a + b; 3]` produces `$var$0 = a + b` and `$var$1 = [$var$0, $var$0, $var$0]`.
+    fn consume_repeat(&mut self, input: RepeatExpression) -> Self::Output {
+        // Process the repeated element, accumulating any statements produced.
+        let (element, mut statements) = self.consume_expression(*input.expr);
+
+        // Construct and accumulate a new assignment statement for the array expression.
+        let (place, statement) = self.unique_simple_assign_statement(Expression::Array(ArrayExpression {
+            elements: vec![element; input.count.value()],
+            span: input.span,
+            id: input.id,
+        }));
+        statements.push(statement);
+
+        (Expression::Identifier(place), statements)
+    }
+
     /// Consumes a ternary expression, accumulating any statements that are generated.
     fn consume_ternary(&mut self, input: TernaryExpression) -> Self::Output {
         // Reconstruct the condition of the ternary expression.
@@ -132,7 +132,10 @@ impl ProgramScopeConsumer for StaticSingleAssigner<'_> {
     fn consume_program_scope(&mut self, input: ProgramScope) -> Self::Output {
         ProgramScope {
             program_id: input.program_id,
+            modules: input.modules,
+            type_aliases: input.type_aliases,
             structs: input.structs.into_iter().map(|(i, s)| (i, self.consume_struct(s))).collect(),
+            enums: input.enums,
             mappings: input.mappings,
             functions: input.functions.into_iter().map(|(i, f)| (i, self.consume_function(f))).collect(),
             consts: input.consts,
@@ -18,21 +18,27 @@ use crate::{RenameTable, StaticSingleAssigner};
 
 use leo_ast::{
     AccessExpression,
+    ArrayIterationStatement,
     AssertStatement,
     AssertVariant,
     AssignStatement,
     AssociatedFunction,
     Block,
+    BreakStatement,
     CallExpression,
     ConditionalStatement,
     ConsoleStatement,
     ConstDeclaration,
+    ContinueStatement,
     DefinitionStatement,
     Expression,
     ExpressionConsumer,
     ExpressionStatement,
     Identifier,
     IterationStatement,
+    MatchArm,
+    MatchExpression,
+    MatchStatement,
     Node,
     ReturnStatement,
     Statement,
@@ -49,6 +55,10 @@ use itertools::Itertools;
 impl StatementConsumer for StaticSingleAssigner<'_> {
     type Output = Vec<Statement>;
 
+    fn consume_array_iteration(&mut self, _input: ArrayIterationStatement) -> Self::Output {
+        unreachable!("`ArrayIterationStatement`s should not be in the AST at this phase of compilation.");
+    }
+
     /// Consumes the expressions in an `AssertStatement`, returning the list of simplified statements.
     fn consume_assert(&mut self, input: AssertStatement) -> Self::Output {
         let (variant, mut statements) = match input.variant {
@@ -89,10 +99,23 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
         // First consume the right-hand-side of the assignment.
         let (value, mut statements) = self.consume_expression(assign.value);
 
+        // If the place is a member or array access, rewrite the assignment as an assignment to the root variable.
+        // For example, `a.b = c` becomes `a = A { b: c, ... }`.
+        let (place, value) = match assign.place {
+            Expression::Identifier(identifier) => (identifier, value),
+            place => {
+                let (place, value) = self.reconstruct_place(place, value);
+                // Consume the reconstructed value, accumulating any statements produced.
+                let (value, stmts) = self.consume_expression(value);
+                statements.extend(stmts);
+                (place, value)
+            }
+        };
+
         // Then assign a new unique name to the left-hand-side of the assignment.
         // Note that this order is necessary to ensure that the right-hand-side uses the correct name when consuming a complex assignment.
         self.is_lhs = true;
-        let place = match self.consume_expression(assign.place).0 {
+        let place = match self.consume_identifier(place).0 {
             Expression::Identifier(identifier) => identifier,
             _ => panic!("Type checking guarantees that the left-hand-side of an assignment is an identifier."),
         };
@@ -115,6 +138,10 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
     ///   - `if x > 0 { x = x + 1 }` becomes `let $cond$0 = x > 0; if $cond$0 { x = x + 1; }`
     ///   - `if true { x = x + 1 }` remains the same.
     ///   - `if b { x = x + 1 }` remains the same.
+    fn consume_break(&mut self, _input: BreakStatement) -> Self::Output {
+        unreachable!("`BreakStatement`s should not be in the AST at this phase of compilation.");
+    }
+
     fn consume_conditional(&mut self, conditional: ConditionalStatement) -> Self::Output {
         // Simplify the condition and add it into the rename table.
         let (condition, mut statements) = self.consume_expression(conditional.condition);
@@ -241,6 +268,10 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
     }
 
     /// Consumes the `DefinitionStatement` into an `AssignStatement`, renaming the left-hand-side as appropriate.
+    fn consume_continue(&mut self, _input: ContinueStatement) -> Self::Output {
+        unreachable!("`ContinueStatement`s should not be in the AST at this phase of compilation.");
+    }
+
     fn consume_definition(&mut self, definition: DefinitionStatement) -> Self::Output {
         // First consume the right-hand-side of the definition.
         let (value, mut statements) = self.consume_expression(definition.value);
@@ -381,6 +412,103 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
         unreachable!("`IterationStatement`s should not be in the AST at this phase of compilation.");
     }
 
+    /// Consumes a `match` statement, renaming the variables written in each of its arms.
+    /// The variables written in the arms are merged after the statement by a phi function over the same patterns,
+    /// e.g. `x$3 = match $var$0 { 0u8 => x$1, _ => x$2 };`, which the flattener lowers like any other `match` expression.
+    fn consume_match_statement(&mut self, input: MatchStatement) -> Self::Output {
+        // Simplify the matched expression.
+        let (expression, mut statements) = self.consume_expression(input.expression);
+
+        // Consume each arm, instantiating a `RenameTable` for its block.
+        let mut arms = Vec::with_capacity(input.arms.len());
+        let mut tables = Vec::with_capacity(input.arms.len());
+        for arm in input.arms {
+            let pattern = self.consume_match_pattern(arm.pattern, &mut statements);
+            self.push();
+            let body = Block { span: arm.body.span, id: arm.body.id, statements: self.consume_block(arm.body) };
+            tables.push(self.pop());
+            arms.push(MatchArm { pattern, body, span: arm.span });
+        }
+        let patterns = arms.iter().map(|arm| arm.pattern.clone()).collect::<Vec<_>>();
+
+        // Add reconstructed match statement to the list of produced statements.
+        statements.push(Statement::Match(MatchStatement {
+            expression: expression.clone(),
+            arms,
+            span: input.span,
+            id: input.id,
+        }));
+
+        // Compute the write set for the variables written in any of the arms.
+        let write_set: IndexSet<Symbol> = tables.iter().flat_map(|table| table.local_names()).copied().collect();
+
+        // For each variable in the write set, instantiate and add a phi function to the list of produced statements.
+        for symbol in write_set {
+            // Note that phi functions only need to be instantiated if the variable exists before the `MatchStatement`.
+            if self.rename_table.lookup(symbol).is_some() {
+                // Create the arguments for the phi function, which are the names of the variable at the end of each arm.
+                let arguments = tables
+                    .iter()
+                    .map(|table| {
+                        let name = *table
+                            .lookup(symbol)
+                            .unwrap_or_else(|| panic!("Symbol {symbol} should exist in the program."));
+                        let id = *table
+                            .lookup_id(&name)
+                            .unwrap_or_else(|| panic!("Symbol {name} should exist in the rename table."));
+                        Expression::Identifier(Identifier { name, span: Default::default(), id })
+                    })
+                    .collect::<Vec<_>>();
+
+                // Create a new name for the variable written to in the `MatchStatement`.
+                let new_name = self.assigner.unique_symbol(symbol, "$");
+
+                // Create a new node ID for the the phi function.
+                let id = self.node_builder.next_id();
+                // Update the type of the node ID.
+                let type_ = match self.type_table.get(&arguments[0].id()) {
+                    Some(type_) => type_,
+                    None => unreachable!("Type checking guarantees that all expressions have a type."),
+                };
+                self.type_table.insert(id, type_);
+
+                // Construct a match expression for the phi function.
+                let (value, stmts) = self.consume_match(MatchExpression {
+                    expression: Box::new(expression.clone()),
+                    arms: patterns
+                        .iter()
+                        .zip_eq(arguments)
+                        .map(|(pattern, body)| MatchArm { pattern: pattern.clone(), body, span: Default::default() })
+                        .collect(),
+                    span: Default::default(),
+                    id,
+                });
+
+                statements.extend(stmts);
+
+                // Get the ID for the new name of the variable.
+                let id = match self.rename_table.lookup_id(&symbol) {
+                    Some(id) => *id,
+                    None => {
+                        unreachable!("The ID for the symbol `{}` should already exist in the rename table.", symbol)
+                    }
+                };
+
+                // Update the `RenameTable` with the new name of the variable.
+                self.rename_table.update(symbol, new_name, id);
+
+                // Create a new `AssignStatement` for the phi function.
+                let identifier = Identifier { name: new_name, span: Default::default(), id };
+                let assignment = self.simple_assign_statement(identifier, value);
+
+                // Store the generated phi function.
+                statements.push(assignment);
+            }
+        }
+
+        statements
+    }
+
     /// Reconstructs the expression associated with the return statement, returning a simplified `ReturnStatement`.
     /// Note that type checking guarantees that there is at most one `ReturnStatement` in a block.
     fn consume_return(&mut self, input: ReturnStatement) -> Self::Output {
@@ -16,7 +16,24 @@
 
 use crate::{Assigner, RenameTable, SymbolTable, TypeTable};
 
-use leo_ast::{Expression, Identifier, Node, NodeBuilder, Statement};
+use leo_ast::{
+    AccessExpression,
+    ArrayAccess,
+    ArrayExpression,
+    Expression,
+    ExpressionConsumer,
+    Identifier,
+    IntegerType,
+    Literal,
+    MatchPattern,
+    MemberAccess,
+    Node,
+    NodeBuilder,
+    Statement,
+    StructExpression,
+    StructVariableInitializer,
+    Type,
+};
 
 pub struct StaticSingleAssigner<'a> {
     /// A counter used to generate unique node IDs.
@@ -84,4 +101,162 @@ impl<'a> StaticSingleAssigner<'a> {
 
         (place, statement)
     }
+
+    /// Consumes the expressions in a `match` pattern, accumulating any statements that are generated.
+    /// Literals are kept in place, since the matched value is compared against them directly.
+    pub(crate) fn consume_match_pattern(
+        &mut self,
+        pattern: MatchPattern,
+        statements: &mut Vec<Statement>,
+    ) -> MatchPattern {
+        match pattern {
+            MatchPattern::Constant(Expression::Literal(literal)) => {
+                MatchPattern::Constant(Expression::Literal(literal))
+            }
+            MatchPattern::Constant(expression) => {
+                let (expression, mut stmts) = self.consume_expression(expression);
+                statements.append(&mut stmts);
+                MatchPattern::Constant(expression)
+            }
+            MatchPattern::Or(patterns, span) => MatchPattern::Or(
+                patterns.into_iter().map(|pattern| self.consume_match_pattern(pattern, statements)).collect(),
+                span,
+            ),
+            pattern => pattern,
+        }
+    }
+
+    /// Lowers an assignment to a member or array element into an assignment to the variable at the root of the place.
+    /// Returns the root variable and the expression for its new value.
+    /// For example, given the place `a.b[1u32]` and the value `v`, where `a.b` is an array of length 2,
+    /// the root `a` is returned along with `A { b: [a.b[0u32], v], c: a.c }`.
+    pub(crate) fn reconstruct_place(&mut self, place: Expression, value: Expression) -> (Identifier, Expression) {
+        match place {
+            Expression::Identifier(identifier) => (identifier, value),
+            Expression::Access(AccessExpression::Member(access)) => {
+                // Lookup the struct definition.
+                // Note that type checking guarantees that the inner expression is a struct and that its definition exists.
+                let struct_name = match self.type_table.get(&access.inner.id()) {
+                    Some(Type::Identifier(identifier)) => identifier,
+                    _ => unreachable!(
+                        "Type checking guarantees that the inner expression of a member access is a struct."
+                    ),
+                };
+                let struct_definition = self.symbol_table.lookup_struct(struct_name.name).unwrap().clone();
+
+                // Rebuild the struct, replacing the accessed member with the new value.
+                let mut value = Some(value);
+                let members = struct_definition
+                    .members
+                    .iter()
+                    .map(|member| StructVariableInitializer {
+                        identifier: member.identifier,
+                        expression: Some(match member.identifier.name == access.name.name {
+                            true => value.take().unwrap(),
+                            false => Expression::Access(AccessExpression::Member(MemberAccess {
+                                inner: access.inner.clone(),
+                                name: member.identifier,
+                                span: Default::default(),
+                                id: {
+                                    // Create a new node ID for the access expression.
+                                    let id = self.node_builder.next_id();
+                                    // Set the type of the node ID.
+                                    self.type_table.insert(id, member.type_.clone());
+                                    id
+                                },
+                            })),
+                        }),
+                        span: Default::default(),
+                        id: self.node_builder.next_id(),
+                    })
+                    .collect();
+
+                let expression = Expression::Struct(StructExpression {
+                    name: struct_name,
+                    members,
+                    span: Default::default(),
+                    id: {
+                        // Create a new node ID for the struct expression.
+                        let id = self.node_builder.next_id();
+                        // Set the type of the node ID.
+                        self.type_table.insert(id, Type::Identifier(struct_name));
+                        id
+                    },
+                });
+
+                self.reconstruct_place(*access.inner, expression)
+            }
+            Expression::Access(AccessExpression::Array(access)) => {
+                // Get the type of the array.
+                let array_type = match self.type_table.get(&access.array.id()) {
+                    Some(Type::Array(array_type)) => array_type,
+                    _ => unreachable!(
+                        "Type checking guarantees that the inner expression of an array access is an array."
+                    ),
+                };
+
+                // Get the index of the accessed element.
+                let index = match access.index.as_ref() {
+                    Expression::Literal(Literal::Integer(_, string, _, _)) => {
+                        string.replace('_', "").parse::<usize>().ok()
+                    }
+                    _ => None,
+                }
+                .filter(|index| *index < array_type.length())
+                .unwrap_or_else(|| {
+                    unreachable!(
+                        "Constant folding guarantees that the indices of array writes are in-bounds integer literals."
+                    )
+                });
+
+                // Rebuild the array, replacing the accessed element with the new value.
+                let mut value = Some(value);
+                let elements = (0..array_type.length())
+                    .map(|i| match i == index {
+                        true => value.take().unwrap(),
+                        false => Expression::Access(AccessExpression::Array(ArrayAccess {
+                            array: access.array.clone(),
+                            index: Box::new(Expression::Literal(Literal::Integer(
+                                IntegerType::U32,
+                                i.to_string(),
+                                Default::default(),
+                                {
+                                    // Create a new node ID for the literal.
+                                    let id = self.node_builder.next_id();
+                                    // Set the type of the node ID.
+                                    self.type_table.insert(id, Type::Integer(IntegerType::U32));
+                                    id
+                                },
+                            ))),
+                            span: Default::default(),
+                            id: {
+                                // Create a new node ID for the access expression.
+                                let id = self.node_builder.next_id();
+                                // Set the type of the node ID.
+                                self.type_table.insert(id, array_type.element_type().clone());
+                                id
+                            },
+                        })),
+                    })
+                    .collect();
+
+                let expression = Expression::Array(ArrayExpression {
+                    elements,
+                    span: Default::default(),
+                    id: {
+                        // Create a new node ID for the array expression.
+                        let id = self.node_builder.next_id();
+                        // Set the type of the node ID.
+                        self.type_table.insert(id, Type::Array(array_type));
+                        id
+                    },
+                });
+
+                self.reconstruct_place(*access.array, expression)
+            }
+            _ => unreachable!(
+                "Type checking guarantees that the place of an assignment is an identifier, member access, or array access."
+            ),
+        }
+    }
 }
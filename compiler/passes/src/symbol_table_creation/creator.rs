@@ -20,7 +20,7 @@ use leo_errors::emitter::Handler;
 use crate::{SymbolTable, VariableSymbol, VariableType};
 
 /// A compiler pass during which the `SymbolTable` is created.
-/// Note that this pass only creates the initial entries for functions, structs, and records.
+/// Note that this pass only creates the initial entries for functions, structs, records, enums, and type aliases.
 /// The table is populated further during the type checking pass.
 pub struct SymbolTableCreator<'a> {
     /// The `SymbolTable` constructed by this compiler pass.
@@ -47,12 +47,24 @@ impl<'a> ProgramVisitor<'a> for SymbolTableCreator<'a> {
         self.visit_program(input)
     }
 
+    fn visit_type_alias(&mut self, input: &'a TypeAlias) {
+        if let Err(err) = self.symbol_table.insert_type_alias(input.name(), input) {
+            self.handler.emit_err(err);
+        }
+    }
+
     fn visit_struct(&mut self, input: &'a Struct) {
         if let Err(err) = self.symbol_table.insert_struct(input.name(), input) {
             self.handler.emit_err(err);
         }
     }
 
+    fn visit_enum(&mut self, input: &'a Enum) {
+        if let Err(err) = self.symbol_table.insert_enum(input.name(), input) {
+            self.handler.emit_err(err);
+        }
+    }
+
     fn visit_mapping(&mut self, input: &'a Mapping) {
         // Add the variable associated with the mapping to the symbol table.
         if let Err(err) = self.symbol_table.insert_variable(input.identifier.name, VariableSymbol {
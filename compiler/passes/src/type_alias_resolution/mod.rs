@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+//! The type alias resolution pass replaces each use of a type alias with the type it stands for.
+//! Aliases are structural, so after this pass the program no longer refers to them:
+//! their declarations are erased from the program scope, while the resolved aliases are kept in the symbol table.
+//! The pass is run directly before type checking, so that the type checker only sees resolved types,
+//! including in struct members, mapping keys and values, and function and finalize signatures.
+//! An alias may refer to other aliases, as long as no alias refers to itself, directly or indirectly.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! type Row = [u8; 3];
+//! type Board = [Row; 3];
+//!
+//! mapping boards: address => Board;
+//!
+//! transition first_row(board: Board) -> Row {
+//!     let row: Row = board[0u32];
+//!     return row;
+//! }
+//! ```
+//!
+//! The type alias resolution pass produces the following code.
+//! ```leo
+//! mapping boards: address => [[u8; 3]; 3];
+//!
+//! transition first_row(board: [[u8; 3]; 3]) -> [u8; 3] {
+//!     let row: [u8; 3] = board[0u32];
+//!     return row;
+//! }
+//! ```
+
+mod resolve_program;
+
+mod resolve_statement;
+
+pub mod type_alias_resolver;
+pub use type_alias_resolver::*;
+
+use crate::{Pass, SymbolTable, SymbolTableCreator};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for TypeAliasResolver<'a> {
+    type Input = (Ast, &'a Handler, &'a NodeBuilder, SymbolTable);
+    type Output = Result<(Ast, SymbolTable)>;
+
+    fn do_pass((ast, handler, node_builder, st): Self::Input) -> Self::Output {
+        let mut reconstructor = TypeAliasResolver::new(handler, node_builder);
+        reconstructor.resolve_aliases(&st.type_aliases);
+        handler.last_err().map_err(|e| *e)?;
+
+        let ast = Ast::new(reconstructor.reconstruct_program(ast.into_repr()));
+
+        // Recreate the symbol table, since its entries for structs, enums, mappings, and functions refer to the aliases.
+        let mut st = SymbolTableCreator::do_pass((&ast, handler))?;
+        st.type_aliases = reconstructor.type_aliases;
+
+        Ok((ast, st))
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use crate::TypeAliasResolver;
+
+use leo_ast::{
+    Enum,
+    EnumVariant,
+    Finalize,
+    Function,
+    FunctionInput,
+    FunctionOutput,
+    Input,
+    Mapping,
+    Member,
+    Output,
+    ProgramReconstructor,
+    ProgramScope,
+    Statement,
+    StatementReconstructor,
+    Struct,
+};
+
+impl TypeAliasResolver<'_> {
+    /// Resolves the types of the function inputs.
+    fn resolve_inputs(&self, inputs: Vec<Input>) -> Vec<Input> {
+        inputs
+            .into_iter()
+            .map(|input| match input {
+                Input::Internal(input) => Input::Internal(FunctionInput { type_: self.resolve(&input.type_), ..input }),
+                Input::External(input) => Input::External(input),
+            })
+            .collect()
+    }
+
+    /// Resolves the types of the function outputs.
+    fn resolve_outputs(&self, outputs: Vec<Output>) -> Vec<Output> {
+        outputs
+            .into_iter()
+            .map(|output| match output {
+                Output::Internal(output) => {
+                    Output::Internal(FunctionOutput { type_: self.resolve(&output.type_), ..output })
+                }
+                Output::External(output) => Output::External(output),
+            })
+            .collect()
+    }
+}
+
+impl ProgramReconstructor for TypeAliasResolver<'_> {
+    /// Resolves the aliases in the program scope, and erases their declarations.
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        ProgramScope {
+            program_id: input.program_id,
+            modules: input.modules,
+            type_aliases: Vec::new(),
+            structs: input.structs.into_iter().map(|(i, c)| (i, self.reconstruct_struct(c))).collect(),
+            enums: input.enums.into_iter().map(|(i, e)| (i, self.reconstruct_enum(e))).collect(),
+            mappings: input.mappings.into_iter().map(|(id, mapping)| (id, self.reconstruct_mapping(mapping))).collect(),
+            functions: input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect(),
+            consts: input
+                .consts
+                .into_iter()
+                .map(|(i, c)| match self.reconstruct_const(c) {
+                    (Statement::Const(declaration), _) => (i, declaration),
+                    _ => unreachable!("`reconstruct_const` can only return `Statement::Const`"),
+                })
+                .collect(),
+            span: input.span,
+        }
+    }
+
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        Function {
+            annotations: input.annotations,
+            variant: input.variant,
+            identifier: input.identifier,
+            input: self.resolve_inputs(input.input),
+            output: self.resolve_outputs(input.output),
+            output_type: self.resolve(&input.output_type),
+            block: self.reconstruct_block(input.block).0,
+            finalize: input.finalize.map(|finalize| Finalize {
+                identifier: finalize.identifier,
+                input: self.resolve_inputs(finalize.input),
+                output: self.resolve_outputs(finalize.output),
+                output_type: self.resolve(&finalize.output_type),
+                block: self.reconstruct_block(finalize.block).0,
+                span: finalize.span,
+                id: finalize.id,
+            }),
+            span: input.span,
+            id: input.id,
+        }
+    }
+
+    fn reconstruct_struct(&mut self, input: Struct) -> Struct {
+        Struct {
+            members: input
+                .members
+                .into_iter()
+                .map(|member| Member { type_: self.resolve(&member.type_), ..member })
+                .collect(),
+            ..input
+        }
+    }
+
+    fn reconstruct_enum(&mut self, input: Enum) -> Enum {
+        Enum {
+            variants: input
+                .variants
+                .into_iter()
+                .map(|variant| EnumVariant {
+                    payload: variant.payload.as_ref().map(|payload| self.resolve(payload)),
+                    ..variant
+                })
+                .collect(),
+            ..input
+        }
+    }
+
+    fn reconstruct_mapping(&mut self, input: Mapping) -> Mapping {
+        Mapping { key_type: self.resolve(&input.key_type), value_type: self.resolve(&input.value_type), ..input }
+    }
+}
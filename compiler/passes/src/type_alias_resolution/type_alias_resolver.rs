@@ -0,0 +1,160 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use crate::{DiGraphError, TypeAliasGraph};
+
+use leo_ast::{
+    ArrayType,
+    Expression,
+    ExpressionReconstructor,
+    Identifier,
+    MappingType,
+    NodeBuilder,
+    NonNegativeNumber,
+    StructExpression,
+    StructVariableInitializer,
+    TupleType,
+    Type,
+    TypeAlias,
+};
+use leo_errors::{emitter::Handler, TypeCheckerError};
+use leo_span::{Span, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+
+pub struct TypeAliasResolver<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// A counter used to generate unique node IDs.
+    node_builder: &'a NodeBuilder,
+    /// The type aliases of the program, whose types no longer refer to other aliases.
+    pub(crate) type_aliases: IndexMap<Symbol, TypeAlias>,
+}
+
+impl<'a> TypeAliasResolver<'a> {
+    pub(crate) fn new(handler: &'a Handler, node_builder: &'a NodeBuilder) -> Self {
+        Self { handler, node_builder, type_aliases: IndexMap::new() }
+    }
+
+    /// Resolves the given type aliases, so that each one stands for a type that does not refer to any alias.
+    /// Emits an error if the aliases refer to each other cyclically.
+    pub(crate) fn resolve_aliases(&mut self, type_aliases: &IndexMap<Symbol, TypeAlias>) {
+        // Order the aliases so that each alias is resolved after the aliases it refers to.
+        let names: IndexSet<Symbol> = type_aliases.keys().cloned().collect();
+        let mut type_alias_graph = TypeAliasGraph::new(names.clone());
+        for (name, type_alias) in type_aliases.iter() {
+            let mut used = Vec::new();
+            Self::named_types(&type_alias.type_, &mut used);
+            used.into_iter().filter(|used| names.contains(used)).for_each(|used| {
+                type_alias_graph.add_edge(*name, used);
+            });
+        }
+        let order = match type_alias_graph.post_order() {
+            Ok(order) => order,
+            Err(DiGraphError::CycleDetected(path)) => {
+                self.handler.emit_err(TypeCheckerError::cyclic_type_alias(path));
+                return;
+            }
+        };
+
+        for name in order {
+            let type_alias = &type_aliases[&name];
+            let type_ = self.resolve(&type_alias.type_);
+            self.type_aliases.insert(name, TypeAlias { type_, ..type_alias.clone() });
+        }
+    }
+
+    /// Collects the names of the structs, records, enums, and aliases that the type refers to.
+    fn named_types(type_: &Type, names: &mut Vec<Symbol>) {
+        match type_ {
+            Type::Identifier(identifier) => names.push(identifier.name),
+            Type::Array(array_type) => Self::named_types(array_type.element_type(), names),
+            Type::Mapping(mapping_type) => {
+                Self::named_types(&mapping_type.key, names);
+                Self::named_types(&mapping_type.value, names);
+            }
+            Type::Tuple(tuple_type) => tuple_type.elements().iter().for_each(|type_| Self::named_types(type_, names)),
+            _ => {}
+        }
+    }
+
+    /// Returns the type with each alias it refers to replaced by the type the alias stands for.
+    /// Names that are not aliases, e.g. those of structs, are left as they are.
+    pub(crate) fn resolve(&self, type_: &Type) -> Type {
+        match type_ {
+            Type::Identifier(identifier) => match self.type_aliases.get(&identifier.name) {
+                Some(type_alias) => self.instantiate(&type_alias.type_, identifier.span),
+                None => type_.clone(),
+            },
+            Type::Array(array_type) => Type::Array(ArrayType::new(
+                self.resolve(array_type.element_type()),
+                NonNegativeNumber::from(array_type.length()),
+            )),
+            Type::Mapping(mapping_type) => Type::Mapping(MappingType {
+                key: Box::new(self.resolve(&mapping_type.key)),
+                value: Box::new(self.resolve(&mapping_type.value)),
+            }),
+            Type::Tuple(tuple_type) => {
+                Type::Tuple(TupleType::new(tuple_type.elements().iter().map(|type_| self.resolve(type_)).collect()))
+            }
+            _ => type_.clone(),
+        }
+    }
+
+    /// Returns a copy of the resolved type of an alias, used at the given span.
+    /// The names in the copy are given new node IDs, so that each use of the alias has unique nodes.
+    fn instantiate(&self, type_: &Type, span: Span) -> Type {
+        match type_ {
+            Type::Identifier(identifier) => {
+                Type::Identifier(Identifier { name: identifier.name, span, id: self.node_builder.next_id() })
+            }
+            Type::Array(array_type) => Type::Array(ArrayType::new(
+                self.instantiate(array_type.element_type(), span),
+                NonNegativeNumber::from(array_type.length()),
+            )),
+            Type::Mapping(mapping_type) => Type::Mapping(MappingType {
+                key: Box::new(self.instantiate(&mapping_type.key, span)),
+                value: Box::new(self.instantiate(&mapping_type.value, span)),
+            }),
+            Type::Tuple(tuple_type) => Type::Tuple(TupleType::new(
+                tuple_type.elements().iter().map(|type_| self.instantiate(type_, span)).collect(),
+            )),
+            _ => type_.clone(),
+        }
+    }
+}
+
+impl ExpressionReconstructor for TypeAliasResolver<'_> {
+    type AdditionalOutput = ();
+
+    /// Reconstructs the struct initializer, leaving shorthand members, e.g. `Foo { x }`, as they are.
+    /// Otherwise, the expanded members would share the node ID of their identifier before type checking.
+    fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Struct(StructExpression {
+                members: input
+                    .members
+                    .into_iter()
+                    .map(|member| StructVariableInitializer {
+                        expression: member.expression.map(|expression| self.reconstruct_expression(expression).0),
+                        ..member
+                    })
+                    .collect(),
+                ..input
+            }),
+            Default::default(),
+        )
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::TypeChecker;
+use crate::{TypeChecker, VariableSymbol, VariableType};
 
 use leo_ast::*;
 use leo_errors::{emitter::Handler, TypeCheckerError};
@@ -53,6 +53,8 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             Expression::Err(err) => self.visit_err(err, additional),
             Expression::Identifier(identifier) => self.visit_identifier(identifier, additional),
             Expression::Literal(literal) => self.visit_literal(literal, additional),
+            Expression::Match(match_) => self.visit_match(match_, additional),
+            Expression::Repeat(repeat) => self.visit_repeat(repeat, additional),
             Expression::Ternary(ternary) => self.visit_ternary(ternary, additional),
             Expression::Tuple(tuple) => self.visit_tuple(tuple, additional),
             Expression::Unary(unary) => self.visit_unary(unary, additional),
@@ -92,6 +94,17 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 return element_type;
             }
             AccessExpression::AssociatedFunction(access) => {
+                // If the type is an enum, then check the construction of a variant with a payload.
+                if let (Some(enum_), Type::Identifier(name)) = (self.lookup_enum_type(&access.ty), &access.ty) {
+                    return self.check_enum_variant(
+                        &enum_,
+                        *name,
+                        &access.name,
+                        Some(&access.arguments),
+                        expected,
+                        input.span(),
+                    );
+                }
                 // Check core struct name and function.
                 if let Some(core_instruction) = self.get_core_function_call(&access.ty, &access.name) {
                     // Check that operation is not restricted to finalize blocks.
@@ -227,6 +240,13 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                                             ));
                                         }
                                     }
+                                } else if self.symbol_table.borrow().lookup_enum(identifier.name).is_some() {
+                                    // The payload of an enum value can only be accessed with a `match`.
+                                    self.emit_err(TypeCheckerError::type_should_be(
+                                        identifier,
+                                        "struct",
+                                        access.inner.span(),
+                                    ));
                                 } else {
                                     self.emit_err(TypeCheckerError::undefined_type(&access.inner, access.inner.span()));
                                 }
@@ -245,6 +265,10 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 }
             }
             AccessExpression::AssociatedConstant(access) => {
+                // If the type is an enum, then check the construction of a variant without a payload.
+                if let (Some(enum_), Type::Identifier(name)) = (self.lookup_enum_type(&access.ty), &access.ty) {
+                    return self.check_enum_variant(&enum_, *name, &access.name, None, expected, input.span());
+                }
                 // Check associated constant type and constant name
                 if let Some(core_constant) = self.get_core_constant(&access.ty, &access.name) {
                     // Check return type if the expected type is known.
@@ -756,6 +780,72 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
         })
     }
 
+    fn visit_repeat(&mut self, input: &'a RepeatExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        // Get the type of the repeated element.
+        let element_type = self.visit_expression(&input.expr, &None);
+
+        // Construct the array type.
+        let return_type = match input.count.value() {
+            // The array cannot be empty.
+            0 => {
+                self.emit_err(TypeCheckerError::array_empty(input.span()));
+                None
+            }
+            1..=Testnet3::MAX_ARRAY_ELEMENTS => {
+                element_type.map(|element_type| Type::Array(ArrayType::new(element_type, input.count.clone())))
+            }
+            // The array cannot have more than `MAX_ARRAY_ELEMENTS` elements.
+            num_elements => {
+                self.emit_err(TypeCheckerError::array_too_large(
+                    num_elements,
+                    Testnet3::MAX_ARRAY_ELEMENTS,
+                    input.span(),
+                ));
+                None
+            }
+        };
+
+        // If the expected type is known, then check that the array type is the same as the expected type.
+        if let Some(expected) = additional {
+            self.assert_type(&return_type, expected, input.span());
+        }
+
+        // Return the array type.
+        return_type
+    }
+
+    fn visit_match(&mut self, input: &'a MatchExpression, expected: &Self::AdditionalInput) -> Self::Output {
+        let type_ = self.visit_expression(&input.expression, &None);
+        let bindings = self.check_match_arms(&type_, input.expression.span(), &input.arms, input.span);
+
+        // The arms must have the same type. If the expected type is unknown, then the first arm determines it.
+        let mut output = expected.clone();
+        for (arm, binding) in input.arms.iter().zip_eq(bindings) {
+            // The variable bound by the pattern is only visible in the body of the arm.
+            let binding = binding.and_then(|(identifier, type_)| {
+                match self.symbol_table.borrow_mut().insert_variable(identifier.name, VariableSymbol {
+                    type_,
+                    span: identifier.span,
+                    declaration: VariableType::Mut,
+                }) {
+                    Ok(()) => Some(identifier.name),
+                    Err(err) => {
+                        self.handler.emit_err(err);
+                        None
+                    }
+                }
+            });
+            let arm_type = self.visit_expression(&arm.body, &output);
+            if let Some(name) = binding {
+                self.symbol_table.borrow_mut().remove_variable_from_current_scope(name);
+            }
+            if output.is_none() {
+                output = arm_type;
+            }
+        }
+        output
+    }
+
     fn visit_ternary(&mut self, input: &'a TernaryExpression, expected: &Self::AdditionalInput) -> Self::Output {
         self.visit_expression(&input.condition, &Some(Type::Boolean));
 
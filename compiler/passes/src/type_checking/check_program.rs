@@ -14,38 +14,37 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{DiGraphError, TypeChecker, VariableSymbol, VariableType};
+use crate::{ConstGraph, DiGraphError, TypeChecker, VariableSymbol, VariableType};
 
 use leo_ast::*;
 use leo_errors::TypeCheckerError;
-use leo_span::sym;
+use leo_span::{sym, Symbol};
 
 use snarkvm_console::network::{Network, Testnet3};
 
+use indexmap::IndexSet;
 use std::collections::HashSet;
 
 // TODO: Cleanup logic for tuples.
 
 impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
     fn visit_program(&mut self, input: &'a Program) {
-        match self.is_imported {
-            // If the program is imported, then it is not allowed to import any other programs.
-            true => {
-                input.imports.values().for_each(|(_, span)| {
-                    self.emit_err(TypeCheckerError::imported_program_cannot_import_program(*span))
-                });
-            }
-            // Otherwise, typecheck the imported programs.
-            false => {
-                // Set `self.is_imported`.
-                let previous_is_imported = core::mem::replace(&mut self.is_imported, true);
+        // Note that import resolution flattens the imports of imported programs into the imports of the main program.
+        if !self.is_imported {
+            // Set `self.is_imported`.
+            let previous_is_imported = core::mem::replace(&mut self.is_imported, true);
 
-                // Typecheck the imported programs.
-                input.imports.values().for_each(|import| self.visit_import(&import.0));
+            // Typecheck the imported programs.
+            input.imports.values().for_each(|import| self.visit_import(&import.0));
 
-                // Set `self.is_imported` to its previous state.
-                self.is_imported = previous_is_imported;
-            }
+            // Set `self.is_imported` to its previous state.
+            self.is_imported = previous_is_imported;
+
+            // Check that the type aliases, which have been resolved, stand for valid types.
+            let type_aliases: Vec<TypeAlias> = self.symbol_table.borrow().type_aliases.values().cloned().collect();
+            type_aliases.iter().for_each(|type_alias| {
+                self.assert_type_is_valid(&type_alias.type_, type_alias.span);
+            });
         }
 
         // Typecheck the program scopes.
@@ -53,12 +52,35 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        // Order the const definitions so that each const is checked after the consts it references.
+        let const_names: IndexSet<Symbol> = input.consts.iter().map(|(name, _)| *name).collect();
+        let mut const_graph = ConstGraph::new(const_names.clone());
+        for (name, const_) in input.consts.iter() {
+            let mut collector = IdentifierCollector::default();
+            collector.visit_expression(&const_.value, &());
+            collector.identifiers.into_iter().filter(|used| const_names.contains(used)).for_each(|used| {
+                const_graph.add_edge(*name, used);
+            });
+        }
+        let const_order = match const_graph.post_order() {
+            Ok(order) => order,
+            Err(DiGraphError::CycleDetected(path)) => {
+                self.emit_err(TypeCheckerError::cyclic_const_dependency(path));
+                const_names
+            }
+        };
+
         // Typecheck each const definition, and append to symbol table.
-        input.consts.iter().for_each(|(_, c)| self.visit_const(c));
+        const_order.iter().for_each(|name| {
+            input.consts.iter().filter(|(const_name, _)| const_name == name).for_each(|(_, c)| self.visit_const(c))
+        });
 
         // Typecheck each struct definition.
         input.structs.iter().for_each(|(_, function)| self.visit_struct(function));
 
+        // Typecheck each enum definition.
+        input.enums.iter().for_each(|(_, enum_)| self.visit_enum(enum_));
+
         // Check that the struct dependency graph does not have any cycles.
         if let Err(DiGraphError::CycleDetected(path)) = self.struct_graph.post_order() {
             self.emit_err(TypeCheckerError::cyclic_struct_dependency(path));
@@ -167,6 +189,48 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
         }
     }
 
+    fn visit_enum(&mut self, input: &'a Enum) {
+        if input.variants.is_empty() {
+            self.emit_err(TypeCheckerError::enum_must_have_variants(input.name(), input.span));
+        }
+
+        // The tag of a variant is stored as a `u8`.
+        if input.variants.len() > u8::MAX as usize + 1 {
+            self.emit_err(TypeCheckerError::too_many_enum_variants(input.name(), u8::MAX as usize + 1, input.span));
+        }
+
+        let mut used = HashSet::new();
+        for EnumVariant { identifier, payload, span, .. } in input.variants.iter() {
+            // Check for conflicting variant names.
+            if !used.insert(identifier.name) {
+                self.emit_err(TypeCheckerError::duplicate_enum_variant(input.name(), identifier, identifier.span));
+            }
+            if identifier.name == sym::tag {
+                self.emit_err(TypeCheckerError::enum_variant_cannot_be_named_tag(input.name(), identifier.span));
+            }
+
+            if let Some(payload) = payload {
+                // Check that the payload type is defined, and that a default value can be constructed for it.
+                if self.assert_type_is_valid(payload, *span)
+                    && !self.has_default_value(payload, &mut IndexSet::from([input.name()]))
+                {
+                    self.emit_err(TypeCheckerError::invalid_enum_payload_type(payload, *span));
+                }
+
+                // If the payload is a struct or enum, add it to the struct dependency graph.
+                match payload {
+                    Type::Identifier(payload_type) => self.struct_graph.add_edge(input.name(), payload_type.name),
+                    Type::Array(array_type) => {
+                        if let Type::Identifier(payload_type) = array_type.base_element_type() {
+                            self.struct_graph.add_edge(input.name(), payload_type.name);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
     fn visit_mapping(&mut self, input: &'a Mapping) {
         // Check that a mapping's key type is valid.
         self.assert_type_is_valid(&input.key_type, input.span);
@@ -205,10 +269,29 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
 
     fn visit_function(&mut self, function: &'a Function) {
         // Check that the function's annotations are valid.
-        // Note that Leo does not natively support any specific annotations.
+        // Note that `@test` and `@should_fail` are the only annotations Leo supports, and are used by `leo test`.
+        let is_test = function.annotations.iter().any(|annotation| annotation.identifier.name == sym::test);
         for annotation in function.annotations.iter() {
-            // TODO: Change to compiler warning.
-            self.emit_err(TypeCheckerError::unknown_annotation(annotation, annotation.span))
+            match annotation.identifier.name {
+                sym::test | sym::should_fail if function.variant != Variant::Transition => {
+                    self.emit_err(TypeCheckerError::annotation_requires_transition(annotation, annotation.span))
+                }
+                sym::test => {
+                    // Test transitions are invoked without arguments.
+                    if !function.input.is_empty() {
+                        self.emit_err(TypeCheckerError::test_function_cannot_have_inputs(
+                            function.identifier,
+                            function.identifier.span,
+                        ))
+                    }
+                }
+                sym::should_fail if !is_test => {
+                    self.emit_err(TypeCheckerError::should_fail_requires_test(annotation.span))
+                }
+                sym::should_fail => {}
+                // TODO: Change to compiler warning.
+                _ => self.emit_err(TypeCheckerError::unknown_annotation(annotation, annotation.span)),
+            }
         }
 
         self.variant = Some(function.variant);
@@ -285,7 +368,11 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                         // If the function is not a transition function, then it cannot output a record.
                         if let Type::Identifier(identifier) = function_output.type_ {
                             if !matches!(function.variant, Variant::Transition)
-                                && self.symbol_table.borrow().lookup_struct(identifier.name).unwrap().is_record
+                                && self
+                                    .symbol_table
+                                    .borrow()
+                                    .lookup_struct(identifier.name)
+                                    .map_or(false, |struct_| struct_.is_record)
                             {
                                 self.emit_err(TypeCheckerError::function_cannot_output_record(function_output.span));
                             }
@@ -306,13 +393,16 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
 
         self.visit_block(&function.block);
 
+        // Note that the bodies of imported programs may be omitted, as in the programs derived from `.aleo` imports.
+        // Their bodies are checked when the imported programs are themselves compiled.
+
         // If the function has a return type, then check that it has a return.
-        if function.output_type != Type::Unit && !self.has_return {
+        if !self.is_imported && function.output_type != Type::Unit && !self.has_return {
             self.emit_err(TypeCheckerError::missing_return(function.span));
         }
 
         // If the function has a finalize block, then check that it has at least one finalize statement.
-        if function.finalize.is_some() && !self.has_finalize {
+        if !self.is_imported && function.finalize.is_some() && !self.has_finalize {
             self.emit_err(TypeCheckerError::missing_finalize(function.span));
         }
 
@@ -353,8 +443,12 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                     }
                     // Check that the input parameter is not a record.
                     if let Type::Identifier(identifier) = input_var.type_() {
-                        // Note that this unwrap is safe, as the type is defined.
-                        if self.symbol_table.borrow().lookup_struct(identifier.name).unwrap().is_record {
+                        if self
+                            .symbol_table
+                            .borrow()
+                            .lookup_struct(identifier.name)
+                            .map_or(false, |struct_| struct_.is_record)
+                        {
                             self.emit_err(TypeCheckerError::finalize_cannot_take_record_as_input(input_var.span()))
                         }
                     }
@@ -394,8 +488,12 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                     }
                     // Check that the output is not a record.
                     if let Type::Identifier(identifier) = output_type.type_() {
-                        // Note that this unwrap is safe, as the type is defined.
-                        if self.symbol_table.borrow().lookup_struct(identifier.name).unwrap().is_record {
+                        if self
+                            .symbol_table
+                            .borrow()
+                            .lookup_struct(identifier.name)
+                            .map_or(false, |struct_| struct_.is_record)
+                        {
                             self.emit_err(TypeCheckerError::finalize_cannot_output_record(output_type.span()))
                         }
                     }
@@ -409,7 +507,7 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
 
             // TODO: Remove if this restriction is relaxed at Aleo instructions level.
             // Check that the finalize block is not empty.
-            if finalize.block.statements.is_empty() {
+            if !self.is_imported && finalize.block.statements.is_empty() {
                 self.emit_err(TypeCheckerError::finalize_block_must_not_be_empty(finalize.span));
             }
 
@@ -437,3 +535,27 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
         self.variant = None;
     }
 }
+
+/// Collects the identifiers referenced by an expression.
+#[derive(Default)]
+struct IdentifierCollector {
+    identifiers: IndexSet<Symbol>,
+}
+
+impl<'a> ExpressionVisitor<'a> for IdentifierCollector {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.identifiers.insert(input.name);
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().for_each(|member| match &member.expression {
+            Some(expression) => self.visit_expression(expression, additional),
+            None => {
+                self.identifiers.insert(member.identifier.name);
+            }
+        });
+    }
+}
@@ -19,7 +19,7 @@ use itertools::Itertools;
 
 use leo_ast::*;
 use leo_errors::TypeCheckerError;
-use leo_span::{Span, Symbol};
+use leo_span::{sym, Span, Symbol};
 
 impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
     fn visit_statement(&mut self, input: &'a Statement) {
@@ -30,19 +30,75 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         }
 
         match input {
+            Statement::ArrayIteration(stmt) => self.visit_array_iteration(stmt),
             Statement::Assert(stmt) => self.visit_assert(stmt),
             Statement::Assign(stmt) => self.visit_assign(stmt),
             Statement::Block(stmt) => self.visit_block(stmt),
+            Statement::Break(stmt) => self.visit_break(stmt),
             Statement::Conditional(stmt) => self.visit_conditional(stmt),
             Statement::Console(stmt) => self.visit_console(stmt),
             Statement::Const(stmt) => self.visit_const(stmt),
+            Statement::Continue(stmt) => self.visit_continue(stmt),
             Statement::Definition(stmt) => self.visit_definition(stmt),
             Statement::Expression(stmt) => self.visit_expression_statement(stmt),
             Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Match(stmt) => self.visit_match_statement(stmt),
             Statement::Return(stmt) => self.visit_return(stmt),
         }
     }
 
+    fn visit_array_iteration(&mut self, input: &'a ArrayIterationStatement) {
+        // Check that the expression is an array.
+        let array_type = self.visit_expression(&input.array, &None);
+        self.assert_array_type(&array_type, input.array.span());
+
+        // Get the element type of the array.
+        let element_type = match array_type {
+            Some(Type::Array(array_type)) => Some(array_type.element_type().clone()),
+            _ => None,
+        };
+
+        // If the loop variable is annotated, check that its type is the element type of the array.
+        if let Some(type_) = &input.type_ {
+            if self.assert_type_is_valid(type_, input.variable.span) {
+                self.assert_type(&element_type, type_, input.variable.span);
+            }
+        }
+
+        // Create a new scope for the loop body.
+        let scope_index = self.create_child_scope();
+
+        // Add the loop variable to the scope of the loop body.
+        // Note that the loop variable is bound to a copy of each element, so it is not a constant.
+        if let Some(type_) = element_type.or_else(|| input.type_.clone()) {
+            if let Err(err) = self.symbol_table.borrow_mut().insert_variable(input.variable.name, VariableSymbol {
+                type_,
+                span: input.span(),
+                declaration: VariableType::Mut,
+            }) {
+                self.handler.emit_err(err);
+            }
+        }
+
+        let prior_has_return = core::mem::take(&mut self.has_return);
+        let prior_has_finalize = core::mem::take(&mut self.has_finalize);
+        let prior_is_loop = core::mem::replace(&mut self.is_loop, true);
+
+        self.visit_block(&input.block);
+
+        if self.has_finalize {
+            self.emit_err(TypeCheckerError::loop_body_contains_finalize(input.span()));
+        }
+
+        // Note that a loop body that always returns does not guarantee that the function returns, since the loop may not execute.
+        self.has_return = prior_has_return;
+        self.has_finalize = prior_has_finalize;
+        self.is_loop = prior_is_loop;
+
+        // Exit the scope.
+        self.exit_scope(scope_index);
+    }
+
     fn visit_assert(&mut self, input: &'a AssertStatement) {
         match &input.variant {
             AssertVariant::Assert(expr) => {
@@ -60,11 +116,18 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_assign(&mut self, input: &'a AssignStatement) {
-        let var_name = match input.place {
-            Expression::Identifier(id) => id,
-            _ => {
-                self.emit_err(TypeCheckerError::invalid_assignment_target(input.place.span()));
-                return;
+        // Find the variable at the root of the place.
+        // Note that the place must either be an identifier or a (possibly nested) member or array access into one.
+        let mut place = &input.place;
+        let var_name = loop {
+            match place {
+                Expression::Identifier(id) if id.name != sym::SelfLower => break *id,
+                Expression::Access(AccessExpression::Member(access)) => place = &access.inner,
+                Expression::Access(AccessExpression::Array(access)) => place = &access.array,
+                _ => {
+                    self.emit_err(TypeCheckerError::invalid_assignment_target(input.place.span()));
+                    return;
+                }
             }
         };
 
@@ -84,8 +147,15 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
             None
         };
 
-        if var_type.is_some() {
-            self.visit_expression(&input.value, &var_type);
+        // If the place is a member or array access, then the type of the place is the type of the accessed element.
+        let place_type = match (&input.place, var_type) {
+            (Expression::Identifier(_), var_type) => var_type,
+            (_, Some(_)) => self.visit_expression(&input.place, &None),
+            (_, None) => None,
+        };
+
+        if place_type.is_some() {
+            self.visit_expression(&input.value, &place_type);
         }
     }
 
@@ -99,6 +169,15 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         self.exit_scope(scope_index);
     }
 
+    fn visit_break(&mut self, input: &'a BreakStatement) {
+        if !self.is_loop {
+            self.emit_err(TypeCheckerError::loop_control_outside_of_loop("break", input.span));
+        }
+
+        // Like a return statement, a `break` ends the current path, so no statements can follow it.
+        self.has_return = true;
+    }
+
     fn visit_conditional(&mut self, input: &'a ConditionalStatement) {
         self.visit_expression(&input.condition, &Some(Type::Boolean));
 
@@ -147,8 +226,34 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         self.has_finalize = previous_has_finalize || (then_block_has_finalize && otherwise_block_has_finalize);
     }
 
-    fn visit_console(&mut self, _: &'a ConsoleStatement) {
-        unreachable!("Parsing guarantees that console statements are not present in the AST.");
+    fn visit_console(&mut self, input: &'a ConsoleStatement) {
+        match &input.function {
+            ConsoleFunction::Assert(expr) => {
+                let type_ = self.visit_expression(expr, &Some(Type::Boolean));
+                self.assert_bool_type(&type_, expr.span());
+            }
+            ConsoleFunction::AssertEq(left, right) | ConsoleFunction::AssertNeq(left, right) => {
+                let t1 = self.visit_expression(left, &None);
+                let t2 = self.visit_expression(right, &None);
+
+                // Check that the types are equal.
+                self.check_eq_types(&t1, &t2, input.span());
+            }
+            ConsoleFunction::Log(format, args) => {
+                // Check that there is an argument for each placeholder.
+                let placeholders = format.matches("{}").count();
+                if placeholders != args.len() {
+                    self.emit_err(TypeCheckerError::console_log_wrong_number_of_arguments(
+                        placeholders,
+                        args.len(),
+                        input.span(),
+                    ));
+                }
+                args.iter().for_each(|arg| {
+                    self.visit_expression(arg, &None);
+                });
+            }
+        }
     }
 
     fn visit_const(&mut self, input: &'a ConstDeclaration) {
@@ -172,21 +277,8 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
             _ => (), // Do nothing
         }
 
-        // Enforce that Constant variables have literal expressions on right-hand side
-        match &input.value {
-            Expression::Literal(_) => (),
-            Expression::Tuple(tuple_expression) => match tuple_expression.elements.len() {
-                0 | 1 => unreachable!("Parsing guarantees that tuple types have at least two elements."),
-                _ => {
-                    if tuple_expression.elements.iter().any(|expr| !matches!(expr, Expression::Literal(_))) {
-                        self.emit_err(TypeCheckerError::const_declaration_must_be_literal_or_tuple_of_literals(
-                            input.span,
-                        ))
-                    }
-                }
-            },
-            _ => self.emit_err(TypeCheckerError::const_declaration_must_be_literal_or_tuple_of_literals(input.span())),
-        }
+        // Enforce that the right-hand side of a constant can be evaluated at compile time.
+        self.assert_compile_time_evaluable(&input.value);
 
         // Check the expression on the right-hand side.
         self.visit_expression(&input.value, &Some(input.type_.clone()));
@@ -201,6 +293,15 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         }
     }
 
+    fn visit_continue(&mut self, input: &'a ContinueStatement) {
+        if !self.is_loop {
+            self.emit_err(TypeCheckerError::loop_control_outside_of_loop("continue", input.span));
+        }
+
+        // Like a return statement, a `continue` ends the current path, so no statements can follow it.
+        self.has_return = true;
+    }
+
     fn visit_definition(&mut self, input: &'a DefinitionStatement) {
         // Check that the type of the definition is defined.
         self.assert_type_is_valid(&input.type_, input.span);
@@ -309,19 +410,18 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
 
         let prior_has_return = core::mem::take(&mut self.has_return);
         let prior_has_finalize = core::mem::take(&mut self.has_finalize);
+        let prior_is_loop = core::mem::replace(&mut self.is_loop, true);
 
         self.visit_block(&input.block);
 
-        if self.has_return {
-            self.emit_err(TypeCheckerError::loop_body_contains_return(input.span()));
-        }
-
         if self.has_finalize {
             self.emit_err(TypeCheckerError::loop_body_contains_finalize(input.span()));
         }
 
+        // Note that a loop body that always returns does not guarantee that the function returns, since the loop may not execute.
         self.has_return = prior_has_return;
         self.has_finalize = prior_has_finalize;
+        self.is_loop = prior_is_loop;
 
         // Exit the scope.
         self.exit_scope(scope_index);
@@ -368,6 +468,43 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         }
     }
 
+    fn visit_match_statement(&mut self, input: &'a MatchStatement) {
+        let type_ = self.visit_expression(&input.expression, &None);
+        let bindings = self.check_match_arms(&type_, input.expression.span(), &input.arms, input.span);
+
+        // The match returns or invokes finalize if all of its arms do.
+        let previous_has_return = self.has_return;
+        let previous_has_finalize = self.has_finalize;
+        let mut arms_have_return = true;
+        let mut arms_have_finalize = true;
+
+        for (arm, binding) in input.arms.iter().zip_eq(bindings) {
+            self.has_return = false;
+            self.has_finalize = false;
+
+            // Create a new scope for the arm, which contains the variable bound by the pattern.
+            // Note that the statements of the arm are visited directly, so that each arm has exactly one scope.
+            let scope_index = self.create_child_scope();
+            if let Some((identifier, type_)) = binding {
+                if let Err(err) = self.symbol_table.borrow_mut().insert_variable(identifier.name, VariableSymbol {
+                    type_,
+                    span: identifier.span,
+                    declaration: VariableType::Mut,
+                }) {
+                    self.handler.emit_err(err);
+                }
+            }
+            arm.body.statements.iter().for_each(|stmt| self.visit_statement(stmt));
+            self.exit_scope(scope_index);
+
+            arms_have_return &= self.has_return;
+            arms_have_finalize &= self.has_finalize;
+        }
+
+        self.has_return = previous_has_return || (!input.arms.is_empty() && arms_have_return);
+        self.has_finalize = previous_has_finalize || (!input.arms.is_empty() && arms_have_finalize);
+    }
+
     fn visit_return(&mut self, input: &'a ReturnStatement) {
         // We can safely unwrap all self.parent instances because
         // statements should always have some parent block
@@ -14,14 +14,31 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CallGraph, StructGraph, SymbolTable, TypeTable};
-
-use leo_ast::{CoreConstant, CoreFunction, Identifier, IntegerType, MappingType, Node, Type, Variant};
+use crate::{CallGraph, StructGraph, SymbolTable, TypeTable, VariableType};
+
+use leo_ast::{
+    AccessExpression,
+    CoreConstant,
+    CoreFunction,
+    Enum,
+    Expression,
+    ExpressionVisitor,
+    Identifier,
+    IntegerType,
+    MappingType,
+    MatchArm,
+    MatchPattern,
+    Node,
+    Type,
+    Value,
+    Variant,
+};
 use leo_errors::{emitter::Handler, TypeCheckerError};
 use leo_span::{Span, Symbol};
 
 use snarkvm_console::network::{Network, Testnet3};
 
+use indexmap::IndexSet;
 use itertools::Itertools;
 use std::cell::RefCell;
 
@@ -49,6 +66,8 @@ pub struct TypeChecker<'a> {
     pub(crate) is_finalize: bool,
     /// Whether or not we are currently traversing an imported program.
     pub(crate) is_imported: bool,
+    /// Whether or not we are currently traversing the body of a loop.
+    pub(crate) is_loop: bool,
     /// Whether or not we are currently traversing a return statement.
     pub(crate) is_return: bool,
 }
@@ -100,7 +119,8 @@ const MAGNITUDE_TYPES: [Type; 3] =
 impl<'a> TypeChecker<'a> {
     /// Returns a new type checker given a symbol table and error handler.
     pub fn new(symbol_table: SymbolTable, type_table: &'a TypeTable, handler: &'a Handler) -> Self {
-        let struct_names = symbol_table.structs.keys().cloned().collect();
+        // Note that enums are included, since their payloads may depend on structs and other enums.
+        let struct_names = symbol_table.structs.keys().chain(symbol_table.enums.keys()).cloned().collect();
 
         let function_names = symbol_table.functions.keys().cloned().collect();
 
@@ -117,6 +137,7 @@ impl<'a> TypeChecker<'a> {
             has_finalize: false,
             is_finalize: false,
             is_imported: false,
+            is_loop: false,
             is_return: false,
         }
     }
@@ -1069,7 +1090,10 @@ impl<'a> TypeChecker<'a> {
                 self.emit_err(TypeCheckerError::strings_are_not_supported(span));
             }
             // Check that the named composite type has been defined.
-            Type::Identifier(identifier) if self.symbol_table.borrow().lookup_struct(identifier.name).is_none() => {
+            Type::Identifier(identifier)
+                if self.symbol_table.borrow().lookup_struct(identifier.name).is_none()
+                    && self.symbol_table.borrow().lookup_enum(identifier.name).is_none() =>
+            {
                 is_valid = false;
                 self.emit_err(TypeCheckerError::undefined_type(identifier.name, span));
             }
@@ -1117,6 +1141,241 @@ impl<'a> TypeChecker<'a> {
         is_valid
     }
 
+    /// Returns the enum definition if the type is an enum.
+    pub(crate) fn lookup_enum_type(&self, type_: &Type) -> Option<Enum> {
+        match type_ {
+            Type::Identifier(identifier) => self.symbol_table.borrow().lookup_enum(identifier.name).cloned(),
+            _ => None,
+        }
+    }
+
+    /// Returns true if a default value can be constructed for the type, which is used for the inactive payloads of an enum.
+    /// Note that `visited` guards against cyclic definitions, which are reported separately.
+    pub(crate) fn has_default_value(&self, type_: &Type, visited: &mut IndexSet<Symbol>) -> bool {
+        match type_ {
+            Type::Address | Type::Boolean | Type::Field | Type::Group | Type::Integer(_) | Type::Scalar => true,
+            Type::Array(array_type) => self.has_default_value(array_type.element_type(), visited),
+            Type::Identifier(identifier) if !visited.insert(identifier.name) => true,
+            Type::Identifier(identifier) => {
+                if let Some(struct_) = self.symbol_table.borrow().lookup_struct(identifier.name).cloned() {
+                    !struct_.is_record
+                        && struct_.members.iter().all(|member| self.has_default_value(&member.type_, visited))
+                } else if let Some(enum_) = self.symbol_table.borrow().lookup_enum(identifier.name).cloned() {
+                    enum_
+                        .variants
+                        .iter()
+                        .filter_map(|variant| variant.payload.as_ref())
+                        .all(|payload| self.has_default_value(payload, visited))
+                } else {
+                    // Undefined types are reported separately.
+                    true
+                }
+            }
+            Type::Err | Type::Mapping(_) | Type::Signature | Type::String | Type::Tuple(_) | Type::Unit => false,
+        }
+    }
+
+    /// Type checks the construction of an enum variant, e.g. `Phase::Open` or `Phase::Settled(5u64)`, and returns the enum type.
+    /// The `arguments` are `None` for a variant written without parentheses.
+    pub(crate) fn check_enum_variant(
+        &mut self,
+        enum_: &Enum,
+        name: Identifier,
+        variant: &Identifier,
+        arguments: Option<&'a [Expression]>,
+        expected: &Option<Type>,
+        span: Span,
+    ) -> Option<Type> {
+        let enum_variant = match enum_.variant(variant.name) {
+            Some((_, enum_variant)) => enum_variant,
+            None => {
+                self.emit_err(TypeCheckerError::undefined_enum_variant(name, variant, variant.span));
+                arguments.into_iter().flatten().for_each(|argument| {
+                    self.visit_expression(argument, &None);
+                });
+                return None;
+            }
+        };
+        match (&enum_variant.payload, arguments) {
+            (None, None) | (None, Some([])) => {}
+            (None, Some(arguments)) => {
+                self.emit_err(TypeCheckerError::enum_variant_has_no_payload(name, variant, span));
+                arguments.iter().for_each(|argument| {
+                    self.visit_expression(argument, &None);
+                });
+            }
+            (Some(payload), Some([argument])) => {
+                self.visit_expression(argument, &Some(payload.clone()));
+            }
+            (Some(_), None) => self.emit_err(TypeCheckerError::enum_variant_expects_payload(name, variant, span)),
+            (Some(_), Some(arguments)) => {
+                self.emit_err(TypeCheckerError::incorrect_num_args_to_call(1, arguments.len(), span));
+                arguments.iter().for_each(|argument| {
+                    self.visit_expression(argument, &None);
+                });
+            }
+        }
+        Some(self.check_expected_struct(name, expected, span))
+    }
+
+    /// Checks the patterns of the arms of a `match` on a value of the given type.
+    /// Returns the variable bound by each arm along with its type, if any.
+    pub(crate) fn check_match_arms<T>(
+        &mut self,
+        type_: &Option<Type>,
+        scrutinee_span: Span,
+        arms: &'a [MatchArm<T>],
+        span: Span,
+    ) -> Vec<Option<(Identifier, Type)>> {
+        // Look up the enum being matched. Besides enums, integers and booleans can be matched.
+        let enum_ = match type_ {
+            Some(type_) => {
+                let enum_ = self.lookup_enum_type(type_);
+                if enum_.is_none() && !matches!(type_, Type::Integer(_) | Type::Boolean) {
+                    self.emit_err(TypeCheckerError::invalid_match_type(type_, scrutinee_span));
+                }
+                enum_
+            }
+            None => None,
+        };
+
+        // The variants or literal values covered by the previous arms.
+        let mut covered = IndexSet::new();
+        let mut has_wildcard = false;
+        let bindings = arms
+            .iter()
+            .map(|arm| self.check_match_pattern(&arm.pattern, type_, &enum_, &mut covered, &mut has_wildcard))
+            .collect();
+
+        // Check that the arms cover every variant of the enum, or both booleans.
+        // Integers can only be covered by a wildcard.
+        let missing = match (&enum_, type_) {
+            (Some(enum_), _) => enum_
+                .variants
+                .iter()
+                .filter(|variant| !covered.contains(&variant.name().to_string()))
+                .map(|variant| format!("`{}::{}`", enum_.identifier, variant.identifier))
+                .collect::<Vec<_>>(),
+            (None, Some(Type::Boolean)) => ["true", "false"]
+                .into_iter()
+                .filter(|value| !covered.contains(*value))
+                .map(|value| format!("`{value}`"))
+                .collect(),
+            (None, Some(Type::Integer(_))) => vec!["`_`".to_string()],
+            _ => Vec::new(),
+        };
+        if !has_wildcard && !missing.is_empty() {
+            self.emit_err(TypeCheckerError::non_exhaustive_match(missing.join(", "), span));
+        }
+
+        bindings
+    }
+
+    /// Checks a pattern of an arm of a `match`, recording the variants or literal values it covers.
+    /// Returns the variable bound by the pattern along with its type, if any.
+    fn check_match_pattern(
+        &mut self,
+        pattern: &'a MatchPattern,
+        type_: &Option<Type>,
+        enum_: &Option<Enum>,
+        covered: &mut IndexSet<String>,
+        has_wildcard: &mut bool,
+    ) -> Option<(Identifier, Type)> {
+        // Only report invalid patterns if the type of the matched value can be matched.
+        let is_primitive = matches!(type_, Some(Type::Integer(_) | Type::Boolean));
+        match pattern {
+            MatchPattern::Wildcard(pattern_span) => {
+                // A wildcard is unreachable after another wildcard, or after arms for all of the possible values.
+                let covers_all = match (enum_, type_) {
+                    (Some(enum_), _) => covered.len() == enum_.variants.len(),
+                    (None, Some(Type::Boolean)) => covered.contains("true") && covered.contains("false"),
+                    _ => false,
+                };
+                if *has_wildcard || covers_all {
+                    self.emit_err(TypeCheckerError::unreachable_match_arm(*pattern_span));
+                }
+                *has_wildcard = true;
+                None
+            }
+            MatchPattern::Or(patterns, _) => {
+                for pattern in patterns.iter() {
+                    if self.check_match_pattern(pattern, type_, enum_, covered, has_wildcard).is_some() {
+                        self.emit_err(TypeCheckerError::or_pattern_cannot_bind(pattern, pattern.span()));
+                    }
+                }
+                None
+            }
+            MatchPattern::Constant(expression) => {
+                if let Some(enum_) = enum_ {
+                    self.emit_err(TypeCheckerError::invalid_match_pattern(pattern, enum_.identifier, pattern.span()));
+                    return None;
+                }
+                let expected = if is_primitive { type_.clone() } else { None };
+                self.visit_expression(expression, &expected);
+                if self.find_non_const_expression(expression).is_some() {
+                    self.emit_err(TypeCheckerError::match_pattern_must_be_constant(pattern, pattern.span()));
+                }
+                // The values of constants are not known yet, so only literals are checked for repetition.
+                if let Expression::Literal(literal) = expression {
+                    if let Ok(value) = Value::try_from(literal) {
+                        // An arm is unreachable after a wildcard, or after another arm for the same value.
+                        if !covered.insert(value.to_string()) || *has_wildcard {
+                            self.emit_err(TypeCheckerError::unreachable_match_arm(pattern.span()));
+                        }
+                    }
+                }
+                None
+            }
+            MatchPattern::Variant(pattern) => {
+                let enum_ = match enum_ {
+                    Some(enum_) => enum_,
+                    None => {
+                        if let (true, Some(type_)) = (is_primitive, type_) {
+                            self.emit_err(TypeCheckerError::invalid_match_pattern(pattern, type_, pattern.span));
+                        }
+                        return None;
+                    }
+                };
+                if pattern.enum_name.name != enum_.name() {
+                    self.emit_err(TypeCheckerError::match_pattern_type_mismatch(
+                        enum_.name(),
+                        pattern.enum_name,
+                        pattern.span,
+                    ));
+                    return None;
+                }
+                let variant = match enum_.variant(pattern.variant.name) {
+                    Some((_, variant)) => variant,
+                    None => {
+                        self.emit_err(TypeCheckerError::undefined_enum_variant(
+                            pattern.enum_name,
+                            pattern.variant,
+                            pattern.variant.span,
+                        ));
+                        return None;
+                    }
+                };
+                // An arm is unreachable after a wildcard, or after another arm for the same variant.
+                if !covered.insert(variant.name().to_string()) || *has_wildcard {
+                    self.emit_err(TypeCheckerError::unreachable_match_arm(pattern.span));
+                }
+                match (&variant.payload, pattern.binding) {
+                    (Some(payload), Some(identifier)) => Some((identifier, payload.clone())),
+                    (None, Some(_)) => {
+                        self.emit_err(TypeCheckerError::enum_variant_has_no_payload(
+                            pattern.enum_name,
+                            pattern.variant,
+                            pattern.span,
+                        ));
+                        None
+                    }
+                    // The payload may be ignored.
+                    (_, None) => None,
+                }
+            }
+        }
+    }
+
     /// Emits an error if the type is not a mapping.
     pub(crate) fn assert_mapping_type(&self, type_: &Option<Type>, span: Span) -> Option<MappingType> {
         self.check_type(|type_| matches!(type_, Type::Mapping(_)), "mapping".to_string(), type_, span);
@@ -1130,6 +1389,66 @@ impl<'a> TypeChecker<'a> {
     pub(crate) fn assert_array_type(&self, type_: &Option<Type>, span: Span) {
         self.check_type(|type_| matches!(type_, Type::Array(_)), "array".to_string(), type_, span);
     }
+
+    /// Emits an error if the expression cannot be evaluated at compile time.
+    /// Compile-time evaluable expressions are built from literals, consts, and operators over them.
+    pub(crate) fn assert_compile_time_evaluable(&self, expression: &Expression) {
+        if let Some(expression) = self.find_non_const_expression(expression) {
+            self.emit_err(TypeCheckerError::const_declaration_must_be_compile_time_evaluable(
+                expression,
+                expression.span(),
+            ));
+        }
+    }
+
+    /// Returns the first subexpression that cannot be evaluated at compile time, if one exists.
+    fn find_non_const_expression<'b>(&self, expression: &'b Expression) -> Option<&'b Expression> {
+        let is_const = |name: Symbol| {
+            // Unknown variables are reported when the expression is type checked.
+            self.symbol_table.borrow().lookup_variable(name).map_or(true, |var| var.declaration == VariableType::Const)
+        };
+        match expression {
+            Expression::Literal(_) | Expression::Access(AccessExpression::AssociatedConstant(_)) => None,
+            Expression::Identifier(identifier) => (!is_const(identifier.name)).then_some(expression),
+            Expression::Access(AccessExpression::Array(access)) => {
+                self.find_non_const_expression(&access.array).or_else(|| self.find_non_const_expression(&access.index))
+            }
+            Expression::Access(AccessExpression::Member(access)) => self.find_non_const_expression(&access.inner),
+            Expression::Access(AccessExpression::Tuple(access)) => self.find_non_const_expression(&access.tuple),
+            Expression::Array(array) => {
+                array.elements.iter().find_map(|element| self.find_non_const_expression(element))
+            }
+            Expression::Binary(binary) => {
+                self.find_non_const_expression(&binary.left).or_else(|| self.find_non_const_expression(&binary.right))
+            }
+            Expression::Cast(cast) => self.find_non_const_expression(&cast.expression),
+            Expression::Repeat(repeat) => self.find_non_const_expression(&repeat.expr),
+            Expression::Struct(struct_) => struct_.members.iter().find_map(|member| match &member.expression {
+                Some(expression) => self.find_non_const_expression(expression),
+                None => (!is_const(member.identifier.name)).then_some(expression),
+            }),
+            Expression::Ternary(ternary) => self
+                .find_non_const_expression(&ternary.condition)
+                .or_else(|| self.find_non_const_expression(&ternary.if_true))
+                .or_else(|| self.find_non_const_expression(&ternary.if_false)),
+            Expression::Tuple(tuple) => {
+                tuple.elements.iter().find_map(|element| self.find_non_const_expression(element))
+            }
+            Expression::Unary(unary) => self.find_non_const_expression(&unary.receiver),
+            // An enum variant with a payload is constant if its payload is.
+            Expression::Access(AccessExpression::AssociatedFunction(function))
+                if self.lookup_enum_type(&function.ty).is_some() =>
+            {
+                function.arguments.iter().find_map(|argument| self.find_non_const_expression(argument))
+            }
+            // Function calls, core functions, and matches are evaluated at runtime.
+            Expression::Access(AccessExpression::AssociatedFunction(_))
+            | Expression::Call(_)
+            | Expression::Err(_)
+            | Expression::Match(_)
+            | Expression::Unit(_) => Some(expression),
+        }
+    }
 }
 
 fn types_to_string(types: &[Type]) -> String {
@@ -233,12 +233,15 @@ symbols! {
     assert,
     assert_eq,
     assert_neq,
+    Break: "break",
     caller,
     console,
     Const: "const",
     constant,
+    Continue: "continue",
     decrement,
     Else: "else",
+    Enum: "enum",
     finalize,
     For: "for",
     function,
@@ -250,8 +253,10 @@ symbols! {
     input,
     Let: "let",
     leo,
+    log,
     main,
     mapping,
+    Match: "match",
     Mut: "mut",
     Return: "return",
     SelfLower: "self",
@@ -267,9 +272,14 @@ symbols! {
     private,
     owner,
     _nonce,
+    tag,
     program,
     block,
     height,
+
+    // annotations
+    should_fail,
+    test,
 }
 
 /// An interned string.
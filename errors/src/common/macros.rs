@@ -86,6 +86,32 @@ macro_rules! create_messages {
         }
 
 
+        impl $type_ {
+            /// Returns the span of the message, if it has one.
+            pub fn span(&self) -> Option<leo_span::Span> {
+                match self {
+                    Self::Formatted(formatted) => Some(formatted.span),
+                    Self::Backtraced(_) => None,
+                }
+            }
+
+            /// Returns the text of the message, without its code or help.
+            pub fn message(&self) -> &str {
+                match self {
+                    Self::Formatted(formatted) => &formatted.backtrace.message,
+                    Self::Backtraced(backtraced) => &backtraced.message,
+                }
+            }
+
+            /// Returns the help of the message, if it has one.
+            pub fn help(&self) -> Option<&str> {
+                match self {
+                    Self::Formatted(formatted) => formatted.backtrace.help.as_deref(),
+                    Self::Backtraced(backtraced) => backtraced.help.as_deref(),
+                }
+            }
+        }
+
         // Steps over the list of functions with an initial code of 0.
         impl $type_ {
             create_messages!(@step 0i32, $(($(#[$docs])* $formatted_or_backtraced_list, $names($($arg_names: $arg_types,)*), $messages, $helps),)*);
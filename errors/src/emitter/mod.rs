@@ -18,6 +18,8 @@ use crate::LeoWarning;
 
 use super::LeoError;
 use core::{default::Default, fmt};
+use leo_span::{symbol::with_session_globals, Span};
+use serde::Serialize;
 use std::{cell::RefCell, rc::Rc};
 
 /// Types that are sinks for compiler errors.
@@ -53,6 +55,102 @@ impl Emitter for StderrEmitter {
     }
 }
 
+/// An `Emitter` that writes each error and warning to the standard error as a JSON object on its own line.
+#[derive(Default)]
+pub struct JsonEmitter {
+    /// Exit code of the last emitted error.
+    last_error_code: Option<i32>,
+}
+
+impl Emitter for JsonEmitter {
+    fn emit_err(&mut self, err: LeoError) {
+        self.last_error_code = Some(err.exit_code());
+        eprintln!("{}", JsonMessage::from_error(&err));
+    }
+
+    fn last_emitted_err_code(&self) -> Option<i32> {
+        self.last_error_code
+    }
+
+    fn emit_warning(&mut self, warning: LeoWarning) {
+        eprintln!("{}", JsonMessage::from_warning(&warning));
+    }
+}
+
+/// The JSON representation of an error or warning.
+#[derive(Debug, Serialize)]
+pub struct JsonMessage {
+    /// The unique identifier of the message, e.g. `ETYC0372005`.
+    pub code: String,
+    /// Either `error` or `warning`.
+    pub severity: &'static str,
+    /// The text of the message.
+    pub message: String,
+    /// The help of the message, if it has one.
+    pub help: Option<String>,
+    /// The location of the message in the source, if it has one.
+    pub location: Option<JsonLocation>,
+}
+
+/// The JSON representation of the location of an error or warning.
+#[derive(Debug, Serialize)]
+pub struct JsonLocation {
+    /// The name of the file.
+    pub file: String,
+    /// The 1-based line on which the location starts.
+    pub line_start: usize,
+    /// The 1-based line on which the location ends.
+    pub line_stop: usize,
+    /// The 1-based column at which the location starts.
+    pub col_start: usize,
+    /// The 1-based column at which the location ends.
+    pub col_stop: usize,
+}
+
+impl JsonMessage {
+    /// Creates the JSON representation of an error.
+    pub fn from_error(err: &LeoError) -> Self {
+        Self {
+            code: err.error_code(),
+            severity: "error",
+            message: err.message(),
+            help: err.help(),
+            location: err.span().and_then(JsonLocation::from_span),
+        }
+    }
+
+    /// Creates the JSON representation of a warning.
+    pub fn from_warning(warning: &LeoWarning) -> Self {
+        Self {
+            code: warning.error_code(),
+            severity: "warning",
+            message: warning.message(),
+            help: None,
+            location: warning.span().and_then(JsonLocation::from_span),
+        }
+    }
+}
+
+impl fmt::Display for JsonMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&serde_json::to_string(self).map_err(|_| fmt::Error)?)
+    }
+}
+
+impl JsonLocation {
+    /// Resolves the location of `span` in the source map, if it is known.
+    fn from_span(span: Span) -> Option<Self> {
+        let location = with_session_globals(|s| s.source_map.span_to_location(span))?;
+        Some(Self {
+            file: location.source_file.name.to_string(),
+            line_start: location.line_start,
+            line_stop: location.line_stop,
+            col_start: location.col_start,
+            col_stop: location.col_stop,
+        })
+    }
+}
+
 /// A buffer of `T`s.
 #[derive(Debug)]
 pub struct Buffer<T>(Vec<T>);
@@ -259,7 +357,7 @@ impl Handler {
 mod tests {
     use super::*;
     use crate::ParserError;
-    use leo_span::{symbol::create_session_if_not_set_then, Span};
+    use leo_span::{source_map::FileName, span::BytePos, symbol::create_session_if_not_set_then};
 
     #[test]
     fn fresh_no_errors() {
@@ -296,4 +394,21 @@ mod tests {
             Handler::with(|_| Ok(())).unwrap();
         })
     }
+
+    #[test]
+    fn json_message_has_location() {
+        create_session_if_not_set_then(|s| {
+            let source_file = s.source_map.new_source("let a = 1u8;\nlet b = a;", FileName::Custom("test".into()));
+            let span = Span::new(source_file.start_pos + BytePos(17), source_file.start_pos + BytePos(18));
+
+            let message = JsonMessage::from_error(&ParserError::unexpected_eof(span).into());
+            assert_eq!(message.code, "EPAR0370003");
+            assert_eq!(message.severity, "error");
+
+            let location = message.location.unwrap();
+            assert_eq!(location.file, "test");
+            assert_eq!((location.line_start, location.col_start), (2, 5));
+            assert_eq!((location.line_stop, location.col_stop), (2, 6));
+        })
+    }
 }
@@ -145,4 +145,20 @@ create_messages!(
         msg: format!("failed to convert symbol_table to a json value {error}"),
         help: None,
     }
+
+    /// For when a user shadows an enum.
+    @formatted
+    shadowed_enum {
+        args: (enum_: impl Display),
+        msg: format!("enum `{enum_}` shadowed by"),
+        help: None,
+    }
+
+    /// For when a user shadows a type alias.
+    @formatted
+    shadowed_type_alias {
+        args: (alias: impl Display),
+        msg: format!("type alias `{alias}` shadowed by"),
+        help: None,
+    }
 );
@@ -178,4 +178,81 @@ create_messages!(
         msg: format!("Failed to write file.\nIO Error: {error}"),
         help: None,
     }
+
+    @backtraced
+    failed_to_load_private_key {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to load the private key from `{path}`.\nError: {error}"),
+        help: Some("Provide a private key with `--private-key`, or set `PRIVATE_KEY` in the package's `.env` file.".to_string()),
+    }
+
+    @backtraced
+    failed_to_broadcast_deployment {
+        args: (program_id: impl Display, error: impl Display),
+        msg: format!("Failed to broadcast the deployment of `{program_id}`.\nError: {error}"),
+        help: Some("Check that the endpoint is reachable, or use `--dry-run` to build the deployment without broadcasting it.".to_string()),
+    }
+
+    @backtraced
+    failed_to_execute_test {
+        args: (error: impl Display),
+        msg: format!("Failed to execute the `test` command.\nError: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    tests_failed {
+        args: (failed: impl Display, total: impl Display),
+        msg: format!("{failed} of {total} tests failed."),
+        help: None,
+    }
+
+    @backtraced
+    files_not_formatted {
+        args: (files: impl Display),
+        msg: format!("The following files are not formatted: {files}."),
+        help: Some("Run `leo fmt` to format them.".to_string()),
+    }
+
+    @backtraced
+    failed_to_run_language_server {
+        args: (error: impl Display),
+        msg: format!("Failed to run the language server.\nError: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_load_local_ledger {
+        args: (error: impl Display),
+        msg: format!("Failed to load the local ledger.\nError: {error}"),
+        help: Some("Remove the `.leo/ledger/` directory to reset the local ledger.".to_string()),
+    }
+
+    @backtraced
+    finalize_reverted {
+        args: (function: impl Display, error: impl Display),
+        msg: format!("The finalize block of `{function}` was reverted, so the local ledger is unchanged.\nError: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_query_mapping {
+        args: (mapping: impl Display, error: impl Display),
+        msg: format!("Failed to query the mapping `{mapping}`.\nError: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    invalid_mapping_key {
+        args: (key: impl Display, type_: impl Display, error: impl Display),
+        msg: format!("`{key}` is not a valid key of type `{type_}`.\nError: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_read_debugger_input {
+        args: (error: impl Display),
+        msg: format!("Failed to read the input of the debugger.\nError: {error}"),
+        help: None,
+    }
 );
@@ -70,4 +70,49 @@ create_messages!(
         msg: format!("The program scope name `{program_scope_name}` must match `{file_name}`."),
         help: None,
     }
+
+    @formatted
+    test_annotation_outside_tests_directory {
+        args: (annotation: impl Display),
+        msg: format!("The annotation `{annotation}` can only be used in the files of the `tests/` directory."),
+        help: Some("Move the test transition into a file in the `tests/` directory of the package.".to_string()),
+    }
+
+    @backtraced
+    cyclic_imports {
+        args: (path: Vec<impl Display>),
+        msg: {
+            let path_string = path.into_iter().map(|name| format!("`{name}`")).collect::<Vec<String>>().join(" --> ");
+            format!("Cyclic dependency between imported programs: {path_string}")
+        },
+        help: None,
+    }
+
+    @formatted
+    failed_to_parse_aleo_import {
+        args: (file_path: impl Display, error: impl Display),
+        msg: format!("Failed to parse the Aleo instructions of the imported program `{file_path}`: {error}"),
+        help: None,
+    }
+
+    @formatted
+    unsupported_aleo_import {
+        args: (program: impl Display, item: impl Display),
+        msg: format!("The imported program `{program}` uses `{item}`, which cannot be imported into Leo."),
+        help: None,
+    }
+
+    @formatted
+    module_not_found {
+        args: (file_path: impl Display),
+        msg: format!("Attempted to declare a module whose file does not exist `{file_path}`."),
+        help: None,
+    }
+
+    @formatted
+    module_declared_twice {
+        args: (name: impl Display),
+        msg: format!("The module `{name}` is declared more than once."),
+        help: Some("Declare each module once, in the program or in one of its modules.".to_string()),
+    }
 );
@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+use std::fmt::{Debug, Display};
+
+create_messages!(
+    /// ConstantFolderError enum that represents all the errors for the constant folding pass in the `leo-passes` crate.
+    ConstantFolderError,
+    code_mask: 11000i32,
+    code_prefix: "CFL",
+
+    /// For when a constant operation would cause an overflow.
+    @formatted
+    operation_overflow {
+        args: (operation: impl Display),
+        msg: format!("The const operation `{operation}` causes an overflow."),
+        help: None,
+    }
+
+    /// For when a constant operation divides by zero.
+    @formatted
+    division_by_zero {
+        args: (operation: impl Display),
+        msg: format!("The const operation `{operation}` divides by zero."),
+        help: None,
+    }
+
+    /// For when a constant cast does not fit in the target type.
+    @formatted
+    cast_overflow {
+        args: (value: impl Display, type_: impl Display),
+        msg: format!("The const cast `{value} as {type_}` causes an overflow."),
+        help: None,
+    }
+
+    /// For when a constant array index is out of bounds.
+    @formatted
+    array_index_out_of_bounds {
+        args: (index: impl Display, length: impl Display),
+        msg: format!("The index `{index}` is out of bounds for an array of length {length}."),
+        help: None,
+    }
+
+    /// For when an array is written at an index that is not known at compile time.
+    @formatted
+    variable_array_write {
+        args: (),
+        msg: format!("The index of an array write must be constant."),
+        help: Some("Only array reads may use an index that is not known at compile time.".to_string()),
+    }
+);
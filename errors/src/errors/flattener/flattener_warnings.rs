@@ -0,0 +1,34 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+
+use std::fmt::Display;
+
+create_messages!(
+    /// FlattenWarning enum that represents all the warnings for the flattening pass.
+    FlattenWarning,
+    code_mask: 3000i32,
+    code_prefix: "FLA",
+
+    /// For when an array is indexed with a value that is not known at compile time.
+    @formatted
+    non_constant_array_index {
+        args: (length: impl Display),
+        msg: format!("The array index is not constant, so the access is compiled to a chain of {length} selections."),
+        help: Some("Each selection adds constraints to the circuit. Use a constant index where possible.".to_string()),
+    }
+);
@@ -17,3 +17,6 @@
 /// This module contains the Flattener error definitions.
 pub mod flattener_errors;
 pub use self::flattener_errors::*;
+
+pub mod flattener_warnings;
+pub use self::flattener_warnings::*;
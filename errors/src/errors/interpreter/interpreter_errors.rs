@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+use std::fmt::{Debug, Display};
+
+create_messages!(
+    /// InterpreterError enum that represents all the errors for the `leo-interpreter` crate.
+    InterpreterError,
+    code_mask: 10000i32,
+    code_prefix: "INT",
+
+    @formatted
+    assertion_failed {
+        args: (assertion: impl Display),
+        msg: format!("The assertion `{assertion}` failed."),
+        help: None,
+    }
+
+    @formatted
+    assert_eq_failed {
+        args: (left: impl Display, right: impl Display),
+        msg: format!("The assertion failed: `{left}` is not equal to `{right}`."),
+        help: None,
+    }
+
+    @formatted
+    assert_neq_failed {
+        args: (left: impl Display, right: impl Display),
+        msg: format!("The assertion failed: `{left}` is equal to `{right}`."),
+        help: None,
+    }
+
+    @formatted
+    mapping_key_not_found {
+        args: (mapping: impl Display, key: impl Display),
+        msg: format!("The key `{key}` is not in the mapping `{mapping}`."),
+        help: Some("Use `Mapping::get_or_use` to provide a default value.".to_string()),
+    }
+
+    @formatted
+    array_index_out_of_bounds {
+        args: (index: impl Display, length: impl Display),
+        msg: format!("The index `{index}` is out of bounds for an array of length `{length}`."),
+        help: None,
+    }
+
+    @formatted
+    division_by_zero {
+        args: (),
+        msg: "Attempted to divide by zero.".to_string(),
+        help: None,
+    }
+
+    @formatted
+    operation_failed {
+        args: (operation: impl Display, error: impl Display),
+        msg: format!("Failed to evaluate `{operation}`.\nError: {error}"),
+        help: None,
+    }
+
+    @formatted
+    unsupported {
+        args: (construct: impl Display),
+        msg: format!("The interpreter does not support {construct}."),
+        help: None,
+    }
+
+    @formatted
+    undefined_variable {
+        args: (name: impl Display),
+        msg: format!("`{name}` is not defined."),
+        help: None,
+    }
+
+    @backtraced
+    function_not_found {
+        args: (program: impl Display, function: impl Display),
+        msg: format!("`{program}` does not define a function named `{function}`."),
+        help: None,
+    }
+
+    @backtraced
+    wrong_number_of_arguments {
+        args: (function: impl Display, expected: impl Display, found: impl Display),
+        msg: format!("`{function}` expects {expected} argument(s), but {found} were given."),
+        help: None,
+    }
+
+    @backtraced
+    invalid_argument {
+        args: (argument: impl Display, type_: impl Display, error: impl Display),
+        msg: format!("`{argument}` is not a valid argument of type `{type_}`.\nError: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    execution_stopped {
+        args: (),
+        msg: "The execution was stopped by the debugger.".to_string(),
+        help: None,
+    }
+);
@@ -30,6 +30,7 @@ create_messages!(
         help: None,
     }
 
+    // TODO: This error is no longer emitted, since non-constant indices are lowered during flattening.
     @formatted
     variable_array_access {
         args: (),
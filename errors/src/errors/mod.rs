@@ -17,6 +17,8 @@
 /// Contains the ASG error definitions.
 use crate::LeoMessageCode;
 
+use leo_span::Span;
+
 /// Contains the AST error definitions.
 pub mod ast;
 pub use self::ast::*;
@@ -29,6 +31,10 @@ pub use self::cli::*;
 pub mod compiler;
 pub use self::compiler::*;
 
+/// Contains the Constant Folder error definitions.
+pub mod constant_folder;
+pub use self::constant_folder::*;
+
 /// Contains the Flattener error definitions.
 pub mod flattener;
 pub use self::flattener::*;
@@ -37,6 +43,10 @@ pub use self::flattener::*;
 pub mod input;
 pub use self::input::*;
 
+/// Contains the Interpreter error definitions.
+pub mod interpreter;
+pub use self::interpreter::*;
+
 pub mod loop_unroller;
 pub use self::loop_unroller::*;
 
@@ -66,9 +76,15 @@ pub enum LeoError {
     /// Represents an Compiler Error in a Leo Error.
     #[error(transparent)]
     CompilerError(#[from] CompilerError),
+    /// Represents a Constant Folder Error in a Leo Error.
+    #[error(transparent)]
+    ConstantFolderError(#[from] ConstantFolderError),
     /// Represents an Input Error in a Leo Error.
     #[error(transparent)]
     InputError(#[from] InputError),
+    /// Represents an Interpreter Error in a Leo Error.
+    #[error(transparent)]
+    InterpreterError(#[from] InterpreterError),
     /// Represents an Package Error in a Leo Error.
     #[error(transparent)]
     PackageError(#[from] PackageError),
@@ -101,8 +117,10 @@ impl LeoError {
         match self {
             AstError(error) => error.error_code(),
             CompilerError(error) => error.error_code(),
+            ConstantFolderError(error) => error.error_code(),
             CliError(error) => error.error_code(),
             InputError(error) => error.error_code(),
+            InterpreterError(error) => error.error_code(),
             ParserError(error) => error.error_code(),
             PackageError(error) => error.error_code(),
             TypeCheckerError(error) => error.error_code(),
@@ -120,8 +138,10 @@ impl LeoError {
         match self {
             AstError(error) => error.exit_code(),
             CompilerError(error) => error.exit_code(),
+            ConstantFolderError(error) => error.exit_code(),
             CliError(error) => error.exit_code(),
             InputError(error) => error.exit_code(),
+            InterpreterError(error) => error.exit_code(),
             ParserError(error) => error.exit_code(),
             PackageError(error) => error.exit_code(),
             TypeCheckerError(error) => error.exit_code(),
@@ -131,6 +151,67 @@ impl LeoError {
             Anyhow(_) => unimplemented!(), // todo: implement exit codes for snarkvm errors.
         }
     }
+
+    /// Returns the span of the error, if it has one.
+    pub fn span(&self) -> Option<Span> {
+        use LeoError::*;
+
+        match self {
+            AstError(error) => error.span(),
+            CompilerError(error) => error.span(),
+            ConstantFolderError(error) => error.span(),
+            CliError(error) => error.span(),
+            InputError(error) => error.span(),
+            InterpreterError(error) => error.span(),
+            ParserError(error) => error.span(),
+            PackageError(error) => error.span(),
+            TypeCheckerError(error) => error.span(),
+            LoopUnrollerError(error) => error.span(),
+            FlattenError(error) => error.span(),
+            LastErrorCode(_) | Anyhow(_) => None,
+        }
+    }
+
+    /// Returns the text of the error, without its code or help.
+    pub fn message(&self) -> String {
+        use LeoError::*;
+
+        match self {
+            AstError(error) => error.message().to_string(),
+            CompilerError(error) => error.message().to_string(),
+            ConstantFolderError(error) => error.message().to_string(),
+            CliError(error) => error.message().to_string(),
+            InputError(error) => error.message().to_string(),
+            InterpreterError(error) => error.message().to_string(),
+            ParserError(error) => error.message().to_string(),
+            PackageError(error) => error.message().to_string(),
+            TypeCheckerError(error) => error.message().to_string(),
+            LoopUnrollerError(error) => error.message().to_string(),
+            FlattenError(error) => error.message().to_string(),
+            LastErrorCode(_) => String::new(),
+            Anyhow(error) => error.to_string(),
+        }
+    }
+
+    /// Returns the help of the error, if it has one.
+    pub fn help(&self) -> Option<String> {
+        use LeoError::*;
+
+        match self {
+            AstError(error) => error.help().map(str::to_string),
+            CompilerError(error) => error.help().map(str::to_string),
+            ConstantFolderError(error) => error.help().map(str::to_string),
+            CliError(error) => error.help().map(str::to_string),
+            InputError(error) => error.help().map(str::to_string),
+            InterpreterError(error) => error.help().map(str::to_string),
+            ParserError(error) => error.help().map(str::to_string),
+            PackageError(error) => error.help().map(str::to_string),
+            TypeCheckerError(error) => error.help().map(str::to_string),
+            LoopUnrollerError(error) => error.help().map(str::to_string),
+            FlattenError(error) => error.help().map(str::to_string),
+            LastErrorCode(_) | Anyhow(_) => None,
+        }
+    }
 }
 
 /// The LeoWarning type that contains all sub error types.
@@ -140,6 +221,9 @@ pub enum LeoWarning {
     /// Represents an Parser Error in a Leo Error.
     #[error(transparent)]
     ParserWarning(#[from] ParserWarning),
+    /// Represents a Flatten Warning in a Leo Warning.
+    #[error(transparent)]
+    FlattenWarning(#[from] FlattenWarning),
 }
 
 impl LeoWarning {
@@ -149,6 +233,27 @@ impl LeoWarning {
 
         match self {
             ParserWarning(warning) => warning.warning_code(),
+            FlattenWarning(warning) => warning.warning_code(),
+        }
+    }
+
+    /// Returns the span of the warning, if it has one.
+    pub fn span(&self) -> Option<Span> {
+        use LeoWarning::*;
+
+        match self {
+            ParserWarning(warning) => warning.span(),
+            FlattenWarning(warning) => warning.span(),
+        }
+    }
+
+    /// Returns the text of the warning, without its code or help.
+    pub fn message(&self) -> String {
+        use LeoWarning::*;
+
+        match self {
+            ParserWarning(warning) => warning.message().to_string(),
+            FlattenWarning(warning) => warning.message().to_string(),
         }
     }
 }
@@ -300,10 +300,10 @@ create_messages!(
     }
 
     @backtraced
-    source_directory_can_contain_only_one_file {
+    missing_main_file {
         args: (),
-        msg: "The `src/` directory can contain only one file and must be named `main.leo`.".to_string(),
-        help: None,
+        msg: "The `src/` directory must contain a file named `main.leo`.".to_string(),
+        help: Some("The other files of the `src/` directory are modules, declared with `mod <name>;` in the program.".to_string()),
     }
 
     /// For when the environment file has an IO error.
@@ -313,4 +313,132 @@ create_messages!(
         msg: format!("IO error env file from the provided file path - {error}"),
         help: None,
     }
+
+    /// For when a file of the local ledger cannot be parsed.
+    @backtraced
+    failed_to_parse_ledger_file {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to parse the local ledger file `{path}`: {error}"),
+        help: Some("Remove the `.leo/ledger/` directory to reset the local ledger.".to_string()),
+    }
+
+    /// For when a file of the local ledger cannot be written.
+    @backtraced
+    failed_to_write_ledger_file {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to write the local ledger file `{path}`: {error}"),
+        help: None,
+    }
+
+    /// For when the manifest file cannot be parsed.
+    @backtraced
+    failed_to_parse_manifest {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to parse the manifest file `{path}`: {error}"),
+        help: None,
+    }
+
+    /// For when the manifest file cannot be written.
+    @backtraced
+    failed_to_write_manifest {
+        args: (error: impl ErrorArg),
+        msg: format!("Failed to write the manifest file: {error}"),
+        help: None,
+    }
+
+    /// For when a dependency is not found at the path declared for it.
+    @backtraced
+    dependency_not_found {
+        args: (name: impl Display, path: impl Display),
+        msg: format!("The dependency `{name}` was not found at `{path}`."),
+        help: None,
+    }
+
+    /// For when a dependency is neither a Leo package nor an Aleo program.
+    @backtraced
+    invalid_dependency {
+        args: (name: impl Display, path: impl Display),
+        msg: format!("The dependency `{name}` at `{path}` is neither a Leo package nor an `.aleo` file."),
+        help: None,
+    }
+
+    /// For when the program found at the path of a dependency is not the declared program.
+    @backtraced
+    dependency_name_does_not_match {
+        args: (name: impl Display, path: impl Display),
+        msg: format!("The program at `{path}` is not `{name}`."),
+        help: Some("The `program` of a Leo package, or the name of an `.aleo` file, must match the name of the dependency.".to_string()),
+    }
+
+    /// For when two packages depend on the same program at different paths.
+    @backtraced
+    conflicting_dependency {
+        args: (name: impl Display, first: impl Display, second: impl Display),
+        msg: format!("The dependency `{name}` is declared both at `{first}` and at `{second}`."),
+        help: None,
+    }
+
+    /// For when a dependency to remove is not declared in the manifest.
+    @backtraced
+    dependency_not_declared {
+        args: (name: impl Display),
+        msg: format!("The dependency `{name}` is not declared in the manifest."),
+        help: None,
+    }
+
+    /// For when the content of a dependency no longer matches the checksum pinned in the lock file.
+    @backtraced
+    dependency_drift {
+        args: (name: impl Display, path: impl Display),
+        msg: format!("The dependency `{name}` at `{path}` has changed since it was pinned in `leo.lock`."),
+        help: Some(format!("If the change is intended, run `leo add {name} --path {path}` to pin it again.")),
+    }
+
+    /// For when the lock file cannot be parsed.
+    @backtraced
+    failed_to_parse_lock_file {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to parse the lock file `{path}`: {error}"),
+        help: Some("Remove the lock file to pin the dependencies again.".to_string()),
+    }
+
+    /// For when the lock file has an IO error.
+    @backtraced
+    io_error_lock_file {
+        args: (error: impl ErrorArg),
+        msg: format!("IO error lock file from the provided file path - {error}"),
+        help: None,
+    }
+
+    /// For when the workspace manifest cannot be parsed.
+    @backtraced
+    failed_to_parse_workspace {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to parse the workspace manifest `{path}`: {error}"),
+        help: None,
+    }
+
+    /// For when two members of a workspace are the same program.
+    @backtraced
+    duplicate_workspace_member {
+        args: (name: impl Display, first: impl Display, second: impl Display),
+        msg: format!("The program `{name}` is a member of the workspace both at `{first}` and at `{second}`."),
+        help: None,
+    }
+
+    /// For when a command is run for the members of a workspace outside of any workspace.
+    @backtraced
+    not_in_workspace {
+        args: (path: impl Display),
+        msg: format!("The directory `{path}` is not in a workspace."),
+        help: Some("A workspace is a directory whose `workspace.json` file lists its member packages.".to_string()),
+    }
+
+    /// For when a member selected with `--package` is not in the workspace.
+    @backtraced
+    workspace_member_not_found {
+        args: (name: impl Display),
+        msg: format!("The workspace has no member named `{name}`."),
+        help: None,
+    }
 );
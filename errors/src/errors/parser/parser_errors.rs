@@ -217,8 +217,8 @@ create_messages!(
     @formatted
     leo_imports_only {
         args: (),
-        msg: "Invalid import call to non-leo file.",
-        help: Some("Only imports of Leo `.leo` files are currently supported.".to_string()),
+        msg: "Invalid import call to a file which is neither a Leo nor an Aleo file.",
+        help: Some("Only imports of Leo `.leo` files and Aleo `.aleo` files are supported.".to_string()),
     }
 
     @formatted
@@ -298,4 +298,11 @@ create_messages!(
         msg: format!("An array {kind} must have at least one element."),
         help: None,
     }
+
+    @formatted
+    range_loop_variable_must_have_type {
+        args: (variable: impl Display),
+        msg: format!("The loop variable `{variable}` of a loop over a range must have a type annotation."),
+        help: Some("Annotate the loop variable with its type, e.g. `for i: u32 in 0u32..4u32`.".to_string()),
+    }
 );
@@ -262,6 +262,8 @@ create_messages!(
         help: Some("Remove the unreachable code.".to_string()),
     }
 
+    // TODO: This error is no longer emitted, since return statements inside loops are lowered during loop unrolling.
+    // It is kept so that the codes of the errors that follow it do not change.
     @formatted
     loop_body_contains_return {
         args: (),
@@ -440,6 +442,8 @@ create_messages!(
         help: None,
     }
 
+    // TODO: This error is no longer emitted, since imported programs can import other programs.
+    // It is kept so that the codes of the errors that follow it do not change.
     @formatted
     imported_program_cannot_import_program {
         args: (),
@@ -657,6 +661,8 @@ create_messages!(
         help: None,
     }
 
+    // TODO: This error is no longer emitted, since const declarations may be any compile-time evaluable expression.
+    // It is kept so that the codes of the errors that follow it do not change.
     @formatted
     const_declaration_must_be_literal_or_tuple_of_literals {
         args: (),
@@ -712,4 +718,171 @@ create_messages!(
         msg: format!("An array cannot have a record as an element type"),
         help: None,
     }
+
+    @formatted
+    annotation_requires_transition {
+        args: (annotation: impl Display),
+        msg: format!("The annotation `{annotation}` can only be applied to a transition."),
+        help: None,
+    }
+
+    @formatted
+    test_function_cannot_have_inputs {
+        args: (function: impl Display),
+        msg: format!("The test transition `{function}` cannot have inputs."),
+        help: Some("Test transitions are invoked by `leo test` without arguments.".to_string()),
+    }
+
+    @formatted
+    should_fail_requires_test {
+        args: (),
+        msg: format!("The `@should_fail` annotation can only be applied to a transition annotated with `@test`."),
+        help: None,
+    }
+
+    @formatted
+    console_log_wrong_number_of_arguments {
+        args: (placeholders: impl Display, arguments: impl Display),
+        msg: format!("The format string has {placeholders} `{{}}` placeholder(s), but {arguments} argument(s) were given."),
+        help: None,
+    }
+
+    @formatted
+    const_declaration_must_be_compile_time_evaluable {
+        args: (expression: impl Display),
+        msg: format!("The value of a const declaration must be evaluable at compile time, but `{expression}` is not."),
+        help: Some("Const declarations may only use literals, other consts, and operators over them.".to_string()),
+    }
+
+    @backtraced
+    cyclic_const_dependency {
+        args: (path: Vec<impl Display>),
+        msg: {
+            let path_string = path.into_iter().map(|name| format!("`{name}`")).collect::<Vec<String>>().join(" --> ");
+            format!("Cyclic dependency between consts: {path_string}")
+        },
+        help: None,
+    }
+
+    @formatted
+    loop_control_outside_of_loop {
+        args: (statement: impl Display),
+        msg: format!("`{statement}` can only be used inside the body of a `for` loop."),
+        help: None,
+    }
+
+    @formatted
+    enum_must_have_variants {
+        args: (enum_: impl Display),
+        msg: format!("The enum `{enum_}` must have at least one variant."),
+        help: None,
+    }
+
+    @formatted
+    duplicate_enum_variant {
+        args: (enum_: impl Display, variant: impl Display),
+        msg: format!("The enum `{enum_}` has more than one variant named `{variant}`."),
+        help: None,
+    }
+
+    @formatted
+    enum_variant_cannot_be_named_tag {
+        args: (enum_: impl Display),
+        msg: format!("The enum `{enum_}` cannot have a variant named `tag`."),
+        help: Some("The name `tag` is reserved for the member that stores the variant of an enum value.".to_string()),
+    }
+
+    @formatted
+    too_many_enum_variants {
+        args: (enum_: impl Display, max: impl Display),
+        msg: format!("The enum `{enum_}` has more than the maximum of {max} variants."),
+        help: None,
+    }
+
+    @formatted
+    invalid_enum_payload_type {
+        args: (type_: impl Display),
+        msg: format!("An enum variant cannot carry a payload of type `{type_}`."),
+        help: Some("Payloads cannot be tuples, records, or signatures, nor contain signatures.".to_string()),
+    }
+
+    @formatted
+    undefined_enum_variant {
+        args: (enum_: impl Display, variant: impl Display),
+        msg: format!("The enum `{enum_}` has no variant named `{variant}`."),
+        help: None,
+    }
+
+    @formatted
+    enum_variant_expects_payload {
+        args: (enum_: impl Display, variant: impl Display),
+        msg: format!("The variant `{enum_}::{variant}` carries a payload, which must be given as `{enum_}::{variant}(value)`."),
+        help: None,
+    }
+
+    @formatted
+    enum_variant_has_no_payload {
+        args: (enum_: impl Display, variant: impl Display),
+        msg: format!("The variant `{enum_}::{variant}` does not carry a payload."),
+        help: None,
+    }
+
+    @formatted
+    invalid_match_type {
+        args: (type_: impl Display),
+        msg: format!("Cannot match on a value of type `{type_}`."),
+        help: Some("Only values of an enum, integer, or boolean type can be matched.".to_string()),
+    }
+
+    @formatted
+    match_pattern_type_mismatch {
+        args: (expected: impl Display, found: impl Display),
+        msg: format!("Expected a variant of the enum `{expected}`, but found a variant of `{found}`."),
+        help: None,
+    }
+
+    @formatted
+    unreachable_match_arm {
+        args: (),
+        msg: format!("This arm of the `match` is unreachable, since the previous arms cover its pattern."),
+        help: None,
+    }
+
+    @formatted
+    non_exhaustive_match {
+        args: (missing: impl Display),
+        msg: format!("The `match` does not cover the pattern(s) {missing}."),
+        help: Some("Add an arm for each missing pattern, or a wildcard arm `_ => ...`.".to_string()),
+    }
+
+    @formatted
+    invalid_match_pattern {
+        args: (pattern: impl Display, type_: impl Display),
+        msg: format!("The pattern `{pattern}` cannot match a value of type `{type_}`."),
+        help: None,
+    }
+
+    @formatted
+    match_pattern_must_be_constant {
+        args: (pattern: impl Display),
+        msg: format!("The pattern `{pattern}` is not a literal or a constant."),
+        help: Some("Bind the value to a `const` to use it as a pattern.".to_string()),
+    }
+
+    @formatted
+    or_pattern_cannot_bind {
+        args: (pattern: impl Display),
+        msg: format!("The pattern `{pattern}` binds a variable, which is not allowed in alternatives separated by `|`."),
+        help: None,
+    }
+
+    @backtraced
+    cyclic_type_alias {
+        args: (path: Vec<impl Display>),
+        msg: {
+            let path_string = path.into_iter().map(|name| format!("`{name}`")).collect::<Vec<String>>().join(" --> ");
+            format!("Cyclic dependency between type aliases: {path_string}")
+        },
+        help: None,
+    }
 );
@@ -15,7 +15,8 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::cli::{commands::*, context::*, helpers::*};
-use leo_errors::Result;
+use leo_errors::{LeoError, PackageError, Result};
+use leo_package::workspace::Workspace;
 
 use clap::Parser;
 use colored::Colorize;
@@ -36,6 +37,15 @@ pub struct CLI {
 
     #[clap(long, global = true, help = "Optional path to Leo program root folder")]
     path: Option<PathBuf>,
+
+    #[clap(long, global = true, value_enum, default_value = "human", help = "The format of errors and warnings")]
+    message_format: MessageFormat,
+
+    #[clap(long, global = true, help = "Build, run or test every member of the workspace, in dependency order")]
+    workspace: bool,
+
+    #[clap(short = 'p', long = "package", global = true, help = "Build, run or test the given workspace member")]
+    packages: Vec<String>,
 }
 
 ///Leo compiler and package manager
@@ -56,6 +66,16 @@ enum Commands {
         #[clap(subcommand)]
         command: Example,
     },
+    #[clap(about = "Add a dependency to the current package")]
+    Add {
+        #[clap(flatten)]
+        command: Add,
+    },
+    #[clap(about = "Remove a dependency from the current package")]
+    Remove {
+        #[clap(flatten)]
+        command: Remove,
+    },
     #[clap(about = "Compile the current package as a program")]
     Build {
         #[clap(flatten)]
@@ -66,6 +86,16 @@ enum Commands {
         #[clap(flatten)]
         command: Clean,
     },
+    #[clap(about = "Format the Leo source files of the current package")]
+    Fmt {
+        #[clap(flatten)]
+        command: Format,
+    },
+    #[clap(about = "Run a language server for Leo over stdio")]
+    Lsp {
+        #[clap(flatten)]
+        command: Lsp,
+    },
     #[clap(about = "Run a program with input variables")]
     Run {
         #[clap(flatten)]
@@ -76,6 +106,26 @@ enum Commands {
         #[clap(flatten)]
         command: Execute,
     },
+    #[clap(about = "Deploy a program to the network")]
+    Deploy {
+        #[clap(flatten)]
+        command: Deploy,
+    },
+    #[clap(about = "Query the state of a program")]
+    Query {
+        #[clap(subcommand)]
+        command: Query,
+    },
+    #[clap(about = "Debug the current package in a Leo interpreter")]
+    Debug {
+        #[clap(flatten)]
+        command: Debugger,
+    },
+    #[clap(about = "Run the tests of the current package")]
+    Test {
+        #[clap(flatten)]
+        command: Test,
+    },
     #[clap(about = "Update the Leo CLI")]
     Update {
         #[clap(flatten)]
@@ -87,7 +137,10 @@ pub fn handle_error<T>(res: Result<T>) -> T {
     match res {
         Ok(t) => t,
         Err(err) => {
-            eprintln!("{err}");
+            // Errors which were already emitted only carry their exit code.
+            if !matches!(err, LeoError::LastErrorCode(_)) {
+                eprintln!("{err}");
+            }
             exit(err.exit_code());
         }
     }
@@ -95,7 +148,8 @@ pub fn handle_error<T>(res: Result<T>) -> T {
 
 /// Run command with custom build arguments.
 pub fn run_with_args(cli: CLI) -> Result<()> {
-    if !cli.quiet {
+    // The language server communicates over stdout, so the logger is not initialized for it.
+    if !cli.quiet && !matches!(cli.command, Commands::Lsp { .. }) {
         // Init logger with optional debug flag.
         logger::init_logger("leo", match cli.debug {
             false => 1,
@@ -105,11 +159,17 @@ pub fn run_with_args(cli: CLI) -> Result<()> {
 
     // Get custom root folder and create context for it.
     // If not specified, default context will be created in cwd.
-    let context = handle_error(Context::new(cli.path));
+    let context = handle_error(Context::new(cli.path, cli.message_format));
+    let handler = context.handler();
+
+    // Packages are only selected among the members of a workspace.
+    let in_workspace = cli.workspace || !cli.packages.is_empty();
 
-    match cli.command {
+    let result = match cli.command {
         Commands::Account { command } => command.try_execute(context),
         Commands::New { command } => command.try_execute(context),
+        Commands::Add { command } => command.try_execute(context),
+        Commands::Remove { command } => command.try_execute(context),
         Commands::Build { command } => {
             // Enter tracing span
             let span = command.log_span();
@@ -124,12 +184,45 @@ pub fn run_with_args(cli: CLI) -> Result<()> {
             // Drop tracing span
             drop(span);
 
-            command.try_execute(context)
+            match in_workspace {
+                true => for_each_member(command, context, &cli.packages),
+                false => command.try_execute(context),
+            }
         }
         Commands::Clean { command } => command.try_execute(context),
         Commands::Example { command } => command.try_execute(context),
+        Commands::Fmt { command } => command.try_execute(context),
+        Commands::Lsp { command } => command.try_execute(context),
+        Commands::Run { command } if in_workspace => for_each_member(command, context, &cli.packages),
         Commands::Run { command } => command.try_execute(context),
         Commands::Execute { command } => command.try_execute(context),
+        Commands::Deploy { command } => command.try_execute(context),
+        Commands::Query { command } => command.try_execute(context),
+        Commands::Debug { command } => command.try_execute(context),
+        Commands::Test { command } if in_workspace => for_each_member(command, context, &cli.packages),
+        Commands::Test { command } => command.try_execute(context),
         Commands::Update { command } => command.try_execute(context),
+    };
+
+    // Errors which were not emitted through a handler are emitted in the requested format as well.
+    match result {
+        Err(err) if cli.message_format == MessageFormat::Json && !matches!(err, LeoError::LastErrorCode(_)) => {
+            let code = err.exit_code();
+            handler.emit_err(err);
+            Err(LeoError::LastErrorCode(code))
+        }
+        result => result,
+    }
+}
+
+/// Runs the command for the members of the workspace containing the package, in dependency order.
+/// If any package is given, the command is only run for those members.
+pub(crate) fn for_each_member<C: Command + Clone>(command: C, context: Context, packages: &[String]) -> Result<()> {
+    let path = context.dir()?;
+    let root = Workspace::find_root(&path).ok_or_else(|| PackageError::not_in_workspace(path.display()))?;
+    for (program_id, location) in workspace::workspace_members(&root, packages, &context.handler())? {
+        tracing::info!("📦 Member '{program_id}'");
+        command.clone().try_execute(Context { path: Some(location), ..context.clone() })?;
     }
+    Ok(())
 }
@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_package::dependencies::{Dependencies, Dependency, DependencyKind, LockFile};
+
+use std::path::PathBuf;
+
+/// Add a dependency to the current package.
+#[derive(Parser, Debug)]
+pub struct Add {
+    #[clap(name = "NAME", help = "The program id of the dependency, e.g. `token.aleo`")]
+    pub(crate) name: String,
+
+    #[clap(long, help = "Path to the Leo package or the `.aleo` file of the dependency, relative to the package")]
+    pub(crate) path: PathBuf,
+}
+
+impl Command for Add {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let package_path = context.dir()?;
+        let name = Dependencies::program_id(&self.name);
+
+        // Check that the dependency is found before declaring it.
+        let kind = Dependencies::locate(&name, &package_path.join(&self.path))?;
+
+        // Declare the dependency in the manifest, replacing any previous declaration.
+        let previous = Dependencies::read_from(&package_path)?;
+        let mut dependencies = previous.clone();
+        dependencies.insert(name.clone(), Dependency { path: self.path.clone() });
+        Dependencies::write_to(&package_path, &dependencies)?;
+
+        // Pin the checksum of the dependency, and of the dependencies it brings along.
+        // The manifest is restored if the dependencies cannot be resolved.
+        let lock_file = Dependencies::resolve(&package_path)
+            .and_then(|resolved| LockFile::read_from(&package_path)?.update(&resolved, &[&name]));
+        match lock_file {
+            Ok(lock_file) => lock_file.write_to(&package_path)?,
+            Err(err) => {
+                Dependencies::write_to(&package_path, &previous)?;
+                return Err(err);
+            }
+        }
+
+        let kind = match kind {
+            DependencyKind::Leo => "Leo package",
+            DependencyKind::Aleo => "Aleo program",
+        };
+        tracing::info!("✅ Added the {kind} '{name}' {}", format!("(at \"{}\")", self.path.display()).dimmed());
+
+        Ok(())
+    }
+}
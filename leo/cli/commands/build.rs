@@ -16,15 +16,18 @@
 
 use super::*;
 
-use leo_ast::{NodeBuilder, Struct};
+use leo_ast::{NodeBuilder, Program, Struct};
 use leo_compiler::{Compiler, CompilerOptions, InputAst, OutputOptions};
 use leo_package::{
     build::BuildDirectory,
+    dependencies::{Dependencies, DependencyKind, LockFile, ResolvedDependency},
     imports::ImportsDirectory,
     inputs::InputFile,
-    outputs::OutputsDirectory,
-    source::SourceDirectory,
+    outputs::{ChecksumFile, OutputsDirectory},
+    source::{SourceDirectory, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+    workspace::Workspace,
 };
+use leo_passes::{ImportResolver, ModuleResolver, Pass};
 use leo_span::{symbol::with_session_globals, Symbol};
 
 use snarkvm::{
@@ -33,6 +36,8 @@ use snarkvm::{
 };
 
 use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::{
     io::Write,
     path::{Path, PathBuf},
@@ -55,6 +60,7 @@ impl From<BuildOptions> for CompilerOptions {
                 flattened_ast: options.enable_flattened_ast_snapshot,
                 destructured_ast: options.enable_destructured_ast_snapshot,
                 inlined_ast: options.enable_inlined_ast_snapshot,
+                cse_ast: options.enable_cse_ast_snapshot,
                 dce_ast: options.enable_dce_ast_snapshot,
             },
         };
@@ -66,6 +72,7 @@ impl From<BuildOptions> for CompilerOptions {
             out_options.output.flattened_ast = true;
             out_options.output.destructured_ast = true;
             out_options.output.inlined_ast = true;
+            out_options.output.cse_ast = true;
             out_options.output.dce_ast = true;
         }
 
@@ -74,7 +81,7 @@ impl From<BuildOptions> for CompilerOptions {
 }
 
 /// Compile and build program command.
-#[derive(Parser, Debug)]
+#[derive(Parser, Clone, Debug)]
 pub struct Build {
     #[clap(flatten)]
     pub(crate) options: BuildOptions,
@@ -82,7 +89,7 @@ pub struct Build {
 
 impl Command for Build {
     type Input = ();
-    type Output = (Option<InputAst>, IndexMap<Symbol, Struct>);
+    type Output = (Option<InputAst>, IndexMap<Symbol, Struct>, Program);
 
     fn log_span(&self) -> Span {
         tracing::span!(tracing::Level::INFO, "Leo")
@@ -101,62 +108,89 @@ impl Command for Build {
         let program_id = manifest.program_id();
 
         // Create the outputs directory.
-        let outputs_directory = OutputsDirectory::create(&package_path)?;
+        let outputs_directory = create_outputs_directory(&package_path)?;
 
         // Open the build directory.
         let build_directory = BuildDirectory::open(&package_path)?;
 
         // Initialize error handler
-        let handler = Handler::default();
+        let handler = context.handler();
 
         // Initialize a node counter.
         let node_builder = NodeBuilder::default();
 
+        // Resolve the dependencies declared in the manifest, and check that none has drifted from the lock file.
+        let dependencies = Dependencies::resolve(&package_path)?;
+        let lock_file = LockFile::read_from(&package_path)?;
+        let updated_lock_file = lock_file.update(&dependencies, &[])?;
+        if updated_lock_file != lock_file {
+            updated_lock_file.write_to(&package_path)?;
+        }
+        let import_paths = import_paths(&package_path, &dependencies)?;
+
         // Fetch paths to all .leo files in the source directory.
         let source_files = SourceDirectory::files(&package_path)?;
 
-        // Check the source files.
-        SourceDirectory::check_files(&source_files)?;
+        // Check the source files, and get the main file. The other source files are modules of the program.
+        let main_file_path = SourceDirectory::check_files(&source_files)?;
 
-        // Store all struct declarations made in the source files.
+        // Store all struct declarations made in the program, its modules and its imports.
         let mut structs = IndexMap::new();
 
-        // Compile all .leo files into .aleo files.
-        for file_path in source_files.into_iter() {
-            structs.extend(compile_leo_file(
-                file_path,
-                &package_path,
-                program_id,
-                &outputs_directory,
-                &build_directory,
-                &handler,
-                self.options.clone(),
-                false,
-            )?);
-        }
+        // Collect the programs imported by the program, transitively.
+        // Note that import resolution orders them so that each program comes after the programs it imports.
+        let imports = parse_program(&main_file_path, &package_path, &import_paths, &handler)?.imports;
 
-        if !ImportsDirectory::is_empty(&package_path)? {
+        // Pre-compiled dependencies are copied into the build/imports/ directory as they are.
+        let aleo_dependencies = dependencies.iter().filter(|(_, dependency)| dependency.kind == DependencyKind::Aleo);
+
+        if !imports.is_empty() || aleo_dependencies.clone().next().is_some() {
             // Create Aleo build/imports/ directory.
             let build_imports_directory = ImportsDirectory::create(&build_directory)?;
 
-            // Fetch paths to all .leo files in the imports directory.
-            let import_files = ImportsDirectory::files(&package_path)?;
+            for (name, dependency) in aleo_dependencies {
+                std::fs::copy(dependency.source_file(), build_imports_directory.join(name))
+                    .map_err(|err| PackageError::failed_to_read_file(dependency.location.display(), err))?;
+            }
 
-            // Compile all .leo files into .aleo files.
-            for file_path in import_files.into_iter() {
-                structs.extend(compile_leo_file(
-                    file_path,
+            // Compile each imported program into an .aleo file, once, in dependency order.
+            // Programs imported from their Aleo instructions are copied as they are.
+            for import in imports.into_keys() {
+                let import_path = ImportResolver::import_file_path(import, &package_path, &import_paths);
+                if ImportResolver::is_aleo_file(&import_path) {
+                    std::fs::copy(&import_path, build_imports_directory.join(format!("{import}.aleo")))
+                        .map_err(|err| PackageError::failed_to_read_file(import_path.display(), err))?;
+                    continue;
+                }
+                let (import_structs, _) = compile_leo_file(
+                    import_path,
                     &package_path,
+                    &import_paths,
                     program_id,
                     &outputs_directory,
                     &build_imports_directory,
                     &handler,
                     self.options.clone(),
-                    true,
-                )?);
+                    Some(import),
+                )?;
+                structs.extend(import_structs);
             }
         }
 
+        // Compile the program, along with its modules, into an .aleo file.
+        let (program_structs, program) = compile_leo_file(
+            main_file_path,
+            &package_path,
+            &import_paths,
+            program_id,
+            &outputs_directory,
+            &build_directory,
+            &handler,
+            self.options.clone(),
+            None,
+        )?;
+        structs.extend(program_structs);
+
         // Load the input file at `package_name.in`
         let input_file_path = InputFile::new(&manifest.program_id().name().to_string()).setup_file_path(&package_path);
 
@@ -195,49 +229,81 @@ impl Command for Build {
         // // Log the result of the build
         // tracing::info!("{}", result);
 
-        Ok((input_ast, structs))
+        Ok((input_ast, structs, program))
     }
 }
 
 /// Compiles a Leo file in the `src/` directory.
+/// Returns the structs of the program and its imports, and the AST of the program once it is type checked.
 #[allow(clippy::too_many_arguments)]
 fn compile_leo_file(
     file_path: PathBuf,
-    _package_path: &Path,
+    package_path: &Path,
+    import_paths: &IndexMap<Symbol, PathBuf>,
     program_id: &ProgramID<Testnet3>,
     outputs: &Path,
     build: &Path,
     handler: &Handler,
     options: BuildOptions,
-    is_import: bool,
-) -> Result<IndexMap<Symbol, Struct>> {
+    import: Option<Symbol>,
+) -> Result<(IndexMap<Symbol, Struct>, Program)> {
     // Construct the Leo file name with extension `foo.leo`.
     let file_name =
         file_path.file_name().and_then(|name| name.to_str()).ok_or_else(PackageError::failed_to_get_file_name)?;
 
-    // If the program is an import, construct program name from file_path
+    // If the program is an import, use the name under which it is imported.
     // Otherwise, use the program_id found in `package.json`.
-    let program_name = match is_import {
-        false => program_id.name().to_string(),
-        true => file_name.strip_suffix(".leo").ok_or_else(PackageError::failed_to_get_file_name)?.to_string(),
+    let program_name = match import {
+        None => program_id.name().to_string(),
+        Some(import) => import.to_string(),
     };
 
     // Create the path to the Aleo file.
     let mut aleo_file_path = build.to_path_buf();
-    aleo_file_path.push(match is_import {
-        true => format!("{program_name}.{}", program_id.network()),
-        false => format!("main.{}", program_id.network()),
+    aleo_file_path.push(match import {
+        Some(_) => format!("{program_name}.{}", program_id.network()),
+        None => format!("main.{}", program_id.network()),
     });
 
     // Create a new instance of the Leo compiler.
     let mut compiler = Compiler::new(
-        program_name,
+        program_name.clone(),
         program_id.network().to_string(),
         handler,
         file_path.clone(),
         outputs.to_path_buf(),
-        Some(options.into()),
+        Some(options.clone().into()),
     );
+    compiler.import_paths = import_paths.clone();
+
+    // Reuse the Aleo instructions of the previous build if the program and its imports are unchanged.
+    // Note that the record of an import is named after the importing program, since the members of a workspace
+    // share the `outputs/` directory and each compile their imports into their own `build/` directory.
+    let checksum_file = ChecksumFile::new(&match import {
+        Some(_) => format!("{}.{program_name}", program_id.name()),
+        None => program_name,
+    });
+    if aleo_file_path.exists() {
+        if let Ok(previous) = checksum_file.read_from(outputs) {
+            if let Ok(previous) = serde_json::from_str::<BuildRecord>(&previous) {
+                let modules = previous.program.modules.keys().cloned();
+                let imports = previous
+                    .imports
+                    .iter()
+                    .map(|(name, import)| (name.clone(), import.modules.keys().cloned().collect()));
+                let current = BuildRecord::new(&file_path, package_path, import_paths, &options, modules, imports)?;
+                if previous == current {
+                    // The program is still type checked, for its structs and its AST.
+                    compiler.parse_program()?;
+                    let symbol_table = compiler.symbol_table_pass()?;
+                    let symbol_table = compiler.type_alias_resolution_pass(symbol_table)?;
+                    let (symbol_table, ..) = compiler.type_checker_pass(symbol_table)?;
+                    tracing::info!("✅ Reused the Aleo instructions of '{}' (unchanged)", file_name);
+                    return Ok((symbol_table.structs, compiler.ast.as_repr().clone()));
+                }
+            }
+        }
+    }
 
     // Compile the Leo program into Aleo instructions.
     let (symbol_table, instructions) = compiler.compile()?;
@@ -248,6 +314,144 @@ fn compile_leo_file(
         .write_all(instructions.as_bytes())
         .map_err(CliError::failed_to_load_instructions)?;
 
+    // Record the checksums of the program, its modules and its imports for the next build.
+    let program = compiler.ast.as_repr();
+    let imports = program.imports.iter().map(|(name, (import, _))| (name.to_string(), module_names(import)));
+    let record = BuildRecord::new(&file_path, package_path, import_paths, &options, module_names(program), imports)?;
+    let record = serde_json::to_string_pretty(&record).map_err(PackageError::io_error_checksum_file)?;
+    checksum_file.write_to(outputs, record)?;
+
     tracing::info!("✅ Compiled '{}' into Aleo instructions", file_name);
-    Ok(symbol_table.structs)
+    // Note that this unwrap is safe, since the compiler keeps the type checked AST when it compiles the program.
+    Ok((symbol_table.structs, compiler.type_checked_ast.take().unwrap().into_repr()))
+}
+
+/// The checksums of everything a compiled program depends on.
+/// It is recorded in the `outputs/` directory, so that unchanged programs are not recompiled.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct BuildRecord {
+    /// The version of the Leo compiler.
+    leo_version: String,
+    /// The build options.
+    options: String,
+    /// The checksums of the source files of the program.
+    program: SourceRecord,
+    /// The checksums of the source files of the programs imported by the program, transitively.
+    imports: IndexMap<String, SourceRecord>,
+}
+
+/// The checksums of the source file of a program and of the source files of its modules.
+/// Files which no longer exist have an empty checksum.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct SourceRecord {
+    /// The checksum of the source file of the program.
+    source: String,
+    /// The checksums of the source files of the modules of the program, transitively.
+    modules: IndexMap<String, String>,
+}
+
+impl BuildRecord {
+    /// Computes the record of the given source file, modules and imports, along with the modules of each import.
+    fn new(
+        file_path: &Path,
+        package_path: &Path,
+        import_paths: &IndexMap<Symbol, PathBuf>,
+        options: &BuildOptions,
+        modules: impl IntoIterator<Item = String>,
+        imports: impl IntoIterator<Item = (String, Vec<String>)>,
+    ) -> Result<Self> {
+        let imports = imports
+            .into_iter()
+            .map(|(name, modules)| {
+                let path = ImportResolver::import_file_path(Symbol::intern(&name), package_path, import_paths);
+                Ok((name, SourceRecord::new(&path, modules)?))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            leo_version: env!("CARGO_PKG_VERSION").to_string(),
+            options: format!("{options:?}"),
+            program: SourceRecord::new(file_path, modules)?,
+            imports,
+        })
+    }
+}
+
+impl SourceRecord {
+    /// Computes the record of the given source file and modules.
+    fn new(file_path: &Path, modules: impl IntoIterator<Item = String>) -> Result<Self> {
+        // Note that the modules of a program are in the directory of its source file.
+        let modules = modules
+            .into_iter()
+            .map(|name| {
+                let path = file_path.with_file_name(format!("{name}.leo"));
+                Ok((name, checksum(&path)?))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { source: checksum(file_path)?, modules })
+    }
+}
+
+/// Returns the SHA256 checksum of the file, or an empty checksum if it does not exist.
+fn checksum(path: &Path) -> Result<String> {
+    if !path.exists() {
+        return Ok(String::new());
+    }
+    let bytes = std::fs::read(path).map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
+    Ok(format!("{:x}", Sha256::digest(bytes)))
+}
+
+/// Returns the names of the modules of the program, declared directly or not.
+fn module_names(program: &Program) -> Vec<String> {
+    program.program_scopes.values().flat_map(|scope| scope.modules.iter().map(|(name, _)| name.to_string())).collect()
+}
+
+/// Returns the paths to the sources of the given dependencies of the package, by program name.
+/// The source of a Leo package is its Leo source, and that of a compiled program is its Aleo instructions.
+/// If the package is in a workspace, the other members are imported from their Leo sources as well,
+/// unless they are declared as dependencies.
+pub(crate) fn import_paths(
+    package_path: &Path,
+    dependencies: &IndexMap<String, ResolvedDependency>,
+) -> Result<IndexMap<Symbol, PathBuf>> {
+    let mut import_paths: IndexMap<Symbol, PathBuf> = dependencies
+        .iter()
+        .map(|(name, dependency)| (Symbol::intern(name.trim_end_matches(".aleo")), dependency.source_file()))
+        .collect();
+
+    if let Some(root) = Workspace::find_root(package_path) {
+        for (name, location) in Workspace::read_from(&root)?.members(&root)? {
+            import_paths
+                .entry(Symbol::intern(name.trim_end_matches(".aleo")))
+                .or_insert_with(|| location.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME));
+        }
+    }
+
+    Ok(import_paths)
+}
+
+/// Creates the `outputs/` directory of the package, and returns its path.
+/// The members of a workspace share the `outputs/` directory at the root of the workspace.
+pub(crate) fn create_outputs_directory(package_path: &Path) -> Result<PathBuf> {
+    match Workspace::find_root(package_path) {
+        Some(root) => OutputsDirectory::create(&root),
+        None => OutputsDirectory::create(package_path),
+    }
+}
+
+/// Parses a Leo file along with its modules, and loads the programs it imports, transitively, from the package and its dependencies.
+fn parse_program(
+    file_path: &Path,
+    package_path: &Path,
+    import_paths: &IndexMap<Symbol, PathBuf>,
+    handler: &Handler,
+) -> Result<Program> {
+    let source_file = with_session_globals(|s| s.source_map.load_file(file_path))
+        .map_err(|e| CompilerError::file_read_error(file_path, e))?;
+    let node_builder = NodeBuilder::default();
+    let ast = leo_parser::parse_ast(handler, &node_builder, &source_file.src, source_file.start_pos)?;
+    // Note that the path of a loaded file always has a parent.
+    let ast = ModuleResolver::do_pass((ast, handler, &node_builder, file_path.parent().unwrap()))?;
+    Ok(ImportResolver::do_pass((ast, handler, &node_builder, package_path, import_paths))?.into_repr())
 }
@@ -16,6 +16,8 @@
 
 use super::*;
 
+use leo_package::workspace::Workspace;
+
 /// Clean outputs folder command
 #[derive(Parser, Debug)]
 pub struct Clean {}
@@ -35,8 +37,8 @@ impl Command for Clean {
     fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
         let path = context.dir()?;
 
-        // Removes the outputs/ directory.
-        let outputs_path = OutputsDirectory::remove(&path)?;
+        // Removes the outputs/ directory, which is shared by the members of a workspace.
+        let outputs_path = OutputsDirectory::remove(&Workspace::find_root(&path).unwrap_or_else(|| path.clone()))?;
         tracing::info!("🧹 Cleaned the outputs directory {}", outputs_path.dimmed());
 
         // Removes the build/ directory.
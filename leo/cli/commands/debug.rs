@@ -0,0 +1,354 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    build::{create_outputs_directory, import_paths},
+    *,
+};
+
+use leo_ast::{ConsoleStatement, ExpressionVisitor, Node, Program, ProgramVisitor, Statement, StatementVisitor, Value};
+use leo_compiler::Compiler;
+use leo_errors::InterpreterError;
+use leo_interpreter::{format_value, Frame, Hook, Interpreter};
+use leo_package::{
+    dependencies::Dependencies,
+    source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+use leo_span::{symbol::with_session_globals, Span as SourceSpan, Symbol};
+
+use snarkvm::prelude::{Address, ProgramID};
+
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+const HELP: &str = "Commands:
+  call <transition> <arguments>  Calls a transition of the program, e.g. `call main 1u32 2`
+  break [<file>:]<line>          Pauses before the statements on the line
+  delete [<number>]              Deletes a breakpoint, or every breakpoint
+  breakpoints                    Lists the breakpoints
+  mappings                       Prints the entries of the mappings
+  quit                           Exits the debugger
+
+While paused:
+  step, s                        Executes the next statement, stepping into calls
+  next, n                        Executes the next statement, stepping over calls
+  continue, c                    Continues until the next breakpoint
+  print, p <variable>            Prints a variable
+  locals                         Prints the variables in scope
+  backtrace, bt                  Prints the call stack";
+
+/// Debugs the current package in a Leo interpreter
+#[derive(Parser, Debug)]
+pub struct Debugger {}
+
+impl Command for Debugger {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let package_path = context.dir()?;
+        let manifest = context.open_manifest()?;
+        let program_id = manifest.program_id();
+
+        // Imports are resolved relative to the current directory.
+        std::env::set_current_dir(&package_path)
+            .map_err(|err| PackageError::failed_to_set_cwd(package_path.display(), err))?;
+
+        let program = type_checked_program(&package_path, program_id, &[], &context.handler())?;
+        let mut interpreter = Interpreter::new(&program, DebugHook::default());
+        let transitions = interpreter.transitions().map(|function| function.identifier.to_string()).collect::<Vec<_>>();
+        println!("Debugging '{program_id}'. Transitions: {}", transitions.join(", "));
+        println!("Type `help` for the list of commands.");
+
+        while let Some(line) = read_line("(leo) ")? {
+            let words = line.split_whitespace().collect::<Vec<_>>();
+            match words.as_slice() {
+                [] => {}
+                ["call", function, ..] => {
+                    let function = Symbol::intern(function);
+                    let arguments = split_arguments(line.trim_start().trim_start_matches("call").trim_start())
+                        .into_iter()
+                        .skip(1)
+                        .collect::<Vec<_>>();
+                    let result = interpreter
+                        .parse_arguments(function, &arguments)
+                        .and_then(|arguments| interpreter.call(function, arguments));
+                    match result {
+                        Ok(output) => println!("{}", format_value(&output)),
+                        Err(error) => println!("{error}"),
+                    }
+                    interpreter.hook.mode = Mode::Continue;
+                }
+                ["mappings"] => {
+                    for ((program, mapping), entries) in interpreter.mappings.iter() {
+                        println!("{program}.aleo/{mapping}");
+                        for (key, value) in entries.iter() {
+                            println!("  {key} => {}", format_value(value));
+                        }
+                    }
+                }
+                ["q" | "quit"] => break,
+                words => {
+                    if !interpreter.hook.breakpoint_command(words) {
+                        println!("Unknown command `{}`. Type `help` for the list of commands.", words.join(" "));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// How the execution proceeds until it pauses.
+#[derive(Default)]
+enum Mode {
+    /// Pauses at the breakpoints.
+    #[default]
+    Continue,
+    /// Pauses at the next statement.
+    Step,
+    /// Pauses at the next statement whose call is at most as deep as the given depth.
+    Next(usize),
+}
+
+/// Pauses the interpreter at breakpoints and when stepping, and reads the commands of the user.
+#[derive(Default)]
+struct DebugHook {
+    /// The breakpoints, as an optional file name and a line number.
+    breakpoints: Vec<(Option<String>, usize)>,
+    /// How the execution proceeds until it pauses.
+    mode: Mode,
+}
+
+impl Hook for DebugHook {
+    fn before_statement(&mut self, statement: &Statement, frames: &[Frame]) -> Result<()> {
+        let (file, line) = match location(statement.span()) {
+            Some(location) => location,
+            None => return Ok(()),
+        };
+        let depth = frames.len();
+        let pause = match self.mode {
+            Mode::Step => true,
+            Mode::Next(next_depth) => depth <= next_depth,
+            Mode::Continue => self.breakpoints.iter().any(|(breakpoint_file, breakpoint_line)| {
+                *breakpoint_line == line && breakpoint_file.as_ref().is_none_or(|name| file.ends_with(name.as_str()))
+            }),
+        };
+        if !pause {
+            return Ok(());
+        }
+
+        let source = with_session_globals(|s| s.source_map.line_contents_of_span(statement.span())).unwrap_or_default();
+        println!("{file}:{line}\n{line:>4} | {}", source.trim_end());
+
+        while let Some(input) = read_line("(leo) ")? {
+            let words = input.split_whitespace().collect::<Vec<_>>();
+            match words.as_slice() {
+                [] => {}
+                ["s" | "step"] => {
+                    self.mode = Mode::Step;
+                    return Ok(());
+                }
+                ["n" | "next"] => {
+                    self.mode = Mode::Next(depth);
+                    return Ok(());
+                }
+                ["c" | "continue"] => {
+                    self.mode = Mode::Continue;
+                    return Ok(());
+                }
+                ["p" | "print", name] => match frames.last().and_then(|frame| frame.lookup(Symbol::intern(name))) {
+                    Some(value) => println!("{name} = {}", format_value(value)),
+                    None => println!("`{name}` is not defined."),
+                },
+                ["locals"] => {
+                    if let Some(frame) = frames.last() {
+                        for (name, value) in frame.locals() {
+                            println!("{name} = {}", format_value(value));
+                        }
+                    }
+                }
+                ["bt" | "backtrace"] => {
+                    for (index, frame) in frames.iter().rev().enumerate() {
+                        let block = if frame.is_finalize { " (finalize)" } else { "" };
+                        println!("#{index} {}.aleo/{}{block}", frame.program, frame.function);
+                    }
+                }
+                ["q" | "quit"] => return Err(InterpreterError::execution_stopped().into()),
+                words => {
+                    if !self.breakpoint_command(words) {
+                        println!("Unknown command `{}`. Type `help` for the list of commands.", words.join(" "));
+                    }
+                }
+            }
+        }
+        Err(InterpreterError::execution_stopped().into())
+    }
+}
+
+impl DebugHook {
+    /// Handles the commands available both while paused and not, returning `false` for an unknown command.
+    fn breakpoint_command(&mut self, words: &[&str]) -> bool {
+        match words {
+            ["help"] => println!("{HELP}"),
+            ["break", location] => {
+                let (file, line) = match location.rsplit_once(':') {
+                    Some((file, line)) => (Some(file.to_string()), line),
+                    None => (None, *location),
+                };
+                match line.parse() {
+                    Ok(line) => {
+                        self.breakpoints.push((file, line));
+                        println!("Breakpoint {} at {location}", self.breakpoints.len());
+                    }
+                    Err(_) => println!("`{line}` is not a line number."),
+                }
+            }
+            ["delete"] => self.breakpoints.clear(),
+            ["delete", number] => match number.parse::<usize>() {
+                Ok(number) if (1..=self.breakpoints.len()).contains(&number) => {
+                    self.breakpoints.remove(number - 1);
+                }
+                _ => println!("There is no breakpoint {number}."),
+            },
+            ["breakpoints"] => {
+                for (index, (file, line)) in self.breakpoints.iter().enumerate() {
+                    match file {
+                        Some(file) => println!("{}: {file}:{line}", index + 1),
+                        None => println!("{}: {line}", index + 1),
+                    }
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// Parses and type checks the program of the package, along with the given test files, without lowering it,
+/// so that the interpreter sees the Leo source.
+pub(crate) fn type_checked_program(
+    package_path: &Path,
+    program_id: &ProgramID<CurrentNetwork>,
+    test_files: &[PathBuf],
+    handler: &Handler,
+) -> Result<Program> {
+    let mut compiler = Compiler::new(
+        program_id.name().to_string(),
+        program_id.network().to_string(),
+        handler,
+        package_path.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME),
+        create_outputs_directory(package_path)?,
+        None,
+    );
+    compiler.import_paths = import_paths(package_path, &Dependencies::resolve(package_path)?)?;
+    compiler.parse_program()?;
+    if !test_files.is_empty() {
+        compiler.parse_tests(test_files)?;
+    }
+    let symbol_table = compiler.symbol_table_pass()?;
+    let symbol_table = compiler.type_alias_resolution_pass(symbol_table)?;
+    compiler.type_checker_pass(symbol_table)?;
+    // Note that the compiler stays borrowed by the type checker, so the program is cloned out of it.
+    Ok(compiler.ast.as_repr().clone())
+}
+
+/// Returns an interpreter for the program, whose transitions are signed by the given address.
+pub(crate) fn signed_interpreter<'a>(program: &'a Program, signer: &Address<CurrentNetwork>) -> Interpreter<'a> {
+    let mut interpreter = Interpreter::new(program, ());
+    interpreter.signer = Value::Address(signer.to_string(), SourceSpan::default());
+    interpreter
+}
+
+/// Returns `true` if the program or one of its imports contains `console` statements.
+/// They are removed from the Aleo instructions, so they are only honored by the interpreter.
+pub(crate) fn has_console_statements(program: &Program) -> bool {
+    let mut finder = ConsoleFinder::default();
+    finder.visit_program(program);
+    finder.found
+}
+
+/// Finds `console` statements.
+#[derive(Default)]
+struct ConsoleFinder {
+    /// Whether a `console` statement was found.
+    found: bool,
+}
+
+impl<'a> ExpressionVisitor<'a> for ConsoleFinder {
+    type AdditionalInput = ();
+    type Output = ();
+}
+
+impl<'a> StatementVisitor<'a> for ConsoleFinder {
+    fn visit_console(&mut self, _: &'a ConsoleStatement) {
+        self.found = true;
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for ConsoleFinder {}
+
+/// Returns the file name and line number of the start of the span.
+fn location(span: SourceSpan) -> Option<(String, usize)> {
+    with_session_globals(|s| s.source_map.span_to_location(span))
+        .map(|location| (location.source_file.name.to_string(), location.line_start))
+}
+
+/// Prints the prompt and reads a line of input, returning `None` at the end of the input.
+fn read_line(prompt: &str) -> Result<Option<String>> {
+    print!("{prompt}");
+    std::io::stdout().flush().map_err(CliError::failed_to_read_debugger_input)?;
+    let mut line = String::new();
+    match std::io::stdin().read_line(&mut line).map_err(CliError::failed_to_read_debugger_input)? {
+        0 => Ok(None),
+        _ => Ok(Some(line)),
+    }
+}
+
+/// Splits a line on whitespace outside of braces and brackets, so that struct arguments are kept whole.
+fn split_arguments(line: &str) -> Vec<String> {
+    let mut arguments = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0usize;
+    for character in line.chars() {
+        match character {
+            '{' | '[' => depth += 1,
+            '}' | ']' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        if character.is_whitespace() && depth == 0 {
+            if !current.is_empty() {
+                arguments.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(character);
+        }
+    }
+    if !current.is_empty() {
+        arguments.push(current);
+    }
+    arguments
+}
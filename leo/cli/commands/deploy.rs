@@ -16,42 +16,254 @@
 
 use super::*;
 
+use leo_package::imports::ImportsDirectory;
+
+use snarkvm::{
+    console::network::prelude::Error,
+    ledger::{
+        block::Transaction,
+        query::Query,
+        store::{helpers::memory::ConsensusMemory, ConsensusStore},
+    },
+    prelude::{PrivateKey, Process, Program, ProgramID, Record, VM},
+};
+
+use indexmap::IndexMap;
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+type CurrentVM = VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>>;
+
+/// The imported programs of a package, keyed by their IDs.
+pub(crate) type Imports = IndexMap<ProgramID<CurrentNetwork>, Program<CurrentNetwork>>;
+
 /// Deploys an Aleo program.
 #[derive(Parser, Debug)]
-pub struct Deploy;
+pub struct Deploy {
+    #[clap(
+        name = "ENDPOINT",
+        help = "The endpoint of the node used to query state and broadcast the deployment.",
+        default_value = "https://api.explorer.aleo.org/v1",
+        long
+    )]
+    pub(crate) endpoint: String,
+
+    #[clap(long, help = "The network of the endpoint.", default_value = "testnet3")]
+    pub(crate) network: String,
+
+    #[clap(long, help = "Builds the deployment transaction without contacting the endpoint or broadcasting it.")]
+    pub(crate) dry_run: bool,
+
+    #[clap(long, help = "Writes the deployment transaction of the main program to the given file.")]
+    pub(crate) output: Option<PathBuf>,
+
+    #[clap(long, help = "The priority fee in microcredits.", default_value = "0")]
+    pub(crate) priority_fee: u64,
+
+    #[clap(long, help = "The record to pay the fee with. If not provided, the fee is paid publicly.")]
+    pub(crate) record: Option<String>,
+
+    #[clap(long, help = "The private key of the deployer. If not provided, `PRIVATE_KEY` in `.env` is used.")]
+    pub(crate) private_key: Option<String>,
+
+    #[clap(flatten)]
+    pub(crate) compiler_options: BuildOptions,
+}
 
 impl Command for Deploy {
-    type Input = ();
+    type Input = <Build as Command>::Output;
     type Output = ();
 
     fn log_span(&self) -> Span {
         tracing::span!(tracing::Level::INFO, "Leo")
     }
 
-    fn prelude(&self, _: Context) -> Result<Self::Input> {
-        Ok(())
+    fn prelude(&self, context: Context) -> Result<Self::Input> {
+        (Build { options: self.compiler_options.clone() }).execute(context)
     }
 
     fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
-        // Open the Leo build/ directory
+        // Open the Leo build/ directory.
         let path = context.dir()?;
         let build_directory = BuildDirectory::open(&path).map_err(|_| CliError::needs_leo_build())?;
 
-        // Change the cwd to the Leo build/ directory to deploy aleo files.
-        std::env::set_current_dir(&build_directory)
-            .map_err(|err| PackageError::failed_to_set_cwd(build_directory.display(), err))?;
+        // Load the main program and the programs in the build/imports/ directory.
+        let (main_program, imports) = load_programs(&build_directory, context.open_manifest()?.program_id())?;
+
+        // Load the private key of the deployer.
+        let private_key = match &self.private_key {
+            Some(private_key) => PrivateKey::from_str(private_key).map_err(CliError::failed_to_execute_deploy)?,
+            None => context.dotenv_private_key()?,
+        };
+
+        // Parse the fee record, if provided.
+        let fee_record =
+            self.record.as_deref().map(Record::from_str).transpose().map_err(CliError::failed_to_execute_deploy)?;
+
+        // Initialize a VM backed by an in-memory store, which is used to synthesize the deployments.
+        let store = ConsensusStore::open(None).map_err(CliError::failed_to_execute_deploy)?;
+        let vm = CurrentVM::from(store).map_err(CliError::failed_to_execute_deploy)?;
+
+        // Initialize an RNG.
+        let rng = &mut rand::thread_rng();
 
-        // Unset the Leo panic hook.
-        let _ = std::panic::take_hook();
+        // Deploy the imported programs in dependency order, followed by the main program.
+        for program_id in dependency_order(&main_program, &imports) {
+            let program = &imports[&program_id];
 
-        // Call the `node` command.
-        println!();
-        let command = SnarkVMDeploy::try_parse_from([ALEO_CLI_COMMAND]).map_err(CliError::failed_to_parse_aleo_node)?;
-        let res = command.parse().map_err(CliError::failed_to_execute_aleo_node)?;
+            // Imports are only deployed when they are missing from the network.
+            // Note that in dry-run mode the network is never contacted, so imports are only loaded.
+            if !self.dry_run && !program_exists(&self.endpoint, &self.network, &program_id)? {
+                let transaction = self.deploy_program(&vm, &private_key, program, None, rng)?;
+                broadcast(&self.endpoint, &self.network, &program_id, &transaction)?;
+            }
 
-        // Log the output of the `node` command.
-        tracing::info!("{}", res);
+            // Add the import to the VM so that programs depending on it can be deployed.
+            vm.process().write().add_program(program).map_err(CliError::failed_to_execute_deploy)?;
+        }
+
+        let transaction = self.deploy_program(&vm, &private_key, &main_program, fee_record, rng)?;
+        self.write_transaction(&transaction)?;
+
+        match self.dry_run {
+            true => tracing::info!("✅ Built the deployment of '{}' without broadcasting it", main_program.id()),
+            false => broadcast(&self.endpoint, &self.network, main_program.id(), &transaction)?,
+        }
+
+        Ok(())
+    }
+}
 
+impl Deploy {
+    /// Writes the transaction to the output file, if one is specified.
+    /// Otherwise, in dry-run mode, the transaction is printed instead.
+    pub(crate) fn write_transaction(&self, transaction: &Transaction<CurrentNetwork>) -> Result<()> {
+        match &self.output {
+            Some(output) => {
+                std::fs::write(output, transaction.to_string()).map_err(CliError::failed_to_write_file)?;
+                tracing::info!("✅ Wrote the transaction to '{}'", output.display());
+            }
+            None if self.dry_run => println!("{transaction}"),
+            None => {}
+        }
         Ok(())
     }
+
+    /// Constructs the deployment transaction for the given program.
+    fn deploy_program(
+        &self,
+        vm: &CurrentVM,
+        private_key: &PrivateKey<CurrentNetwork>,
+        program: &Program<CurrentNetwork>,
+        fee_record: Option<Record<CurrentNetwork, snarkvm::prelude::Plaintext<CurrentNetwork>>>,
+        rng: &mut rand::rngs::ThreadRng,
+    ) -> Result<Transaction<CurrentNetwork>> {
+        tracing::info!("⏳ Building the deployment of '{}'...", program.id());
+
+        // In dry-run mode, the state root is taken from the local VM instead of the endpoint.
+        let query = match self.dry_run {
+            true => None,
+            false => Some(Query::from(&self.endpoint)),
+        };
+
+        vm.deploy(private_key, program, fee_record, self.priority_fee, query, rng)
+            .map_err(|err| CliError::failed_to_execute_deploy(err).into())
+    }
+}
+
+/// Loads the main program of the package and the programs in its build/imports/ directory, keyed by their IDs.
+pub(crate) fn load_programs(
+    build_directory: &Path,
+    program_id: &ProgramID<CurrentNetwork>,
+) -> Result<(Program<CurrentNetwork>, Imports)> {
+    let main_program = load_program(&build_directory.join(format!("main.{}", program_id.network())))?;
+    let mut imports = IndexMap::new();
+    if !ImportsDirectory::is_empty(build_directory)? {
+        for file_path in ImportsDirectory::files(build_directory)? {
+            let program = load_program(&file_path)?;
+            imports.insert(*program.id(), program);
+        }
+    }
+    Ok((main_program, imports))
+}
+
+/// Loads the Aleo program at the given path.
+pub(crate) fn load_program(path: &Path) -> Result<Program<CurrentNetwork>> {
+    let program_string =
+        std::fs::read_to_string(path).map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
+    Program::from_str(&program_string).map_err(|err| CliError::failed_to_load_instructions(err).into())
+}
+
+/// Initializes a process with the imports of `program`, in dependency order, followed by `program` itself.
+/// Returns the process along with the programs added to it, in the order they were added.
+/// The snarkVM error is returned as it is, so that each command reports it with its own error.
+pub(crate) fn load_process<'a>(
+    program: &'a Program<CurrentNetwork>,
+    imports: &'a Imports,
+) -> std::result::Result<(Process<CurrentNetwork>, Vec<&'a Program<CurrentNetwork>>), Error> {
+    let mut process = Process::load()?;
+    let mut programs: Vec<_> = dependency_order(program, imports).iter().map(|id| &imports[id]).collect();
+    programs.push(program);
+    for program in &programs {
+        process.add_program(program)?;
+    }
+    Ok((process, programs))
+}
+
+/// Returns the IDs of the imports of `program` that are present in `imports`, ordered such that every program
+/// appears after the programs it imports.
+pub(crate) fn dependency_order(program: &Program<CurrentNetwork>, imports: &Imports) -> Vec<ProgramID<CurrentNetwork>> {
+    fn visit(program: &Program<CurrentNetwork>, imports: &Imports, order: &mut Vec<ProgramID<CurrentNetwork>>) {
+        for import_id in program.imports().keys() {
+            // Note that imports which are not in the build/imports/ directory, such as `credits.aleo`, are expected to already exist.
+            if let Some(import) = imports.get(import_id) {
+                if !order.contains(import_id) {
+                    visit(import, imports, order);
+                    order.push(*import_id);
+                }
+            }
+        }
+    }
+
+    let mut order = Vec::new();
+    visit(program, imports, &mut order);
+    order
+}
+
+/// Returns `true` if the program is already deployed on the network of the given endpoint.
+fn program_exists(endpoint: &str, network: &str, program_id: &ProgramID<CurrentNetwork>) -> Result<bool> {
+    let url = format!("{endpoint}/{network}/program/{program_id}");
+    match reqwest::blocking::get(url) {
+        Ok(response) => Ok(response.status().is_success()),
+        Err(err) => Err(CliError::failed_to_broadcast_deployment(program_id, err).into()),
+    }
+}
+
+/// Broadcasts the deployment transaction to the network of the given endpoint.
+fn broadcast(
+    endpoint: &str,
+    network: &str,
+    program_id: &ProgramID<CurrentNetwork>,
+    transaction: &Transaction<CurrentNetwork>,
+) -> Result<()> {
+    let url = format!("{endpoint}/{network}/transaction/broadcast");
+    let response = reqwest::blocking::Client::new()
+        .post(url)
+        .json(transaction)
+        .send()
+        .map_err(|err| CliError::failed_to_broadcast_deployment(program_id, err))?;
+
+    match response.status().is_success() {
+        true => {
+            tracing::info!("✅ Broadcast the deployment of '{program_id}' (transaction '{}')", transaction.id());
+            Ok(())
+        }
+        false => {
+            let status = response.status();
+            let message = response.text().unwrap_or_default();
+            Err(CliError::failed_to_broadcast_deployment(program_id, format!("{status} {message}")).into())
+        }
+    }
 }
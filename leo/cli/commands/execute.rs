@@ -16,7 +16,26 @@
 
 use super::*;
 
-use snarkvm::cli::Execute as SnarkVMExecute;
+use super::{
+    debug::{has_console_statements, signed_interpreter},
+    deploy::{load_process, load_programs},
+};
+use crate::cli::helpers::ledger::LocalLedger;
+
+use leo_package::ledger::LEDGER_DIRECTORY_NAME;
+use leo_span::Symbol;
+
+use snarkvm::{
+    circuit::AleoV0,
+    cli::Execute as SnarkVMExecute,
+    ledger::block::Execution,
+    prelude::{Address, Identifier, Network, PrivateKey, Program, Value},
+    synthesizer::program::FinalizeGlobalState,
+};
+
+use std::str::FromStr;
+
+type CurrentAleo = AleoV0;
 
 /// Build, Prove and Run Leo program with inputs
 #[derive(Parser, Debug)]
@@ -35,6 +54,12 @@ pub struct Execute {
     )]
     endpoint: String,
 
+    #[clap(
+        long,
+        help = "Executes the program and its finalize block against the local ledger in `.leo/ledger/`, without contacting the endpoint."
+    )]
+    local: bool,
+
     #[clap(flatten)]
     pub(crate) compiler_options: BuildOptions,
 }
@@ -54,14 +79,19 @@ impl Command for Execute {
     fn apply(self, context: Context, input: Self::Input) -> Result<Self::Output> {
         // If input values are provided, then run the program with those inputs.
         // Otherwise, use the input file.
+        let (input_ast, circuits, program) = input;
         let mut inputs = match self.inputs.is_empty() {
-            true => match input {
-                (Some(input_ast), circuits) => input_ast.program_inputs(&self.name, circuits),
-                _ => Vec::new(),
+            true => match input_ast {
+                Some(input_ast) => input_ast.program_inputs(&self.name, circuits),
+                None => Vec::new(),
             },
             false => self.inputs,
         };
 
+        if self.local {
+            return execute_locally(&context, &program, &self.name, &inputs);
+        }
+
         // Compose the `execute` command.
         let mut arguments = vec![SNARKVM_COMMAND.to_string(), self.name];
 
@@ -99,3 +129,109 @@ impl Command for Execute {
         Ok(())
     }
 }
+
+/// Executes the function and its finalize block in-process, against the local ledger of the package.
+/// The mapping updates are persisted only if the finalize block succeeds.
+/// The given program is the type checked AST of the build, which the interpreter runs if it contains `console` statements.
+pub(crate) fn execute_locally(
+    context: &Context,
+    program: &leo_ast::Program,
+    name: &str,
+    inputs: &[String],
+) -> Result<()> {
+    // Load the main program and the programs in the build/imports/ directory.
+    let path = context.dir()?;
+    let build_directory = BuildDirectory::open(&path).map_err(|_| CliError::needs_leo_build())?;
+    let manifest = context.open_manifest()?;
+    let (main_program, imports) = load_programs(&build_directory, manifest.program_id())?;
+
+    // Initialize a process with the imports, followed by the main program.
+    let (process, programs) = load_process(&main_program, &imports).map_err(CliError::failed_to_execute_execute)?;
+
+    // Load the private key of the caller, and the state of the local ledger.
+    let private_key = context.dotenv_private_key()?;
+    let ledger = LocalLedger::open(&path, &programs)?;
+
+    // Console statements are removed from the Aleo instructions, so if the program or its imports contain any,
+    // the function is also evaluated by the interpreter, which prints the logs and checks the assertions.
+    // The Aleo instructions stay authoritative: a failure of the interpreter only reverts an execution that succeeds.
+    let interpreted = match has_console_statements(program) {
+        true => interpret(program, &private_key, &ledger, &programs, name, inputs),
+        false => Ok(()),
+    };
+
+    // Parse the function name and its inputs.
+    let function_name = Identifier::<CurrentNetwork>::from_str(name).map_err(CliError::failed_to_execute_execute)?;
+    let inputs = inputs
+        .iter()
+        .map(|input| Value::<CurrentNetwork>::from_str(input))
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(CliError::failed_to_execute_execute)?;
+
+    // Initialize an RNG.
+    let rng = &mut rand::thread_rng();
+
+    // Execute the function.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&private_key, main_program.id(), function_name, inputs.into_iter(), rng)
+        .map_err(CliError::failed_to_execute_execute)?;
+    let (response, trace) =
+        process.execute::<CurrentAleo>(authorization).map_err(CliError::failed_to_execute_execute)?;
+
+    // Log the outputs of the function.
+    tracing::info!("➡️  Output\n");
+    for output in response.outputs() {
+        tracing::info!(" • {output}");
+    }
+    tracing::info!("");
+
+    // Run the finalize blocks of the execution against the local ledger.
+    // Note that the execution is not proven, since `finalize_execution` does not verify proofs.
+    let execution =
+        Execution::from(trace.transitions().iter().cloned(), <CurrentNetwork as Network>::StateRoot::default(), None)
+            .map_err(CliError::failed_to_execute_execute)?;
+    let state = FinalizeGlobalState::new_genesis::<CurrentNetwork>().map_err(CliError::failed_to_execute_execute)?;
+    if let Err(error) = process.finalize_execution(state, ledger.store(), &execution, None) {
+        return Err(CliError::finalize_reverted(format!("{}/{name}", main_program.id()), error).into());
+    }
+    if let Err(error) = interpreted {
+        return Err(CliError::finalize_reverted(format!("{}/{name}", main_program.id()), error).into());
+    }
+
+    // Persist the mapping updates.
+    ledger.save(&programs)?;
+    tracing::info!("✅ Executed '{}/{name}' against the local ledger in '{LEDGER_DIRECTORY_NAME}'", main_program.id());
+
+    Ok(())
+}
+
+/// Evaluates the function with the interpreter, starting from the mappings of the local ledger.
+/// Note that the mappings of the interpreter are discarded, since the ledger is updated by the Aleo instructions.
+fn interpret(
+    program: &leo_ast::Program,
+    private_key: &PrivateKey<CurrentNetwork>,
+    ledger: &LocalLedger,
+    programs: &[&Program<CurrentNetwork>],
+    name: &str,
+    inputs: &[String],
+) -> Result<()> {
+    let signer = Address::try_from(private_key).map_err(CliError::failed_to_execute_execute)?;
+    let mut interpreter = signed_interpreter(program, &signer);
+    for program in programs {
+        let program_name = Symbol::intern(&program.id().name().to_string());
+        for mapping in program.mappings().keys() {
+            let entries = ledger
+                .store()
+                .get_mapping_confirmed(*program.id(), *mapping)
+                .map_err(CliError::failed_to_load_local_ledger)?;
+            for (key, value) in entries {
+                let mapping = Symbol::intern(&mapping.to_string());
+                interpreter.set_mapping_entry(program_name, mapping, &key.to_string(), &value.to_string())?;
+            }
+        }
+    }
+    let function = Symbol::intern(name);
+    let arguments = interpreter.parse_arguments(function, inputs)?;
+    interpreter.call(function, arguments)?;
+    Ok(())
+}
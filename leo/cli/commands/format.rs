@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_ast::NodeBuilder;
+use leo_package::{
+    imports::ImportsDirectory,
+    source::{SourceDirectory, MAIN_FILENAME},
+};
+use leo_span::symbol::with_session_globals;
+
+/// Format the Leo source files of the package
+#[derive(Parser, Debug)]
+pub struct Format {
+    #[clap(long, help = "Checks that the files are formatted, without modifying them.")]
+    check: bool,
+}
+
+impl Command for Format {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let path = context.dir()?;
+
+        // Fetch paths to all .leo files in the source and imports directories.
+        // Note that the files of the source directory other than the main file are modules.
+        let source_paths = SourceDirectory::files(&path)?;
+        let modules = source_paths.iter().filter(|path| !path.ends_with(MAIN_FILENAME)).cloned().collect::<Vec<_>>();
+        let mut file_paths = source_paths;
+        if !ImportsDirectory::is_empty(&path)? {
+            // Compiled Aleo programs are not formatted.
+            let import_paths = ImportsDirectory::files(&path)?;
+            file_paths.extend(
+                import_paths
+                    .into_iter()
+                    .filter(|path| matches!(path.extension().and_then(|extension| extension.to_str()), Some("leo"))),
+            );
+        }
+
+        let handler = context.handler();
+        let mut unformatted = Vec::new();
+
+        for file_path in file_paths {
+            // Load the file into the source map.
+            let source_file = with_session_globals(|s| s.source_map.load_file(&file_path))
+                .map_err(|e| CompilerError::file_read_error(&file_path, e))?;
+
+            let format = match modules.contains(&file_path) {
+                true => leo_parser::format_module_source,
+                false => leo_parser::format_source,
+            };
+            let formatted = format(&handler, &NodeBuilder::default(), &source_file.src, source_file.start_pos)?;
+
+            // Skip the files that are already formatted.
+            if formatted == source_file.src {
+                continue;
+            }
+
+            match self.check {
+                true => {
+                    tracing::info!("❌ '{}' is not formatted", file_path.display());
+                    unformatted.push(file_path.display().to_string());
+                }
+                false => {
+                    std::fs::write(&file_path, formatted).map_err(CliError::failed_to_write_file)?;
+                    tracing::info!("✅ Formatted '{}'", file_path.display());
+                }
+            }
+        }
+
+        match unformatted.is_empty() {
+            true => Ok(()),
+            false => Err(CliError::files_not_formatted(unformatted.join(", ")).into()),
+        }
+    }
+}
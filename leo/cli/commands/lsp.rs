@@ -0,0 +1,340 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::cli::helpers::analysis::{core_function_completions, Analysis, CoreFunctionCompletion};
+
+use leo_span::source_map::FileName;
+
+use lsp_server::{Connection, Message, Notification, Request, RequestId, Response};
+use lsp_types::{
+    notification::{DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, PublishDiagnostics},
+    request::{Completion, GotoDefinition, HoverRequest},
+    CompletionItem,
+    CompletionItemKind,
+    CompletionOptions,
+    CompletionResponse,
+    DiagnosticSeverity,
+    GotoDefinitionResponse,
+    Hover,
+    HoverContents,
+    HoverProviderCapability,
+    Location,
+    MarkupContent,
+    MarkupKind,
+    NumberOrString,
+    OneOf,
+    Position,
+    PublishDiagnosticsParams,
+    Range,
+    ServerCapabilities,
+    TextDocumentSyncCapability,
+    TextDocumentSyncKind,
+    Url,
+};
+use std::{collections::HashMap, path::Path};
+
+/// Runs a language server for Leo over stdio
+#[derive(Parser, Debug)]
+pub struct Lsp {}
+
+impl Command for Lsp {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, _: Context, _: Self::Input) -> Result<Self::Output> {
+        let (connection, io_threads) = Connection::stdio();
+
+        let capabilities = ServerCapabilities {
+            text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+            hover_provider: Some(HoverProviderCapability::Simple(true)),
+            definition_provider: Some(OneOf::Left(true)),
+            completion_provider: Some(CompletionOptions {
+                trigger_characters: Some(vec![":".to_string()]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let capabilities = serde_json::to_value(capabilities).map_err(CliError::failed_to_run_language_server)?;
+        connection.initialize(capabilities).map_err(CliError::failed_to_run_language_server)?;
+
+        let mut server = Server { connection, documents: HashMap::new(), completions: core_function_completions() };
+        server.run()?;
+
+        io_threads.join().map_err(CliError::failed_to_run_language_server)?;
+        Ok(())
+    }
+}
+
+/// An open Leo source file.
+struct Document {
+    /// The text of the file, as last sent by the client.
+    text: String,
+    /// The analysis of the text.
+    analysis: Analysis,
+}
+
+/// The state of the language server.
+struct Server {
+    /// The connection to the client.
+    connection: Connection,
+    /// The files opened by the client.
+    documents: HashMap<Url, Document>,
+    /// The core functions offered as completions.
+    completions: Vec<CoreFunctionCompletion>,
+}
+
+impl Server {
+    /// Handles the messages of the client until it requests a shutdown.
+    fn run(&mut self) -> Result<()> {
+        while let Ok(message) = self.connection.receiver.recv() {
+            match message {
+                Message::Request(request) => {
+                    if self.connection.handle_shutdown(&request).map_err(CliError::failed_to_run_language_server)? {
+                        return Ok(());
+                    }
+                    self.handle_request(request)?;
+                }
+                Message::Notification(notification) => self.handle_notification(notification)?,
+                Message::Response(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Answers a hover, go-to-definition, or completion request.
+    fn handle_request(&self, request: Request) -> Result<()> {
+        use lsp_types::request::Request as _;
+
+        let method = request.method.clone();
+        let (id, result) = match method.as_str() {
+            method if method == HoverRequest::METHOD => {
+                let (id, params) = extract_request::<HoverRequest>(request)?;
+                let position = params.text_document_position_params;
+                (id, serde_json::to_value(self.hover(&position.text_document.uri, position.position)))
+            }
+            method if method == GotoDefinition::METHOD => {
+                let (id, params) = extract_request::<GotoDefinition>(request)?;
+                let position = params.text_document_position_params;
+                (id, serde_json::to_value(self.definition(&position.text_document.uri, position.position)))
+            }
+            method if method == Completion::METHOD => {
+                let (id, params) = extract_request::<Completion>(request)?;
+                let position = params.text_document_position;
+                (id, serde_json::to_value(self.completion(&position.text_document.uri, position.position)))
+            }
+            _ => return Ok(()),
+        };
+
+        let result = result.map_err(CliError::failed_to_run_language_server)?;
+        self.send(Message::Response(Response::new_ok(id, result)))
+    }
+
+    /// Updates the open files, publishing their diagnostics whenever they change.
+    fn handle_notification(&mut self, notification: Notification) -> Result<()> {
+        use lsp_types::notification::Notification as _;
+
+        let method = notification.method.clone();
+        match method.as_str() {
+            method if method == DidOpenTextDocument::METHOD => {
+                let params = extract_notification::<DidOpenTextDocument>(notification)?;
+                self.update(params.text_document.uri, params.text_document.text)
+            }
+            method if method == DidChangeTextDocument::METHOD => {
+                let params = extract_notification::<DidChangeTextDocument>(notification)?;
+                // The server requests full synchronization, so the last change contains the entire text.
+                match params.content_changes.into_iter().last() {
+                    Some(change) => self.update(params.text_document.uri, change.text),
+                    None => Ok(()),
+                }
+            }
+            method if method == DidCloseTextDocument::METHOD => {
+                let params = extract_notification::<DidCloseTextDocument>(notification)?;
+                self.documents.remove(&params.text_document.uri);
+                // Clear the diagnostics of the closed file.
+                self.publish_diagnostics(params.text_document.uri, Vec::new())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Analyzes the new text of a file and publishes its diagnostics.
+    fn update(&mut self, uri: Url, text: String) -> Result<()> {
+        // The source map normalizes newlines, so the text is normalized as well for the byte offsets to agree.
+        let text = text.replace("\r\n", "\n");
+
+        let (name, package_root) = match uri.to_file_path() {
+            Ok(path) => {
+                // Imports are resolved in the package whose `src/` directory contains the file.
+                let package_root = path
+                    .parent()
+                    .filter(|parent| parent.ends_with("src"))
+                    .and_then(Path::parent)
+                    .map(Path::to_path_buf);
+                (FileName::Real(path), package_root)
+            }
+            Err(_) => (FileName::Custom(uri.to_string()), None),
+        };
+
+        let analysis = Analysis::new(name, &text, package_root);
+        let diagnostics = analysis
+            .diagnostics
+            .iter()
+            .map(|diagnostic| lsp_types::Diagnostic {
+                range: to_range(&text, &diagnostic.range),
+                severity: Some(match diagnostic.error {
+                    true => DiagnosticSeverity::ERROR,
+                    false => DiagnosticSeverity::WARNING,
+                }),
+                code: Some(NumberOrString::String(diagnostic.code.clone())),
+                source: Some("leo".to_string()),
+                message: diagnostic.message.clone(),
+                ..Default::default()
+            })
+            .collect();
+
+        self.documents.insert(uri.clone(), Document { text, analysis });
+        self.publish_diagnostics(uri, diagnostics)
+    }
+
+    /// Returns the type of the expression at the given position.
+    fn hover(&self, uri: &Url, position: Position) -> Option<Hover> {
+        let document = self.documents.get(uri)?;
+        let (range, type_) = document.analysis.type_at(to_offset(&document.text, position))?;
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: format!("```leo\n{type_}\n```"),
+            }),
+            range: Some(to_range(&document.text, &range)),
+        })
+    }
+
+    /// Returns the location of the definition of the struct, record, function, or mapping at the given position.
+    fn definition(&self, uri: &Url, position: Position) -> Option<GotoDefinitionResponse> {
+        let document = self.documents.get(uri)?;
+        let range = document.analysis.definition_at(to_offset(&document.text, position))?;
+        Some(GotoDefinitionResponse::Scalar(Location { uri: uri.clone(), range: to_range(&document.text, &range) }))
+    }
+
+    /// Returns the core functions that may complete the path before the given position.
+    fn completion(&self, uri: &Url, position: Position) -> Option<CompletionResponse> {
+        let document = self.documents.get(uri)?;
+
+        // Find the path being typed, e.g. `BHP256::ha`.
+        let offset = to_offset(&document.text, position);
+        let path = document.text[..offset]
+            .rsplit(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == ':'))
+            .next()
+            .unwrap_or_default();
+
+        let items = match path.split_once("::") {
+            // Once the module is typed, only the functions of that module are offered.
+            Some((module, _)) => self
+                .completions
+                .iter()
+                .filter(|completion| completion.module == module)
+                .map(|completion| completion_item(completion.function.clone(), completion))
+                .collect(),
+            None => self
+                .completions
+                .iter()
+                .map(|completion| {
+                    completion_item(format!("{}::{}", completion.module, completion.function), completion)
+                })
+                .collect(),
+        };
+        Some(CompletionResponse::Array(items))
+    }
+
+    /// Publishes the diagnostics of a file.
+    fn publish_diagnostics(&self, uri: Url, diagnostics: Vec<lsp_types::Diagnostic>) -> Result<()> {
+        use lsp_types::notification::Notification as _;
+
+        let params = PublishDiagnosticsParams { uri, diagnostics, version: None };
+        self.send(Message::Notification(Notification::new(PublishDiagnostics::METHOD.to_string(), params)))
+    }
+
+    /// Sends a message to the client.
+    fn send(&self, message: Message) -> Result<()> {
+        self.connection.sender.send(message).map_err(|err| CliError::failed_to_run_language_server(err).into())
+    }
+}
+
+/// Extracts the ID and parameters of a request.
+fn extract_request<R: lsp_types::request::Request>(request: Request) -> Result<(RequestId, R::Params)> {
+    request.extract(R::METHOD).map_err(|err| CliError::failed_to_run_language_server(format!("{err:?}")).into())
+}
+
+/// Extracts the parameters of a notification.
+fn extract_notification<N: lsp_types::notification::Notification>(notification: Notification) -> Result<N::Params> {
+    notification.extract(N::METHOD).map_err(|err| CliError::failed_to_run_language_server(format!("{err:?}")).into())
+}
+
+/// Creates the completion item of a core function.
+fn completion_item(label: String, completion: &CoreFunctionCompletion) -> CompletionItem {
+    let arguments = match completion.num_args {
+        1 => "1 argument".to_string(),
+        num_args => format!("{num_args} arguments"),
+    };
+    CompletionItem {
+        label,
+        kind: Some(CompletionItemKind::FUNCTION),
+        detail: Some(format!("{}::{} ({arguments})", completion.module, completion.function)),
+        ..Default::default()
+    }
+}
+
+/// Converts a position, whose character is counted in UTF-16 code units, into a byte offset of `text`.
+fn to_offset(text: &str, position: Position) -> usize {
+    let mut offset = 0;
+    for (line_number, line) in text.split_inclusive('\n').enumerate() {
+        if line_number == position.line as usize {
+            let mut character = 0;
+            for (index, c) in line.char_indices() {
+                if character >= position.character as usize || c == '\n' {
+                    return offset + index;
+                }
+                character += c.len_utf16();
+            }
+            return offset + line.len();
+        }
+        offset += line.len();
+    }
+    offset
+}
+
+/// Converts a byte offset of `text` into a position, whose character is counted in UTF-16 code units.
+fn to_position(text: &str, offset: usize) -> Position {
+    let offset = offset.min(text.len());
+    let line_start = text[..offset].rfind('\n').map_or(0, |index| index + 1);
+    let line = text[..line_start].matches('\n').count();
+    let character = text[line_start..offset].chars().map(char::len_utf16).sum::<usize>();
+    Position::new(line as u32, character as u32)
+}
+
+/// Converts a byte range of `text` into a range of positions.
+fn to_range(text: &str, range: &std::ops::Range<usize>) -> Range {
+    Range::new(to_position(text, range.start), to_position(text, range.end))
+}
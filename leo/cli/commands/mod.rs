@@ -17,6 +17,9 @@
 pub mod account;
 pub use account::Account;
 
+pub mod add;
+pub use add::Add;
+
 pub mod build;
 pub use build::Build;
 
@@ -26,11 +29,20 @@ pub use clean::Clean;
 pub mod example;
 pub use example::Example;
 
+pub mod debug;
+pub use debug::Debugger;
+
+pub mod deploy;
+pub use deploy::Deploy;
+
 pub mod execute;
 pub use execute::Execute;
 
-// pub mod deploy;
-// pub use deploy::Deploy;
+pub mod format;
+pub use format::Format;
+
+pub mod lsp;
+pub use lsp::Lsp;
 
 pub mod new;
 pub use new::New;
@@ -38,9 +50,18 @@ pub use new::New;
 // pub mod node;
 // pub use node::Node;
 
+pub mod query;
+pub use query::Query;
+
+pub mod remove;
+pub use remove::Remove;
+
 pub mod run;
 pub use run::Run;
 
+pub mod test;
+pub use test::Test;
+
 pub mod update;
 pub use update::Update;
 
@@ -149,6 +170,8 @@ pub struct BuildOptions {
     pub enable_destructured_ast_snapshot: bool,
     #[clap(long, help = "Writes AST snapshot of the inlined AST.")]
     pub enable_inlined_ast_snapshot: bool,
+    #[clap(long, help = "Writes AST snapshot of the common subexpression eliminated (CSE) AST.")]
+    pub enable_cse_ast_snapshot: bool,
     #[clap(long, help = "Writes AST snapshot of the dead code eliminated (DCE) AST.")]
     pub enable_dce_ast_snapshot: bool,
 }
@@ -0,0 +1,319 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use super::deploy::{dependency_order, load_programs};
+
+use leo_package::ledger::MappingsFile;
+
+use snarkvm::prelude::{Identifier, LiteralType, Plaintext, PlaintextType, Program, ProgramID};
+
+use serde::de::DeserializeOwned;
+use std::{path::PathBuf, str::FromStr};
+
+/// Commands to query the state of programs.
+#[derive(Parser, Debug)]
+pub enum Query {
+    /// Prints the value of a mapping key, or every entry of the mapping
+    Mapping {
+        /// The ID of the program, e.g. `token.aleo`.
+        program: ProgramID<CurrentNetwork>,
+        /// The name of the mapping.
+        mapping: Identifier<CurrentNetwork>,
+        /// The key to look up. Literal keys may omit their type suffix, e.g. `1` for a `u32` key.
+        #[clap(required_unless_present = "all", conflicts_with = "all")]
+        key: Option<String>,
+        /// Prints every entry of the mapping.
+        #[clap(long)]
+        all: bool,
+        /// The endpoint of the node to query.
+        #[clap(long, default_value = "https://api.explorer.aleo.org/v1")]
+        endpoint: String,
+        /// The network of the endpoint.
+        #[clap(long, default_value = "testnet3")]
+        network: String,
+        /// Reads the mapping from the local ledger in `.leo/ledger/`, as updated by `leo execute --local`.
+        #[clap(long)]
+        local: bool,
+    },
+}
+
+impl Command for Query {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        match self {
+            Query::Mapping { program, mapping, key, all: _, endpoint, network, local } => {
+                let source = match local {
+                    true => Source::Local { path: context.dir()?, main: *context.open_manifest()?.program_id() },
+                    false => Source::Endpoint { endpoint, network },
+                };
+                let label = format!("{program}/{mapping}");
+
+                // Look up the declared key and value types of the mapping in the compiled program.
+                let aleo_program = source.program(&program, &label)?;
+                let declaration =
+                    aleo_program.get_mapping(&mapping).map_err(|err| CliError::failed_to_query_mapping(&label, err))?;
+                let key_type = declaration.key().plaintext_type();
+                let value_type = declaration.value().plaintext_type();
+
+                let entries = match key {
+                    Some(key) => {
+                        let key = parse_key(&aleo_program, key_type, &key)?;
+                        let value = source.value(&program, &mapping, &key, &label)?;
+                        vec![(key, value)]
+                    }
+                    None => source
+                        .entries(&program, &mapping, &label)?
+                        .into_iter()
+                        .map(|(key, value)| (key, Some(value)))
+                        .collect(),
+                };
+
+                println!(
+                    "\n {}  {} => {}\n",
+                    label.cyan().bold(),
+                    leo_type(key_type).bold(),
+                    leo_type(value_type).bold()
+                );
+                if entries.is_empty() {
+                    println!(" The mapping is empty.\n");
+                }
+                for (key, value) in entries {
+                    let value = match value {
+                        Some(value) => leo_value(&aleo_program, &value, value_type),
+                        None => "(not set)".dimmed().to_string(),
+                    };
+                    println!(" {} => {value}", leo_value(&aleo_program, &key, key_type));
+                }
+                println!();
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Where the state of a mapping is read from.
+pub(crate) enum Source {
+    /// The REST API of a node on the given network.
+    Endpoint { endpoint: String, network: String },
+    /// The local ledger of the package at the given path, whose main program has the given ID.
+    Local { path: PathBuf, main: ProgramID<CurrentNetwork> },
+}
+
+impl Source {
+    /// Returns the compiled program with the given ID.
+    pub(crate) fn program(
+        &self,
+        program_id: &ProgramID<CurrentNetwork>,
+        label: &str,
+    ) -> Result<Program<CurrentNetwork>> {
+        if program_id.to_string() == "credits.aleo" {
+            return Program::credits().map_err(|err| CliError::failed_to_query_mapping(label, err).into());
+        }
+
+        match self {
+            Source::Endpoint { endpoint, network } => {
+                let url = format!("{endpoint}/{network}/program/{program_id}");
+                let program: String = get_json(&url, label)?;
+                Program::from_str(&program).map_err(|err| CliError::failed_to_load_instructions(err).into())
+            }
+            // The programs executed locally are the main program and the imports it depends on in the build/ directory.
+            Source::Local { path, main } => {
+                let build_directory = BuildDirectory::open(path).map_err(|_| CliError::needs_leo_build())?;
+                let (main_program, mut imports) = load_programs(&build_directory, main)?;
+                if main_program.id() == program_id {
+                    return Ok(main_program);
+                }
+                let program = match dependency_order(&main_program, &imports).contains(program_id) {
+                    true => imports.shift_remove(program_id),
+                    false => None,
+                };
+                program.ok_or_else(|| {
+                    CliError::failed_to_query_mapping(label, format!("`{program_id}` is not a dependency of `{main}`"))
+                        .into()
+                })
+            }
+        }
+    }
+
+    /// Returns the value of the key in the mapping, if it is set.
+    pub(crate) fn value(
+        &self,
+        program_id: &ProgramID<CurrentNetwork>,
+        mapping: &Identifier<CurrentNetwork>,
+        key: &Plaintext<CurrentNetwork>,
+        label: &str,
+    ) -> Result<Option<Plaintext<CurrentNetwork>>> {
+        match self {
+            Source::Endpoint { endpoint, network } => {
+                let url = format!("{endpoint}/{network}/program/{program_id}/mapping/{mapping}/{key}");
+                let value: Option<String> = get_json(&url, label)?;
+                value.map(|value| parse_plaintext(&value, label)).transpose()
+            }
+            Source::Local { .. } => Ok(self
+                .entries(program_id, mapping, label)?
+                .into_iter()
+                .find(|(entry_key, _)| entry_key == key)
+                .map(|(_, value)| value)),
+        }
+    }
+
+    /// Returns every entry of the mapping.
+    pub(crate) fn entries(
+        &self,
+        program_id: &ProgramID<CurrentNetwork>,
+        mapping: &Identifier<CurrentNetwork>,
+        label: &str,
+    ) -> Result<Vec<(Plaintext<CurrentNetwork>, Plaintext<CurrentNetwork>)>> {
+        let entries: Vec<(String, String)> = match self {
+            // Note that the endpoint is expected to return the entries as a list of `[key, value]` pairs.
+            Source::Endpoint { endpoint, network } => {
+                get_json(&format!("{endpoint}/{network}/program/{program_id}/mapping/{mapping}"), label)?
+            }
+            Source::Local { path, .. } => MappingsFile::new(&program_id.to_string())
+                .read_from(path)?
+                .shift_remove(&mapping.to_string())
+                .unwrap_or_default()
+                .into_iter()
+                .collect(),
+        };
+        entries.iter().map(|(key, value)| Ok((parse_plaintext(key, label)?, parse_plaintext(value, label)?))).collect()
+    }
+}
+
+/// Sends a GET request to the given URL and deserializes the JSON response.
+fn get_json<T: DeserializeOwned>(url: &str, label: &str) -> Result<T> {
+    let response = reqwest::blocking::get(url).map_err(|err| CliError::failed_to_query_mapping(label, err))?;
+    let status = response.status();
+    let body = response.text().map_err(|err| CliError::failed_to_query_mapping(label, err))?;
+    if !status.is_success() {
+        return Err(CliError::failed_to_query_mapping(label, format!("{status} {body}")).into());
+    }
+    serde_json::from_str(&body).map_err(|err| CliError::failed_to_query_mapping(label, err).into())
+}
+
+/// Parses a key or value read from a mapping.
+fn parse_plaintext(string: &str, label: &str) -> Result<Plaintext<CurrentNetwork>> {
+    Plaintext::from_str(string).map_err(|err| CliError::failed_to_query_mapping(label, err).into())
+}
+
+/// Parses a key given on the command line with the declared key type of the mapping.
+pub(crate) fn parse_key(
+    program: &Program<CurrentNetwork>,
+    key_type: &PlaintextType<CurrentNetwork>,
+    key: &str,
+) -> Result<Plaintext<CurrentNetwork>> {
+    let invalid_key = |error: &dyn std::fmt::Display| CliError::invalid_mapping_key(key, leo_type(key_type), error);
+
+    let plaintext = match (Plaintext::from_str(key), key_type) {
+        (Ok(plaintext), _) => plaintext,
+        // Literal keys may omit their type suffix.
+        (Err(error), PlaintextType::Literal(literal_type)) => {
+            Plaintext::from_str(&format!("{key}{literal_type}")).map_err(|_| invalid_key(&error))?
+        }
+        (Err(error), _) => return Err(invalid_key(&error).into()),
+    };
+    check_type(program, &plaintext, key_type).map_err(|error| invalid_key(&error))?;
+    Ok(plaintext)
+}
+
+/// Checks that the plaintext is of the given type.
+pub(crate) fn check_type(
+    program: &Program<CurrentNetwork>,
+    plaintext: &Plaintext<CurrentNetwork>,
+    type_: &PlaintextType<CurrentNetwork>,
+) -> std::result::Result<(), String> {
+    match (plaintext, type_) {
+        (Plaintext::Literal(literal, _), PlaintextType::Literal(literal_type))
+            if literal.to_type() == *literal_type =>
+        {
+            Ok(())
+        }
+        (Plaintext::Struct(members, _), PlaintextType::Struct(name)) => {
+            let declaration = program.get_struct(name).map_err(|err| err.to_string())?;
+            if members.len() != declaration.members().len() {
+                return Err(format!("Expected the {} members of `{name}`.", declaration.members().len()));
+            }
+            for ((member, value), (expected_member, expected_type)) in members.iter().zip(declaration.members()) {
+                if member != expected_member {
+                    return Err(format!("Expected the member `{expected_member}`, found `{member}`."));
+                }
+                check_type(program, value, expected_type)?;
+            }
+            Ok(())
+        }
+        (Plaintext::Array(elements, _), PlaintextType::Array(array_type)) => {
+            let length = **array_type.length() as usize;
+            if elements.len() != length {
+                return Err(format!("Expected an array of {length} elements."));
+            }
+            elements.iter().try_for_each(|element| check_type(program, element, array_type.next_element_type()))
+        }
+        _ => Err(format!("Expected a value of type `{}`.", leo_type(type_))),
+    }
+}
+
+/// Returns the Leo name of the type.
+fn leo_type(type_: &PlaintextType<CurrentNetwork>) -> String {
+    match type_ {
+        PlaintextType::Literal(LiteralType::Boolean) => "bool".to_string(),
+        PlaintextType::Literal(literal_type) => literal_type.to_string(),
+        PlaintextType::Struct(name) => name.to_string(),
+        PlaintextType::Array(array_type) => {
+            format!("[{}; {}]", leo_type(array_type.next_element_type()), **array_type.length())
+        }
+    }
+}
+
+/// Returns the plaintext as a Leo expression of the given type.
+fn leo_value(
+    program: &Program<CurrentNetwork>,
+    plaintext: &Plaintext<CurrentNetwork>,
+    type_: &PlaintextType<CurrentNetwork>,
+) -> String {
+    match (plaintext, type_) {
+        (Plaintext::Struct(members, _), PlaintextType::Struct(name)) => {
+            let member_types = program.get_struct(name).map(|declaration| declaration.members().clone()).ok();
+            let members = members
+                .iter()
+                .map(|(member, value)| match member_types.as_ref().and_then(|types| types.get(member)) {
+                    Some(member_type) => format!("{member}: {}", leo_value(program, value, member_type)),
+                    None => format!("{member}: {value}"),
+                })
+                .collect::<Vec<_>>();
+            format!("{name} {{ {} }}", members.join(", "))
+        }
+        (Plaintext::Array(elements, _), PlaintextType::Array(array_type)) => {
+            let elements = elements
+                .iter()
+                .map(|element| leo_value(program, element, array_type.next_element_type()))
+                .collect::<Vec<_>>();
+            format!("[{}]", elements.join(", "))
+        }
+        _ => plaintext.to_string(),
+    }
+}
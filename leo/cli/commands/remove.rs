@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_package::dependencies::{Dependencies, LockFile};
+
+/// Remove a dependency from the current package.
+#[derive(Parser, Debug)]
+pub struct Remove {
+    #[clap(name = "NAME", help = "The program id of the dependency, e.g. `token.aleo`")]
+    pub(crate) name: String,
+}
+
+impl Command for Remove {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let package_path = context.dir()?;
+        let name = Dependencies::program_id(&self.name);
+
+        // Remove the declaration of the dependency from the manifest.
+        let mut dependencies = Dependencies::read_from(&package_path)?;
+        if dependencies.shift_remove(&name).is_none() {
+            return Err(PackageError::dependency_not_declared(name).into());
+        }
+        Dependencies::write_to(&package_path, &dependencies)?;
+
+        // Unpin the dependency, along with the dependencies which were only needed by it.
+        let resolved = Dependencies::resolve(&package_path)?;
+        LockFile::read_from(&package_path)?.update(&resolved, &[])?.write_to(&package_path)?;
+
+        tracing::info!("✅ Removed the dependency '{name}'");
+
+        Ok(())
+    }
+}
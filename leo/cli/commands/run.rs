@@ -19,7 +19,7 @@ use super::*;
 use snarkvm::cli::Run as SnarkVMRun;
 
 /// Build, Prove and Run Leo program with inputs
-#[derive(Parser, Debug)]
+#[derive(Parser, Clone, Debug)]
 pub struct Run {
     #[clap(name = "NAME", help = "The name of the program to run.", default_value = "main")]
     name: String,
@@ -48,7 +48,7 @@ impl Command for Run {
         // Otherwise, use the input file.
         let mut inputs = match self.inputs.is_empty() {
             true => match input {
-                (Some(input_ast), circuits) => input_ast.program_inputs(&self.name, circuits),
+                (Some(input_ast), circuits, _) => input_ast.program_inputs(&self.name, circuits),
                 _ => Vec::new(),
             },
             false => self.inputs,
@@ -0,0 +1,224 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use super::{
+    build::{create_outputs_directory, import_paths},
+    debug::{has_console_statements, signed_interpreter, type_checked_program},
+    deploy::{load_process, load_programs},
+};
+
+use leo_ast::Function;
+use leo_compiler::{Compiler, CompilerOptions};
+use leo_package::{
+    dependencies::Dependencies,
+    source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+    tests::{TestsDirectory, TESTS_DIRECTORY_NAME},
+};
+use leo_span::{sym, Symbol};
+
+use snarkvm::{
+    circuit::AleoV0,
+    ledger::{
+        block::Execution,
+        store::{helpers::memory::FinalizeMemory, FinalizeStore},
+    },
+    prelude::{Address, Identifier, Network, PrivateKey, Process, Program, ProgramID, Value},
+    synthesizer::program::FinalizeGlobalState,
+};
+
+use std::str::FromStr;
+
+type CurrentAleo = AleoV0;
+
+/// Compiles the tests in the `tests/` directory and runs them against a local VM.
+#[derive(Parser, Clone, Debug)]
+pub struct Test {
+    #[clap(name = "FILTER", help = "Only runs the tests whose names contain the filter.")]
+    pub(crate) filter: Option<String>,
+
+    #[clap(flatten)]
+    pub(crate) compiler_options: BuildOptions,
+}
+
+impl Command for Test {
+    type Input = <Build as Command>::Output;
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, context: Context) -> Result<Self::Input> {
+        (Build { options: self.compiler_options.clone() }).execute(context)
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        // Get the package path and the program id.
+        let package_path = context.dir()?;
+        let manifest = context.open_manifest()?;
+        let program_id = manifest.program_id();
+
+        if TestsDirectory::is_empty(&package_path)? {
+            tracing::info!("No tests found in '{TESTS_DIRECTORY_NAME}'");
+            return Ok(());
+        }
+
+        // Fetch paths to all .leo files in the tests directory.
+        let test_files = TestsDirectory::files(&package_path)?;
+
+        // Compile the main program together with the tests.
+        // Note that the test program only lives in memory, so no AST snapshots are written over those of the build.
+        let mut compiler_options: CompilerOptions = self.compiler_options.clone().into();
+        compiler_options.output = Default::default();
+        let handler = context.handler();
+        let mut compiler = Compiler::new(
+            program_id.name().to_string(),
+            program_id.network().to_string(),
+            &handler,
+            package_path.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME),
+            create_outputs_directory(&package_path)?,
+            Some(compiler_options),
+        );
+        compiler.import_paths = import_paths(&package_path, &Dependencies::resolve(&package_path)?)?;
+        let (_, instructions) = compiler.compile_tests(&test_files)?;
+        let test_program =
+            Program::<CurrentNetwork>::from_str(&instructions).map_err(CliError::failed_to_load_instructions)?;
+        tracing::info!("✅ Compiled the tests of '{}' into Aleo instructions", program_id);
+
+        // Collect the test transitions, in declaration order, and whether they are expected to fail.
+        let tests: Vec<(String, bool)> = compiler
+            .ast
+            .ast
+            .program_scopes
+            .values()
+            .flat_map(|scope| scope.functions.iter().map(|(_, function)| function))
+            .filter(|function| has_annotation(function, sym::test))
+            .map(|function| (function.identifier.to_string(), has_annotation(function, sym::should_fail)))
+            .collect();
+
+        // Load the programs in the build/imports/ directory.
+        let build_directory = BuildDirectory::open(&package_path).map_err(|_| CliError::needs_leo_build())?;
+        let (_, imports) = load_programs(&build_directory, program_id)?;
+
+        // Initialize a process with the imports, followed by the program under test.
+        let (process, programs) = load_process(&test_program, &imports).map_err(CliError::failed_to_execute_test)?;
+
+        // Load the private key used to sign the test transitions.
+        let private_key = context.dotenv_private_key()?;
+
+        // Console statements are removed from the Aleo instructions, so if the program or its tests contain any,
+        // each test is first evaluated by the interpreter, which prints the logs and checks the assertions.
+        let program = type_checked_program(&package_path, program_id, &test_files, &handler)?;
+        let console_program = has_console_statements(&program).then_some(program);
+        let signer = Address::try_from(&private_key).map_err(CliError::failed_to_execute_test)?;
+
+        // Initialize an RNG.
+        let rng = &mut rand::thread_rng();
+
+        let (selected, filtered): (Vec<_>, Vec<_>) = tests
+            .into_iter()
+            .partition(|(name, _)| self.filter.as_ref().is_none_or(|filter| name.contains(filter.as_str())));
+
+        tracing::info!("🧪 Running {} test(s) of '{}'\n", selected.len(), program_id);
+
+        let mut failed = 0;
+        for (name, should_fail) in selected.iter() {
+            // Each test runs against a fresh mapping store, so that tests do not observe each other's state.
+            let outcome = match &console_program {
+                Some(program) => interpret_test(program, &signer, name),
+                None => Ok(()),
+            }
+            .and_then(|()| run_test(&process, &programs, &private_key, test_program.id(), name, rng));
+            match (outcome, should_fail) {
+                (Ok(()), false) | (Err(_), true) => tracing::info!("✅ {name} ... {}", "ok".green()),
+                (Ok(()), true) => {
+                    failed += 1;
+                    tracing::info!("❌ {name} ... {}\n    Expected the test to fail.", "FAILED".red());
+                }
+                (Err(error), false) => {
+                    failed += 1;
+                    tracing::info!("❌ {name} ... {}\n    {error}", "FAILED".red());
+                }
+            }
+        }
+
+        tracing::info!(
+            "\nTest result: {} passed; {} failed; {} filtered out",
+            selected.len() - failed,
+            failed,
+            filtered.len()
+        );
+
+        match failed {
+            0 => Ok(()),
+            _ => Err(CliError::tests_failed(failed, selected.len()).into()),
+        }
+    }
+}
+
+/// Returns `true` if the function is annotated with the given annotation.
+fn has_annotation(function: &Function, name: Symbol) -> bool {
+    function.annotations.iter().any(|annotation| annotation.identifier.name == name)
+}
+
+/// Evaluates the test transition and its finalize block in the interpreter, returning the error message if either fails.
+fn interpret_test(
+    program: &leo_ast::Program,
+    signer: &Address<CurrentNetwork>,
+    name: &str,
+) -> std::result::Result<(), String> {
+    let mut interpreter = signed_interpreter(program, signer);
+    interpreter.call(Symbol::intern(name), Vec::new()).map(|_| ()).map_err(|err| err.to_string())
+}
+
+/// Executes the test transition and its finalize block, returning the error message if either fails.
+fn run_test(
+    process: &Process<CurrentNetwork>,
+    programs: &[&Program<CurrentNetwork>],
+    private_key: &PrivateKey<CurrentNetwork>,
+    program_id: &ProgramID<CurrentNetwork>,
+    name: &str,
+    rng: &mut rand::rngs::ThreadRng,
+) -> std::result::Result<(), String> {
+    let function_name = Identifier::<CurrentNetwork>::from_str(name).map_err(|err| err.to_string())?;
+
+    // Initialize an in-memory store with the mappings of every loaded program.
+    let store =
+        FinalizeStore::<CurrentNetwork, FinalizeMemory<CurrentNetwork>>::open(None).map_err(|err| err.to_string())?;
+    for program in programs {
+        for mapping_name in program.mappings().keys() {
+            store.initialize_mapping(*program.id(), *mapping_name).map_err(|err| err.to_string())?;
+        }
+    }
+
+    // Execute the transition. Test transitions take no inputs.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(private_key, program_id, function_name, std::iter::empty::<Value<_>>(), rng)
+        .map_err(|err| err.to_string())?;
+    let (_, trace) = process.execute::<CurrentAleo>(authorization).map_err(|err| err.to_string())?;
+
+    // Run the finalize blocks of the execution against the store.
+    // Note that the execution is not proven, since `finalize_execution` does not verify proofs.
+    let execution =
+        Execution::from(trace.transitions().iter().cloned(), <CurrentNetwork as Network>::StateRoot::default(), None)
+            .map_err(|err| err.to_string())?;
+    let state = FinalizeGlobalState::new_genesis::<CurrentNetwork>().map_err(|err| err.to_string())?;
+    process.finalize_execution(state, &store, &execution, None).map_err(|err| err.to_string())?;
+
+    Ok(())
+}
@@ -0,0 +1,412 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::cli::commands::build::import_paths;
+use leo_ast::*;
+use leo_errors::{emitter::Handler, LeoError};
+use leo_package::{
+    dependencies::Dependencies,
+    source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+use leo_passes::{ImportResolver, ModuleResolver, Pass, SymbolTableCreator, TypeAliasResolver, TypeChecker, TypeTable};
+use leo_span::{
+    source_map::FileName,
+    span::Pos,
+    symbol::{SessionGlobals, SESSION_GLOBALS},
+    sym,
+    Span,
+    Symbol,
+};
+
+use std::{
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+/// The modules that may contain core functions, e.g. `BHP256` in `BHP256::hash_to_field`.
+const CORE_MODULES: &[&str] = &[
+    "BHP256",
+    "BHP512",
+    "BHP768",
+    "BHP1024",
+    "ChaCha",
+    "Keccak256",
+    "Keccak384",
+    "Keccak512",
+    "Mapping",
+    "Pedersen64",
+    "Pedersen128",
+    "Poseidon2",
+    "Poseidon4",
+    "Poseidon8",
+    "SHA3_256",
+    "SHA3_384",
+    "SHA3_512",
+    "group",
+    "signature",
+];
+
+/// The names of the functions that may be defined on a core module.
+const CORE_FUNCTIONS: &[&str] =
+    &["contains", "get", "get_or_use", "remove", "set", "to_x_coordinate", "to_y_coordinate", "verify"];
+
+/// The types that core functions may commit, hash, or sample to, e.g. `field` in `BHP256::hash_to_field`.
+const CORE_FUNCTION_TYPES: &[&str] = &[
+    "address", "bool", "field", "group", "i8", "i16", "i32", "i64", "i128", "u8", "u16", "u32", "u64", "u128", "scalar",
+];
+
+/// A core function that can be offered as a completion.
+pub struct CoreFunctionCompletion {
+    /// The module of the core function, e.g. `BHP256`.
+    pub module: String,
+    /// The name of the core function, e.g. `hash_to_field`.
+    pub function: String,
+    /// The number of arguments of the core function.
+    pub num_args: usize,
+}
+
+/// Returns the core functions, as recognized by `CoreFunction::from_symbols`.
+pub fn core_function_completions() -> Vec<CoreFunctionCompletion> {
+    let functions = CORE_FUNCTIONS
+        .iter()
+        .map(|function| function.to_string())
+        .chain(CORE_FUNCTION_TYPES.iter().flat_map(|type_| {
+            ["commit_to_", "hash_to_", "rand_"].into_iter().map(move |prefix| format!("{prefix}{type_}"))
+        }))
+        .collect::<Vec<_>>();
+
+    let mut completions = Vec::new();
+    for module in CORE_MODULES {
+        for function in functions.iter() {
+            if let Some(core_function) = CoreFunction::from_symbols(Symbol::intern(module), Symbol::intern(function)) {
+                completions.push(CoreFunctionCompletion {
+                    module: module.to_string(),
+                    function: function.clone(),
+                    num_args: core_function.num_args(),
+                });
+            }
+        }
+    }
+    completions
+}
+
+/// An error or warning reported for a Leo source file.
+pub struct Diagnostic {
+    /// The byte range of the source the diagnostic refers to.
+    pub range: Range<usize>,
+    /// The code of the error or warning, e.g. `ETYC0372003`.
+    pub code: String,
+    /// The message of the diagnostic, including its help, if any.
+    pub message: String,
+    /// Is this diagnostic an error or a warning?
+    pub error: bool,
+}
+
+/// The result of parsing and type checking a single Leo source file.
+#[derive(Default)]
+pub struct Analysis {
+    /// The errors and warnings emitted for the file.
+    pub diagnostics: Vec<Diagnostic>,
+    /// The byte ranges of the expressions in the file, along with their types.
+    types: Vec<(Range<usize>, String)>,
+    /// The byte ranges of the references to structs, records, type aliases, functions, and mappings,
+    /// along with the byte ranges of their definitions.
+    definitions: Vec<(Range<usize>, Range<usize>)>,
+}
+
+impl Analysis {
+    /// Parses and type checks the given source, collecting the diagnostics, types, and definitions of the file.
+    /// Imports are resolved in the given package root, or in the current directory if there is none.
+    /// The files of the `src/` directory other than `main.leo` are modules, which are only parsed.
+    pub fn new(name: FileName, source: &str, package_root: Option<PathBuf>) -> Self {
+        let package_root = package_root.unwrap_or_default();
+        // The modules of the program are in the directory of the file.
+        let (directory, is_module) = match &name {
+            FileName::Real(path) => {
+                let directory = path.parent().unwrap_or(Path::new("")).to_path_buf();
+                let is_module = directory.ends_with(SOURCE_DIRECTORY_NAME) && !path.ends_with(MAIN_FILENAME);
+                (directory, is_module)
+            }
+            _ => (package_root.join(SOURCE_DIRECTORY_NAME), false),
+        };
+
+        // The file is analyzed in a fresh session, so that the source map does not grow with every edit.
+        let globals = SessionGlobals::default();
+        SESSION_GLOBALS.set(&globals, || {
+            let source_file = globals.source_map.new_source(source, name);
+            let (handler, buf) = Handler::new_with_buf();
+            let mut analysis = Self::default();
+
+            // Converts a span into a byte range of the file, if the span lies in the file.
+            let to_range = |span: Span| {
+                (span.lo >= source_file.start_pos && span.hi <= source_file.end_pos)
+                    .then(|| (span.lo - source_file.start_pos).to_usize()..(span.hi - source_file.start_pos).to_usize())
+            };
+
+            // Errors returned by the passes have already been emitted to the handler.
+            let emit = |err: LeoError| {
+                if !matches!(err, LeoError::LastErrorCode(_)) {
+                    handler.emit_err(err)
+                }
+            };
+
+            let node_builder = NodeBuilder::default();
+
+            // Dependencies which cannot be resolved are ignored here; building the package reports them.
+            let import_paths = Dependencies::resolve(&package_root)
+                .and_then(|dependencies| import_paths(&package_root, &dependencies))
+                .unwrap_or_default();
+            let ast = match is_module {
+                // Modules are only parsed here; they are checked as part of the program that declares them.
+                true => {
+                    let program_id = ProgramId {
+                        name: Identifier::new(sym::main, NodeID::default()),
+                        network: Identifier::new(sym::aleo, NodeID::default()),
+                    };
+                    leo_parser::parse_module(
+                        &handler,
+                        &node_builder,
+                        &source_file.src,
+                        source_file.start_pos,
+                        program_id,
+                    )
+                    .map(|_| None)
+                }
+                false => leo_parser::parse_ast(&handler, &node_builder, &source_file.src, source_file.start_pos)
+                    .and_then(|ast| ModuleResolver::do_pass((ast, &handler, &node_builder, &directory)))
+                    .and_then(|ast| {
+                        ImportResolver::do_pass((ast, &handler, &node_builder, &package_root, &import_paths))
+                    })
+                    .map(Some),
+            };
+            match ast {
+                Err(err) => emit(err),
+                Ok(None) => {}
+                Ok(Some(ast)) => {
+                    let type_table = TypeTable::default();
+                    // The aliases are resolved in a copy of the AST, so that the collector still sees their uses.
+                    let symbol_table = match SymbolTableCreator::do_pass((&ast, &handler)).and_then(|symbol_table| {
+                        TypeAliasResolver::do_pass((ast.clone(), &handler, &node_builder, symbol_table))
+                    }) {
+                        Ok((resolved, symbol_table)) => {
+                            if let Err(err) =
+                                TypeChecker::do_pass((&resolved, &handler, symbol_table.clone(), &type_table))
+                            {
+                                emit(err);
+                            }
+                            Some(symbol_table)
+                        }
+                        Err(err) => {
+                            emit(err);
+                            None
+                        }
+                    };
+
+                    let mut collector = Collector::default();
+                    collector.visit_program(ast.as_repr());
+
+                    // Note that the types of expressions which failed to type check are absent from the type table.
+                    for (span, id) in collector.expressions {
+                        if let (Some(range), Some(type_)) = (to_range(span), type_table.get(&id)) {
+                            analysis.types.push((range, type_.to_string()));
+                        }
+                    }
+
+                    if let Some(symbol_table) = symbol_table {
+                        for reference in collector.references {
+                            let definition = symbol_table
+                                .lookup_struct(reference.name)
+                                .map(|struct_| struct_.identifier.span)
+                                .or_else(|| symbol_table.lookup_enum(reference.name).map(|enum_| enum_.identifier.span))
+                                .or_else(|| {
+                                    symbol_table.lookup_type_alias(reference.name).map(|alias| alias.identifier.span)
+                                })
+                                .or_else(|| symbol_table.lookup_fn_symbol(reference.name).map(|function| function.span))
+                                .or_else(|| symbol_table.lookup_variable(reference.name).map(|mapping| mapping.span));
+                            if let (Some(range), Some(definition)) =
+                                (to_range(reference.span), definition.and_then(to_range))
+                            {
+                                analysis.definitions.push((range, definition));
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Errors without a span, or with a span in another file, are reported at the start of the file.
+            for err in buf.extract_errs().into_inner() {
+                let message = match err.help() {
+                    Some(help) => format!("{}\n{help}", err.message()),
+                    None => err.message(),
+                };
+                analysis.diagnostics.push(Diagnostic {
+                    range: err.span().and_then(to_range).unwrap_or(0..0),
+                    code: err.error_code(),
+                    message,
+                    error: true,
+                });
+            }
+            for warning in buf.extract_warnings().into_inner() {
+                analysis.diagnostics.push(Diagnostic {
+                    range: warning.span().and_then(to_range).unwrap_or(0..0),
+                    code: warning.error_code(),
+                    message: warning.message(),
+                    error: false,
+                });
+            }
+
+            analysis
+        })
+    }
+
+    /// Returns the type of the innermost expression at `offset`, along with the byte range of the expression.
+    pub fn type_at(&self, offset: usize) -> Option<(Range<usize>, &str)> {
+        self.types
+            .iter()
+            .filter(|(range, _)| range.contains(&offset))
+            .min_by_key(|(range, _)| range.len())
+            .map(|(range, type_)| (range.clone(), type_.as_str()))
+    }
+
+    /// Returns the byte range of the definition of the struct, record, enum, function, or mapping referenced at `offset`.
+    pub fn definition_at(&self, offset: usize) -> Option<Range<usize>> {
+        self.definitions.iter().find(|(range, _)| range.contains(&offset)).map(|(_, definition)| definition.clone())
+    }
+}
+
+/// Collects the expressions of a program, along with the identifiers that may refer to
+/// a struct, record, enum, function, or mapping.
+#[derive(Default)]
+struct Collector {
+    /// The spans and IDs of the expressions.
+    expressions: Vec<(Span, NodeID)>,
+    /// The identifiers that may refer to a named item of the program.
+    references: Vec<Identifier>,
+}
+
+impl Collector {
+    /// Collects the struct and record names referenced by the type.
+    fn visit_type(&mut self, input: &Type) {
+        match input {
+            Type::Identifier(identifier) => self.references.push(*identifier),
+            Type::Array(array) => self.visit_type(array.element_type()),
+            Type::Tuple(tuple) => tuple.elements().iter().for_each(|type_| self.visit_type(type_)),
+            Type::Mapping(mapping) => {
+                self.visit_type(&mapping.key);
+                self.visit_type(&mapping.value);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for Collector {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_expression(&mut self, input: &'a Expression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.expressions.push((input.span(), input.id()));
+
+        match input {
+            Expression::Access(access) => self.visit_access(access, additional),
+            Expression::Array(array) => self.visit_array(array, additional),
+            Expression::Binary(binary) => self.visit_binary(binary, additional),
+            Expression::Call(call) => self.visit_call(call, additional),
+            Expression::Cast(cast) => self.visit_cast(cast, additional),
+            Expression::Struct(struct_) => self.visit_struct_init(struct_, additional),
+            Expression::Err(err) => self.visit_err(err, additional),
+            Expression::Identifier(identifier) => self.visit_identifier(identifier, additional),
+            Expression::Literal(literal) => self.visit_literal(literal, additional),
+            Expression::Match(match_) => self.visit_match(match_, additional),
+            Expression::Repeat(repeat) => self.visit_repeat(repeat, additional),
+            Expression::Ternary(ternary) => self.visit_ternary(ternary, additional),
+            Expression::Tuple(tuple) => self.visit_tuple(tuple, additional),
+            Expression::Unary(unary) => self.visit_unary(unary, additional),
+            Expression::Unit(unit) => self.visit_unit(unit, additional),
+        }
+    }
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.function, additional);
+        input.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+    }
+
+    fn visit_cast(&mut self, input: &'a CastExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.expression, additional);
+        self.visit_type(&input.type_);
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.references.push(input.name);
+        input
+            .members
+            .iter()
+            .filter_map(|member| member.expression.as_ref())
+            .for_each(|expression| self.visit_expression(expression, additional));
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.references.push(*input);
+    }
+}
+
+impl<'a> StatementVisitor<'a> for Collector {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        self.visit_expression(&input.place, &());
+        self.visit_expression(&input.value, &());
+    }
+
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        self.visit_type(&input.type_);
+        self.visit_expression(&input.value, &());
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        self.visit_type(&input.type_);
+        self.visit_expression(&input.place, &());
+        self.visit_expression(&input.value, &());
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for Collector {
+    fn visit_type_alias(&mut self, input: &'a TypeAlias) {
+        self.visit_type(&input.type_);
+    }
+
+    fn visit_struct(&mut self, input: &'a Struct) {
+        input.members.iter().for_each(|member| self.visit_type(&member.type_));
+    }
+
+    fn visit_enum(&mut self, input: &'a Enum) {
+        input.variants.iter().filter_map(|variant| variant.payload.as_ref()).for_each(|type_| self.visit_type(type_));
+    }
+
+    fn visit_mapping(&mut self, input: &'a Mapping) {
+        self.visit_type(&input.key_type);
+        self.visit_type(&input.value_type);
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        input.input.iter().for_each(|input| self.visit_type(&input.type_()));
+        self.visit_type(&input.output_type);
+        self.visit_block(&input.block);
+        if let Some(finalize) = &input.finalize {
+            finalize.input.iter().for_each(|input| self.visit_type(&input.type_()));
+            self.visit_type(&finalize.output_type);
+            self.visit_block(&finalize.block);
+        }
+    }
+}
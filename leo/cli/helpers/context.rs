@@ -15,29 +15,58 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use leo_errors::{CliError, PackageError, Result};
-use leo_package::build::{BuildDirectory, BUILD_DIRECTORY_NAME};
+use leo_errors::{
+    emitter::{Handler, JsonEmitter},
+    CliError,
+    PackageError,
+    Result,
+};
+use leo_package::{
+    build::{BuildDirectory, BUILD_DIRECTORY_NAME},
+    root::ENV_FILENAME,
+};
 
-use snarkvm::file::Manifest;
+use snarkvm::{file::Manifest, prelude::PrivateKey};
 
 use std::{
     env::current_dir,
     fs::File,
     io::Write,
     path::{Path, PathBuf},
+    str::FromStr,
 };
 
+/// The format in which errors and warnings are emitted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum MessageFormat {
+    /// Human-readable text, with the offending source underlined.
+    #[default]
+    Human,
+    /// One JSON object per error or warning.
+    Json,
+}
+
 /// Project context, manifest, current directory etc
 /// All the info that is relevant in most of the commands
 #[derive(Clone)]
 pub struct Context {
     /// Path at which the command is called, None when default
     pub path: Option<PathBuf>,
+    /// The format in which errors and warnings are emitted
+    pub message_format: MessageFormat,
 }
 
 impl Context {
-    pub fn new(path: Option<PathBuf>) -> Result<Context> {
-        Ok(Context { path })
+    pub fn new(path: Option<PathBuf>, message_format: MessageFormat) -> Result<Context> {
+        Ok(Context { path, message_format })
+    }
+
+    /// Returns a handler that emits errors and warnings in the requested format.
+    pub fn handler(&self) -> Handler {
+        match self.message_format {
+            MessageFormat::Human => Handler::default(),
+            MessageFormat::Json => Handler::new(Box::new(JsonEmitter::default())),
+        }
     }
 
     /// Returns the path to the Leo package.
@@ -82,4 +111,16 @@ impl Context {
         // Get package name from program id.
         Ok(manifest)
     }
+
+    /// Returns the private key stored as `PRIVATE_KEY` in the `.env` file of the package.
+    pub fn dotenv_private_key(&self) -> Result<PrivateKey<CurrentNetwork>> {
+        let env_path = self.dir()?.join(ENV_FILENAME);
+        let private_key = dotenvy::from_path_iter(&env_path)
+            .map_err(|err| CliError::failed_to_load_private_key(env_path.display(), err))?
+            .filter_map(|item| item.ok())
+            .find_map(|(key, value)| (key == "PRIVATE_KEY").then_some(value))
+            .ok_or_else(|| CliError::failed_to_load_private_key(env_path.display(), "`PRIVATE_KEY` is not set"))?;
+        Ok(PrivateKey::from_str(&private_key)
+            .map_err(|err| CliError::failed_to_load_private_key(env_path.display(), err))?)
+    }
 }
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use leo_errors::{CliError, PackageError, Result};
+use leo_package::ledger::{LedgerDirectory, MappingEntries, MappingsFile};
+
+use snarkvm::{
+    ledger::store::{helpers::memory::FinalizeMemory, FinalizeStore},
+    prelude::{Plaintext, Program, Value},
+    synthesizer::program::FinalizeStoreTrait,
+};
+
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// The mapping state of a package, persisted in its `.leo/ledger/` directory.
+///
+/// The mappings are loaded into an in-memory finalize store, against which finalize blocks are run,
+/// and written back with `save` once the finalize blocks succeed.
+pub struct LocalLedger {
+    /// The path to the `.leo/ledger/` directory.
+    path: PathBuf,
+    /// The finalize store holding the mappings of the loaded programs.
+    store: FinalizeStore<CurrentNetwork, FinalizeMemory<CurrentNetwork>>,
+}
+
+impl LocalLedger {
+    /// Loads the mappings of the given programs from the `.leo/ledger/` directory of the package.
+    pub fn open(package_path: &Path, programs: &[&Program<CurrentNetwork>]) -> Result<Self> {
+        let path = LedgerDirectory::create(package_path)?;
+        let store = FinalizeStore::open(None).map_err(CliError::failed_to_load_local_ledger)?;
+
+        for program in programs {
+            let file = MappingsFile::new(&program.id().to_string());
+            let mut entries = file.read_from(&path)?;
+
+            for mapping_name in program.mappings().keys() {
+                store
+                    .initialize_mapping(*program.id(), *mapping_name)
+                    .map_err(CliError::failed_to_load_local_ledger)?;
+
+                for (key, value) in entries.shift_remove(&mapping_name.to_string()).unwrap_or_default() {
+                    let key = Plaintext::from_str(&key).map_err(CliError::failed_to_load_local_ledger)?;
+                    let value = Value::from_str(&value).map_err(CliError::failed_to_load_local_ledger)?;
+                    store
+                        .insert_key_value(*program.id(), *mapping_name, key, value)
+                        .map_err(CliError::failed_to_load_local_ledger)?;
+                }
+            }
+        }
+
+        Ok(Self { path, store })
+    }
+
+    /// Returns the finalize store holding the mappings.
+    pub fn store(&self) -> &FinalizeStore<CurrentNetwork, FinalizeMemory<CurrentNetwork>> {
+        &self.store
+    }
+
+    /// Writes the mappings of the given programs back to the `.leo/ledger/` directory.
+    pub fn save(&self, programs: &[&Program<CurrentNetwork>]) -> Result<()> {
+        for program in programs {
+            let mut entries = MappingEntries::new();
+            for mapping_name in program.mappings().keys() {
+                let mapping = self
+                    .store
+                    .get_mapping_confirmed(*program.id(), *mapping_name)
+                    .map_err(|err| PackageError::failed_to_write_ledger_file(self.path.display(), err))?;
+                entries.insert(
+                    mapping_name.to_string(),
+                    mapping.into_iter().map(|(key, value)| (key.to_string(), value.to_string())).collect(),
+                );
+            }
+            MappingsFile::new(&program.id().to_string()).write_to(&self.path, &entries)?;
+        }
+        Ok(())
+    }
+}
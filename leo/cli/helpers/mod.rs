@@ -16,6 +16,9 @@
 
 pub use super::*;
 
+pub mod analysis;
 pub mod context;
+pub mod ledger;
 pub mod logger;
 pub mod updater;
+pub mod workspace;
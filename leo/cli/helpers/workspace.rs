@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::NodeBuilder;
+use leo_errors::{emitter::Handler, CompilerError, PackageError, Result};
+use leo_package::{
+    dependencies::Dependencies,
+    source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+    workspace::Workspace,
+};
+use leo_passes::{DiGraphError, ImportGraph};
+use leo_span::{symbol::with_session_globals, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+use std::path::{Path, PathBuf};
+
+/// Returns the members of the workspace at `root` by program id, ordered so that each member comes after the
+/// members it imports. If any package is given, by program name or id, only those members are returned.
+pub fn workspace_members(root: &Path, packages: &[String], handler: &Handler) -> Result<IndexMap<String, PathBuf>> {
+    let mut members = Workspace::read_from(root)?.members(root)?;
+
+    let packages = packages.iter().map(|name| Dependencies::program_id(name)).collect::<Vec<_>>();
+    if let Some(name) = packages.iter().find(|name| !members.contains_key(*name)) {
+        return Err(PackageError::workspace_member_not_found(name).into());
+    }
+
+    // The members are ordered by the imports declared in their main files.
+    let name = |program_id: &str| Symbol::intern(program_id.trim_end_matches(".aleo"));
+    let names = members.keys().map(|program_id| name(program_id)).collect::<IndexSet<_>>();
+    let mut import_graph = ImportGraph::new(names.clone());
+    for (program_id, location) in members.iter() {
+        let file_path = location.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME);
+        let source_file = with_session_globals(|s| s.source_map.load_file(&file_path))
+            .map_err(|e| CompilerError::file_read_error(&file_path, e))?;
+        let ast = leo_parser::parse_ast(handler, &NodeBuilder::default(), &source_file.src, source_file.start_pos)?;
+        for import in ast.as_repr().imports.keys().filter(|import| names.contains(*import)) {
+            import_graph.add_edge(name(program_id), *import);
+        }
+    }
+    let order = match import_graph.post_order() {
+        Ok(order) => order,
+        Err(DiGraphError::CycleDetected(path)) => return Err(CompilerError::cyclic_imports(path).into()),
+    };
+
+    Ok(order
+        .into_iter()
+        .map(|name| Dependencies::program_id(&name.to_string()))
+        .filter(|program_id| packages.is_empty() || packages.contains(program_id))
+        .filter_map(|program_id| members.swap_remove(&program_id).map(|location| (program_id, location)))
+        .collect())
+}
@@ -14,9 +14,37 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_errors::Result;
+use crate::cli::{
+    commands::{
+        debug::type_checked_program,
+        deploy::{dependency_order, load_process, load_programs, Deploy},
+        Add,
+        execute::execute_locally,
+        query::{check_type, parse_key, Source},
+        Build,
+        Command,
+        Remove,
+        Test,
+    },
+    helpers::{analysis::Analysis, context::Context, ledger::LocalLedger, workspace::workspace_members},
+    for_each_member,
+    CurrentNetwork,
+};
+use leo_errors::{emitter::Handler, CompilerError, LeoMessageCode, PackageError, Result};
+use leo_package::dependencies::{Dependencies, LockFile};
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
 // use std::path::PathBuf;
 
+use snarkvm::{
+    circuit::AleoV0,
+    ledger::block::{Execution, Transaction},
+    prelude::{Identifier, Network, Plaintext, PlaintextType, PrivateKey, Program, ProgramID, Value},
+    synthesizer::program::FinalizeStoreTrait,
+};
+
+use std::str::FromStr;
+use test_dir::{DirBuilder, TestDir};
+
 /* use crate::{
     commands::{
         // package::{Login, Logout},
@@ -44,6 +72,925 @@ pub fn format_event() -> Result<()> {
     Ok(())
 }
 
+const MAIN_PROGRAM: &str = "import child.aleo;
+import grandchild.aleo;
+
+program main_program.aleo;
+
+function main:
+    input r0 as u32.private;
+    call child.aleo/twice r0 into r1;
+    call grandchild.aleo/twice r1 into r2;
+    output r2 as u32.private;
+";
+
+const CHILD_PROGRAM: &str = "import grandchild.aleo;
+
+program child.aleo;
+
+function twice:
+    input r0 as u32.private;
+    call grandchild.aleo/twice r0 into r1;
+    output r1 as u32.private;
+";
+
+const GRANDCHILD_PROGRAM: &str = "program grandchild.aleo;
+
+function twice:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.private;
+";
+
+#[test]
+pub fn deploy_loads_compiled_imports() {
+    let build = package(&[
+        ("main.aleo", MAIN_PROGRAM),
+        ("imports/child.aleo", CHILD_PROGRAM),
+        ("imports/grandchild.aleo", GRANDCHILD_PROGRAM),
+    ]);
+
+    // The compiled imports are loaded, and each import is deployed after the programs it imports.
+    let (main_program, imports) =
+        load_programs(build.root(), &ProgramID::from_str("main_program.aleo").unwrap()).unwrap();
+    let order = dependency_order(&main_program, &imports);
+    assert_eq!(order, [ProgramID::from_str("grandchild.aleo").unwrap(), ProgramID::from_str("child.aleo").unwrap()]);
+}
+
+#[test]
+pub fn test_annotation_outside_tests_directory_fail() {
+    let package = package(&[
+        ("program.json", r#"{ "program": "annotated.aleo" }"#),
+        (
+            "src/main.leo",
+            "program annotated.aleo {\n    @test\n    transition foo() -> u8 {\n        return 1u8;\n    }\n}\n",
+        ),
+    ]);
+
+    // Test transitions must not be built into the program itself.
+    let context = Context::new(Some(package.root().to_path_buf()), Default::default()).unwrap();
+    let build = create_session_if_not_set_then(|_| (Build { options: Default::default() }).apply(context, ()));
+    assert!(build.is_err());
+}
+
+/// The private key used to sign the transitions run by the tests.
+const TEST_PRIVATE_KEY: &str = "APrivateKey1zkpH5Ne1Xfd79t61VhK7b6yaYz92yW5dbuVkiFheR7rwCDE";
+
+const COUNTER_SOURCE: &str = "program counter.aleo {
+    mapping counts: u8 => u8;
+
+    transition increment(key: u8) {
+        return then finalize(key);
+    }
+
+    finalize increment(key: u8) {
+        let count: u8 = Mapping::get_or_use(counts, key, 0u8);
+        Mapping::set(counts, key, count + 1u8);
+    }
+
+    transition reset_and_decrement(key: u8) {
+        return then finalize(key);
+    }
+
+    finalize reset_and_decrement(key: u8) {
+        Mapping::set(counts, key, 0u8);
+        let count: u8 = Mapping::get(counts, key);
+        Mapping::set(counts, key, count - 1u8);
+    }
+}
+";
+
+#[test]
+pub fn test_runs_tests() {
+    let package = package(&[
+        ("program.json", r#"{ "program": "counter.aleo" }"#),
+        (".env", &format!("NETWORK=testnet3\nPRIVATE_KEY={TEST_PRIVATE_KEY}\n")),
+        ("src/main.leo", COUNTER_SOURCE),
+        (
+            "tests/test_counter.leo",
+            "program counter.aleo {
+    @test
+    transition test_set_count() {
+        return then finalize(1u8);
+    }
+
+    finalize test_set_count(key: u8) {
+        Mapping::set(counts, key, 1u8);
+        assert_eq(Mapping::get(counts, key), 1u8);
+    }
+
+    @test
+    @should_fail
+    transition test_missing_count() {
+        return then finalize(1u8);
+    }
+
+    finalize test_missing_count(key: u8) {
+        assert_eq(Mapping::get(counts, key), 1u8);
+    }
+
+    @test
+    transition test_stale_count() {
+        return then finalize(1u8);
+    }
+
+    finalize test_stale_count(key: u8) {
+        assert_eq(Mapping::get_or_use(counts, key, 0u8), 1u8);
+    }
+}
+",
+        ),
+    ]);
+    let test = |filter: Option<&str>| {
+        let context = || Context::new(Some(package.root().to_path_buf()), Default::default()).unwrap();
+        create_session_if_not_set_then(|_| {
+            (Build { options: Default::default() }).apply(context(), ())?;
+            let test = Test { filter: filter.map(str::to_string), compiler_options: Default::default() };
+            test.apply(context(), Default::default())
+        })
+    };
+
+    // Each test runs against fresh mappings, so the count set by one test is not observed by the others.
+    assert!(test(None).unwrap_err().to_string().contains("1 of 3 tests failed"));
+
+    // A test passes if its transition and its finalize block succeed, unless it is expected to fail.
+    assert!(test(Some("set_count")).is_ok());
+    assert!(test(Some("missing_count")).is_ok());
+    assert!(test(Some("stale_count")).unwrap_err().to_string().contains("1 of 1 tests failed"));
+}
+
+#[test]
+pub fn test_honors_console_statements() {
+    let package = package(&[
+        ("program.json", r#"{ "program": "counter.aleo" }"#),
+        (".env", &format!("NETWORK=testnet3\nPRIVATE_KEY={TEST_PRIVATE_KEY}\n")),
+        ("src/main.leo", COUNTER_SOURCE),
+        (
+            "tests/test_counter.leo",
+            "program counter.aleo {
+    @test
+    transition test_console_assert() {
+        let count: u8 = 1u8;
+        console.log(\"count is {}\", count);
+        console.assert_eq(count, 2u8);
+        return then finalize(count);
+    }
+
+    finalize test_console_assert(count: u8) {
+        Mapping::set(counts, 1u8, count);
+    }
+
+    @test
+    @should_fail
+    transition test_console_should_fail() {
+        console.assert(false);
+        return then finalize(1u8);
+    }
+
+    finalize test_console_should_fail(count: u8) {
+        Mapping::set(counts, 1u8, count);
+    }
+}
+",
+        ),
+    ]);
+    let test = |filter: Option<&str>| {
+        let context = || Context::new(Some(package.root().to_path_buf()), Default::default()).unwrap();
+        create_session_if_not_set_then(|_| {
+            (Build { options: Default::default() }).apply(context(), ())?;
+            let test = Test { filter: filter.map(str::to_string), compiler_options: Default::default() };
+            test.apply(context(), Default::default())
+        })
+    };
+
+    // The console statements are not in the Aleo instructions, but a failing console assertion still fails the test.
+    assert!(test(None).unwrap_err().to_string().contains("1 of 2 tests failed"));
+    assert!(test(Some("should_fail")).is_ok());
+}
+
+#[test]
+pub fn execute_locally_honors_console_statements() {
+    let package = package(&[
+        ("program.json", r#"{ "program": "counter.aleo" }"#),
+        (".env", &format!("NETWORK=testnet3\nPRIVATE_KEY={TEST_PRIVATE_KEY}\n")),
+        (
+            "src/main.leo",
+            "program counter.aleo {
+    mapping counts: u8 => u8;
+
+    transition increment(key: u8) {
+        console.log(\"incrementing {}\", key);
+        return then finalize(key);
+    }
+
+    finalize increment(key: u8) {
+        let count: u8 = Mapping::get_or_use(counts, key, 0u8);
+        console.assert(count < 1u8);
+        Mapping::set(counts, key, count + 1u8);
+    }
+}
+",
+        ),
+    ]);
+    let context = || Context::new(Some(package.root().to_path_buf()), Default::default()).unwrap();
+
+    // Note that the program is built and executed in one session, since its AST refers to the symbols interned in it.
+    create_session_if_not_set_then(|_| {
+        let (_, _, ast) = (Build { options: Default::default() }).apply(context(), ()).unwrap();
+        let program =
+            load_programs(&package.root().join("build"), &ProgramID::from_str("counter.aleo").unwrap()).unwrap().0;
+        let counts = || {
+            let ledger = LocalLedger::open(package.root(), &[&program]).unwrap();
+            ledger.store().get_mapping_confirmed(*program.id(), Identifier::from_str("counts").unwrap()).unwrap()
+        };
+        let entry = || (Plaintext::from_str("1u8").unwrap(), Value::from_str("1u8").unwrap());
+
+        // The console assertions are checked against the state of the local ledger.
+        let execute =
+            || execute_locally(&context(), &ast, "increment", &["1u8".to_string()]).map_err(|err| err.to_string());
+        execute().unwrap();
+        assert_eq!(counts(), [entry()]);
+        assert!(execute().unwrap_err().contains("assertion"));
+        assert_eq!(counts(), [entry()]);
+    });
+}
+
+#[test]
+pub fn execute_locally_reverts_failed_finalize() {
+    assert_reverts_failed_finalize(COUNTER_SOURCE);
+}
+
+#[test]
+pub fn execute_locally_reverts_failed_finalize_with_console_statements() {
+    // The interpreter evaluates the program for its logs, but the failure is still reported by the Aleo instructions.
+    let source = COUNTER_SOURCE.replace(
+        "transition reset_and_decrement(key: u8) {",
+        "transition reset_and_decrement(key: u8) {\n        console.log(\"resetting {}\", key);",
+    );
+    assert_reverts_failed_finalize(&source);
+}
+
+/// Checks that a failing finalize block of the counter program leaves the local ledger unchanged.
+fn assert_reverts_failed_finalize(source: &str) {
+    let package = package(&[
+        ("program.json", r#"{ "program": "counter.aleo" }"#),
+        (".env", &format!("NETWORK=testnet3\nPRIVATE_KEY={TEST_PRIVATE_KEY}\n")),
+        ("src/main.leo", source),
+    ]);
+    let context = || Context::new(Some(package.root().to_path_buf()), Default::default()).unwrap();
+    create_session_if_not_set_then(|_| {
+        let (_, _, ast) = (Build { options: Default::default() }).apply(context(), ()).unwrap();
+        let program =
+            load_programs(&package.root().join("build"), &ProgramID::from_str("counter.aleo").unwrap()).unwrap().0;
+        let counts = || {
+            let ledger = LocalLedger::open(package.root(), &[&program]).unwrap();
+            ledger.store().get_mapping_confirmed(*program.id(), Identifier::from_str("counts").unwrap()).unwrap()
+        };
+        let entry = || (Plaintext::from_str("1u8").unwrap(), Value::from_str("1u8").unwrap());
+
+        // A successful finalize block updates the local ledger.
+        let execute = |name: &str| execute_locally(&context(), &ast, name, &["1u8".to_string()]);
+        execute("increment").unwrap();
+        assert_eq!(counts(), [entry()]);
+
+        // A failing finalize block leaves the local ledger unchanged, including the updates made before it failed.
+        assert!(execute("reset_and_decrement").unwrap_err().to_string().contains("was reverted"));
+        assert_eq!(counts(), [entry()]);
+    });
+}
+
+#[test]
+pub fn build_compiles_transitive_imports() {
+    let package = package(&[
+        ("program.json", r#"{ "program": "main_program.aleo" }"#),
+        (
+            "src/main.leo",
+            "import child.leo;\nimport grandchild.leo;\n\nprogram main_program.aleo {\n    transition main(a: u32) -> u32 {\n        return grandchild.leo/twice(child.leo/quadruple(a));\n    }\n}\n",
+        ),
+        (
+            "imports/child.leo",
+            "import grandchild.leo;\n\nprogram child.aleo {\n    transition quadruple(a: u32) -> u32 {\n        return grandchild.leo/twice(grandchild.leo/twice(a));\n    }\n}\n",
+        ),
+        (
+            "imports/grandchild.leo",
+            "program grandchild.aleo {\n    transition twice(a: u32) -> u32 {\n        return a + a;\n    }\n}\n",
+        ),
+    ]);
+
+    // Every program imported by the main program, directly or not, is compiled into the build/imports/ directory.
+    let context = Context::new(Some(package.root().to_path_buf()), Default::default()).unwrap();
+    create_session_if_not_set_then(|_| (Build { options: Default::default() }).apply(context, ())).unwrap();
+    let (main_program, imports) =
+        load_programs(&package.root().join("build"), &ProgramID::from_str("main_program.aleo").unwrap()).unwrap();
+    let order = dependency_order(&main_program, &imports);
+    assert_eq!(order, [ProgramID::from_str("grandchild.aleo").unwrap(), ProgramID::from_str("child.aleo").unwrap()]);
+}
+
+#[test]
+pub fn build_cyclic_imports_fail() {
+    let package = package(&[
+        ("program.json", r#"{ "program": "main_program.aleo" }"#),
+        (
+            "src/main.leo",
+            "import ping.leo;\n\nprogram main_program.aleo {\n    transition main(a: u32) -> u32 {\n        return a;\n    }\n}\n",
+        ),
+        (
+            "imports/ping.leo",
+            "import pong.leo;\n\nprogram ping.aleo {\n    transition main(a: u32) -> u32 {\n        return a;\n    }\n}\n",
+        ),
+        (
+            "imports/pong.leo",
+            "import ping.leo;\n\nprogram pong.aleo {\n    transition main(a: u32) -> u32 {\n        return a;\n    }\n}\n",
+        ),
+    ]);
+
+    let context = Context::new(Some(package.root().to_path_buf()), Default::default()).unwrap();
+    let build = create_session_if_not_set_then(|_| (Build { options: Default::default() }).apply(context, ()));
+    assert!(build.unwrap_err().to_string().contains("Cyclic dependency between imported programs"));
+}
+
+#[test]
+pub fn build_imports_aleo_program() {
+    let token = "program token.aleo;\n\nrecord token:\n    owner as address.private;\n    amount as u64.private;\n\nmapping supply:\n    key as address.public;\n    value as u64.public;\n\nfunction mint:\n    input r0 as u64.private;\n    cast self.caller r0 into r1 as token.record;\n    output r1 as token.record;\n\nfunction burn:\n    input r0 as token.record;\n    output r0.amount as u64.private;\n\nfunction mint_public:\n    input r0 as u64.public;\n    async mint_public self.caller r0 into r1;\n    output r1 as token.aleo/mint_public.future;\n\nfinalize mint_public:\n    input r0 as address.public;\n    input r1 as u64.public;\n    get.or_use supply[r0] 0u64 into r2;\n    add r2 r1 into r3;\n    set r3 into supply[r0];\n";
+    let package = package(&[
+        ("program.json", r#"{ "program": "main_program.aleo" }"#),
+        (
+            "src/main.leo",
+            "import token.aleo;\n\nprogram main_program.aleo {\n    transition mint_token(amount: u64) -> token.leo/token.record {\n        return token.leo/mint(amount);\n    }\n\n    transition burn_token(t: token.leo/token.record) -> u64 {\n        return token.leo/burn(t);\n    }\n}\n",
+        ),
+        ("imports/token.aleo", token),
+    ]);
+
+    // The imported program is copied into the build/imports/ directory as it is.
+    let context = Context::new(Some(package.root().to_path_buf()), Default::default()).unwrap();
+    create_session_if_not_set_then(|_| (Build { options: Default::default() }).apply(context, ())).unwrap();
+    assert_eq!(std::fs::read_to_string(package.root().join("build/imports/token.aleo")).unwrap(), token);
+    let main_program = std::fs::read_to_string(package.root().join("build/main.aleo")).unwrap();
+    assert!(main_program.contains("call token.aleo/mint"));
+    assert!(main_program.contains("call token.aleo/burn"));
+}
+
+#[test]
+pub fn add_and_remove_dependencies() {
+    let package = package(&[
+        ("program.json", r#"{ "program": "main_program.aleo" }"#),
+        (
+            "src/main.leo",
+            "import token.leo;\n\nprogram main_program.aleo {\n    transition main(a: u32) -> u32 {\n        return token.leo/mint(a);\n    }\n}\n",
+        ),
+        ("deps/token/program.json", r#"{ "program": "token.aleo" }"#),
+        (
+            "deps/token/src/main.leo",
+            "program token.aleo {\n    transition mint(a: u32) -> u32 {\n        return a + 1u32;\n    }\n}\n",
+        ),
+        (
+            "deps/helper.aleo",
+            "program helper.aleo;\n\nfunction identity:\n    input r0 as u32.private;\n    output r0 as u32.private;\n",
+        ),
+    ]);
+    let context = || Context::new(Some(package.root().to_path_buf()), Default::default()).unwrap();
+
+    // Local Leo packages are compiled into the build/imports/ directory, and `.aleo` files are copied into it.
+    create_session_if_not_set_then(|_| {
+        (Add { name: "token".to_string(), path: "deps/token".into() }).apply(context(), ())?;
+        (Add { name: "helper.aleo".to_string(), path: "deps/helper.aleo".into() }).apply(context(), ())?;
+        (Build { options: Default::default() }).apply(context(), ())
+    })
+    .unwrap();
+    let dependencies = Dependencies::read_from(package.root()).unwrap();
+    assert_eq!(dependencies.keys().collect::<Vec<_>>(), ["token.aleo", "helper.aleo"]);
+    assert_eq!(LockFile::read_from(package.root()).unwrap().packages.len(), 2);
+    assert!(package.root().join("build/imports/token.aleo").exists());
+    assert!(package.root().join("build/imports/helper.aleo").exists());
+
+    // Removing a dependency unpins it.
+    create_session_if_not_set_then(|_| (Remove { name: "helper".to_string() }).apply(context(), ())).unwrap();
+    let dependencies = Dependencies::read_from(package.root()).unwrap();
+    assert_eq!(dependencies.keys().collect::<Vec<_>>(), ["token.aleo"]);
+    let lock_file = LockFile::read_from(package.root()).unwrap();
+    assert_eq!(lock_file.packages.iter().map(|locked| locked.name.as_str()).collect::<Vec<_>>(), ["token.aleo"]);
+}
+
+#[test]
+pub fn build_dependency_drift_fail() {
+    let package = package(&[
+        ("program.json", r#"{ "program": "main_program.aleo" }"#),
+        (
+            "src/main.leo",
+            "import token.leo;\n\nprogram main_program.aleo {\n    transition main(a: u32) -> u32 {\n        return token.leo/mint(a);\n    }\n}\n",
+        ),
+        ("deps/token/program.json", r#"{ "program": "token.aleo" }"#),
+        (
+            "deps/token/src/main.leo",
+            "program token.aleo {\n    transition mint(a: u32) -> u32 {\n        return a + 1u32;\n    }\n}\n",
+        ),
+    ]);
+    let context = || Context::new(Some(package.root().to_path_buf()), Default::default()).unwrap();
+    create_session_if_not_set_then(|_| {
+        (Add { name: "token".to_string(), path: "deps/token".into() }).apply(context(), ())
+    })
+    .unwrap();
+
+    // Changing the source of the dependency after it is pinned fails the build.
+    std::fs::write(
+        package.root().join("deps/token/src/main.leo"),
+        "program token.aleo {\n    transition mint(a: u32) -> u32 {\n        return a + 2u32;\n    }\n}\n",
+    )
+    .unwrap();
+    let build = create_session_if_not_set_then(|_| (Build { options: Default::default() }).apply(context(), ()));
+    assert!(build.unwrap_err().to_string().contains("has changed since it was pinned"));
+
+    // Adding the dependency again pins its new content.
+    create_session_if_not_set_then(|_| {
+        (Add { name: "token".to_string(), path: "deps/token".into() }).apply(context(), ())?;
+        (Build { options: Default::default() }).apply(context(), ())
+    })
+    .unwrap();
+}
+
+#[test]
+pub fn build_merges_source_modules() {
+    let package = package(&[
+        ("program.json", r#"{ "program": "geometry.aleo" }"#),
+        (
+            "src/main.leo",
+            "program geometry.aleo {\n    mod math;\n\n    transition main(a: u32) -> Point {\n        return Point { x: a, y: sum(a, ORIGIN) };\n    }\n}\n",
+        ),
+        (
+            "src/math.leo",
+            "mod point;\n\nconst ORIGIN: u32 = 0u32;\n\ninline sum(a: u32, b: u32) -> u32 {\n    return a + b;\n}\n",
+        ),
+        ("src/point.leo", "struct Point {\n    x: u32,\n    y: u32,\n}\n"),
+    ]);
+
+    // The modules of the program, declared directly or not, are compiled into the program itself.
+    let context = Context::new(Some(package.root().to_path_buf()), Default::default()).unwrap();
+    create_session_if_not_set_then(|_| (Build { options: Default::default() }).apply(context, ())).unwrap();
+    let program = std::fs::read_to_string(package.root().join("build/main.aleo")).unwrap();
+    assert!(program.contains("struct Point:"));
+    assert!(!package.root().join("build/math.aleo").exists());
+}
+
+#[test]
+pub fn build_source_modules_fail() {
+    let build = |files: &[(&str, &str)]| {
+        let package = package(files);
+        let context = Context::new(Some(package.root().to_path_buf()), Default::default()).unwrap();
+        create_session_if_not_set_then(|_| {
+            (Build { options: Default::default() }).apply(context, ()).map(|_| ()).map_err(|err| err.error_code())
+        })
+    };
+    let manifest = ("program.json", r#"{ "program": "geometry.aleo" }"#);
+    let main = |declarations: &str| {
+        format!(
+            "program geometry.aleo {{\n{declarations}\n    transition main(a: u32) -> u32 {{\n        return a;\n    }}\n}}\n"
+        )
+    };
+
+    // The source directory must contain a main file.
+    assert_eq!(
+        build(&[manifest, ("src/math.leo", "const ORIGIN: u32 = 0u32;\n")]),
+        Err(PackageError::missing_main_file().error_code())
+    );
+
+    // A declared module must exist.
+    assert_eq!(
+        build(&[manifest, ("src/main.leo", &main("    mod math;\n"))]),
+        Err(CompilerError::module_not_found("math.leo", Default::default()).error_code())
+    );
+
+    // A module must not be declared twice, even by another module.
+    assert_eq!(
+        build(&[
+            manifest,
+            ("src/main.leo", &main("    mod math;\n    mod point;\n")),
+            ("src/math.leo", "mod point;\n"),
+            ("src/point.leo", "struct Point {\n    x: u32,\n    y: u32,\n}\n"),
+        ]),
+        Err(CompilerError::module_declared_twice("point", Default::default()).error_code())
+    );
+}
+
+#[test]
+pub fn build_reports_errors_in_source_modules() {
+    let package = package(&[
+        ("program.json", r#"{ "program": "geometry.aleo" }"#),
+        (
+            "src/main.leo",
+            "program geometry.aleo {\n    mod math;\n\n    transition main(a: u32) -> u32 {\n        return sum(a, 1u32);\n    }\n}\n",
+        ),
+        ("src/math.leo", "inline sum(a: u32, b: u32) -> u32 {\n    return a + true;\n}\n"),
+    ]);
+
+    // A type error in a module is reported at its location in the file of the module.
+    create_session_if_not_set_then(|_| {
+        let (handler, buffer) = Handler::new_with_buf();
+        let program_id = ProgramID::from_str("geometry.aleo").unwrap();
+        assert!(type_checked_program(package.root(), &program_id, &[], &handler).is_err());
+        let errors = buffer.extract_errs().to_string();
+        assert!(errors.contains("math.leo:2:"), "{errors}");
+    });
+}
+
+#[test]
+pub fn build_reuses_unchanged_programs() {
+    let package = package(&[
+        ("program.json", r#"{ "program": "main_program.aleo" }"#),
+        (
+            "src/main.leo",
+            "import child.leo;\n\nprogram main_program.aleo {\n    mod math;\n\n    transition main(a: u32) -> u32 {\n        return child.leo/double(increment(a));\n    }\n}\n",
+        ),
+        ("src/math.leo", "inline increment(a: u32) -> u32 {\n    return a + 1u32;\n}\n"),
+        (
+            "imports/child.leo",
+            "program child.aleo {\n    mod arithmetic;\n\n    transition double(a: u32) -> u32 {\n        return twice(a);\n    }\n}\n",
+        ),
+        ("imports/arithmetic.leo", "inline twice(a: u32) -> u32 {\n    return a + a;\n}\n"),
+    ]);
+    let root = package.root();
+    // Builds the package, and returns when the Aleo files of the program and of its import were last written.
+    let build = || {
+        let context = Context::new(Some(root.to_path_buf()), Default::default()).unwrap();
+        create_session_if_not_set_then(|_| (Build { options: Default::default() }).apply(context, ())).unwrap();
+        let modified = |path: &str| std::fs::metadata(root.join(path)).unwrap().modified().unwrap();
+        (modified("build/main.aleo"), modified("build/imports/child.aleo"))
+    };
+    let edit = |path: &str, from: &str, to: &str| {
+        let source = std::fs::read_to_string(root.join(path)).unwrap();
+        std::fs::write(root.join(path), source.replace(from, to)).unwrap();
+    };
+
+    // An unchanged build reuses the Aleo instructions of the previous one.
+    let (main, child) = build();
+    assert_eq!(build(), (main, child));
+
+    // Editing the source of the program recompiles it, but not its import.
+    edit("src/main.leo", "increment(a)", "increment(a + a)");
+    let (edited_main, edited_child) = build();
+    assert!(edited_main != main && edited_child == child);
+
+    // Editing a module of the program recompiles it.
+    let (main, child) = (edited_main, edited_child);
+    edit("src/math.leo", "1u32", "2u32");
+    let (edited_main, edited_child) = build();
+    assert!(edited_main != main && edited_child == child);
+
+    // Editing an import, or one of its modules, recompiles both the import and the program.
+    let (main, child) = (edited_main, edited_child);
+    edit("imports/child.leo", "twice(a)", "twice(a + 1u32)");
+    let (edited_main, edited_child) = build();
+    assert!(edited_main != main && edited_child != child);
+
+    let (main, child) = (edited_main, edited_child);
+    edit("imports/arithmetic.leo", "a + a", "a * 2u32");
+    let (edited_main, edited_child) = build();
+    assert!(edited_main != main && edited_child != child);
+}
+
+#[test]
+pub fn build_workspace_in_dependency_order() {
+    let workspace = package(&[
+        ("workspace.json", r#"{ "members": ["exchange", "token"] }"#),
+        ("exchange/program.json", r#"{ "program": "exchange.aleo" }"#),
+        (
+            "exchange/src/main.leo",
+            "import token.leo;\n\nprogram exchange.aleo {\n    transition swap(amount: u64) -> u64 {\n        return token.leo/issue(amount);\n    }\n}\n",
+        ),
+        ("token/program.json", r#"{ "program": "token.aleo" }"#),
+        (
+            "token/src/main.leo",
+            "program token.aleo {\n    transition issue(amount: u64) -> u64 {\n        return amount;\n    }\n}\n",
+        ),
+    ]);
+    let root = workspace.root().canonicalize().unwrap();
+
+    // Each member comes after the members it imports, and members may be selected by name.
+    create_session_if_not_set_then(|_| {
+        let handler = Handler::default();
+        let members = workspace_members(&root, &[], &handler).unwrap();
+        assert_eq!(members.keys().collect::<Vec<_>>(), ["token.aleo", "exchange.aleo"]);
+        let members = workspace_members(&root, &["exchange".to_string()], &handler).unwrap();
+        assert_eq!(members.keys().collect::<Vec<_>>(), ["exchange.aleo"]);
+        assert!(workspace_members(&root, &["wallet".to_string()], &handler).is_err());
+    });
+
+    // Members import each other without an `imports/` directory, and share the `outputs/` directory of the workspace.
+    let context = Context::new(Some(root.join("exchange")), Default::default()).unwrap();
+    create_session_if_not_set_then(|_| for_each_member(Build { options: Default::default() }, context, &[])).unwrap();
+    assert!(root.join("token/build/main.aleo").exists());
+    assert!(root.join("exchange/build/main.aleo").exists());
+    assert!(root.join("exchange/build/imports/token.aleo").exists());
+    assert!(!root.join("exchange/imports").exists());
+    assert!(root.join("outputs").exists() && !root.join("exchange/outputs").exists());
+}
+
+#[test]
+pub fn build_workspace_members_sharing_an_import() {
+    let token = |amount: &str| {
+        format!(
+            "program token.aleo {{\n    transition issue(amount: u64) -> u64 {{\n        return {amount};\n    }}\n}}\n"
+        )
+    };
+    let member = |name: &str| {
+        format!(
+            "import token.leo;\n\nprogram {name}.aleo {{\n    transition run(amount: u64) -> u64 {{\n        return token.leo/issue(amount);\n    }}\n}}\n"
+        )
+    };
+    let workspace = package(&[
+        ("workspace.json", r#"{ "members": ["exchange", "token", "wallet"] }"#),
+        ("exchange/program.json", r#"{ "program": "exchange.aleo" }"#),
+        ("exchange/src/main.leo", &member("exchange")),
+        ("wallet/program.json", r#"{ "program": "wallet.aleo" }"#),
+        ("wallet/src/main.leo", &member("wallet")),
+        ("token/program.json", r#"{ "program": "token.aleo" }"#),
+        ("token/src/main.leo", &token("amount")),
+    ]);
+    let root = workspace.root().canonicalize().unwrap();
+    let build = |member: &str| {
+        let context = Context::new(Some(root.join(member)), Default::default()).unwrap();
+        create_session_if_not_set_then(|_| (Build { options: Default::default() }).apply(context, ())).unwrap();
+    };
+    build("exchange");
+    build("wallet");
+
+    // Once the shared import is edited, each member recompiles its own copy of it, even if another member already has.
+    std::fs::write(root.join("token/src/main.leo"), token("amount + 1u64")).unwrap();
+    build("exchange");
+    build("wallet");
+    for member in ["exchange", "wallet"] {
+        let instructions = std::fs::read_to_string(root.join(member).join("build/imports/token.aleo")).unwrap();
+        assert!(instructions.contains("add r0 1u64"), "{member} reused a stale copy of `token.aleo`");
+    }
+}
+
+#[test]
+pub fn analyze_program() {
+    let source = "program test.aleo {
+    struct Point { x: u32, y: u32 }
+
+    transition main(a: u32) -> Point {
+        return Point { x: a, y: a + 1u32 };
+    }
+}
+";
+    let analysis = Analysis::new(FileName::Custom("test".into()), source, None);
+    assert!(analysis.diagnostics.is_empty());
+
+    // The innermost expression at the offset is the literal.
+    let offset = source.find("1u32").unwrap();
+    assert_eq!(analysis.type_at(offset), Some((offset..offset + 4, "u32")));
+
+    // The struct expression refers to the struct definition.
+    let definition = source.find("Point").unwrap();
+    assert_eq!(analysis.definition_at(source.rfind("Point").unwrap()), Some(definition..definition + 5));
+
+    let source = "program test.aleo {
+    transition main() -> u32 {
+        return true;
+    }
+}
+";
+    let analysis = Analysis::new(FileName::Custom("test".into()), source, None);
+    assert!(analysis.diagnostics.iter().any(|diagnostic| diagnostic.error && diagnostic.code.starts_with("ETYC")));
+}
+
+#[test]
+pub fn analyze_program_with_imports() {
+    let source = "import child.leo;
+
+program main_program.aleo {
+    transition main(a: u32) -> u32 {
+        return child.leo/double(a);
+    }
+}
+";
+    let package = package(&[
+        (
+            "imports/child.leo",
+            "program child.aleo {\n    transition double(a: u32) -> u32 {\n        return a + a;\n    }\n}\n",
+        ),
+        ("src/main.leo", source),
+    ]);
+
+    // The imports are resolved in the given package, regardless of the current directory.
+    let main_file = package.root().join("src/main.leo");
+    let analysis = Analysis::new(FileName::Real(main_file), source, Some(package.root().to_path_buf()));
+    assert!(analysis.diagnostics.is_empty());
+}
+
+#[test]
+#[ignore = "downloads the snarkVM proving parameters of the deployment fee"]
+pub fn deploy_dry_run_writes_transaction() {
+    let package = package(&[
+        ("program.json", r#"{ "program": "counter.aleo" }"#),
+        (".env", &format!("NETWORK=testnet3\nPRIVATE_KEY={TEST_PRIVATE_KEY}\n")),
+        ("src/main.leo", COUNTER_SOURCE),
+    ]);
+    let context = || Context::new(Some(package.root().to_path_buf()), Default::default()).unwrap();
+    let output = package.root().join("deployment.json");
+
+    // Note that the endpoint is unreachable, so the deployment fails if it is broadcast.
+    let deploy = Deploy {
+        endpoint: "http://127.0.0.1:1".to_string(),
+        network: "testnet3".to_string(),
+        dry_run: true,
+        output: Some(output.clone()),
+        priority_fee: 0,
+        record: None,
+        private_key: None,
+        compiler_options: Default::default(),
+    };
+    create_session_if_not_set_then(|_| {
+        let build = (Build { options: Default::default() }).apply(context(), ())?;
+        deploy.apply(context(), build)
+    })
+    .unwrap();
+
+    // The deployment transaction of the main program is written to the output file.
+    let transaction = Transaction::<CurrentNetwork>::from_str(&std::fs::read_to_string(output).unwrap()).unwrap();
+    assert_eq!(transaction.deployment().unwrap().program_id(), &ProgramID::from_str("counter.aleo").unwrap());
+}
+
+#[test]
+pub fn deploy_dry_run_writes_transaction_to_output() {
+    let package = package(&[
+        ("program.json", r#"{ "program": "counter.aleo" }"#),
+        (".env", &format!("NETWORK=testnet3\nPRIVATE_KEY={TEST_PRIVATE_KEY}\n")),
+        ("src/main.leo", COUNTER_SOURCE),
+    ]);
+    let context = Context::new(Some(package.root().to_path_buf()), Default::default()).unwrap();
+    create_session_if_not_set_then(|_| (Build { options: Default::default() }).apply(context, ())).unwrap();
+
+    // Building a deployment requires proving its fee, so an unproven execution of the program is written instead.
+    let (program, imports) =
+        load_programs(&package.root().join("build"), &ProgramID::from_str("counter.aleo").unwrap()).unwrap();
+    let (process, _) = load_process(&program, &imports).unwrap();
+    let rng = &mut rand::thread_rng();
+    let private_key = PrivateKey::from_str(TEST_PRIVATE_KEY).unwrap();
+    let function = Identifier::from_str("increment").unwrap();
+    let inputs = [Value::<CurrentNetwork>::from_str("1u8").unwrap()];
+    let authorization =
+        process.authorize::<AleoV0, _>(&private_key, program.id(), function, inputs.into_iter(), rng).unwrap();
+    let (_, trace) = process.execute::<AleoV0>(authorization).unwrap();
+    let execution =
+        Execution::from(trace.transitions().iter().cloned(), <CurrentNetwork as Network>::StateRoot::default(), None)
+            .unwrap();
+    let transaction = Transaction::from_execution(execution, None).unwrap();
+
+    let output = package.root().join("transaction.json");
+    let deploy = Deploy {
+        endpoint: "http://127.0.0.1:1".to_string(),
+        network: "testnet3".to_string(),
+        dry_run: true,
+        output: Some(output.clone()),
+        priority_fee: 0,
+        record: None,
+        private_key: None,
+        compiler_options: Default::default(),
+    };
+    deploy.write_transaction(&transaction).unwrap();
+
+    // The transaction is written to the output file, from which it is read back as it is.
+    let written = Transaction::<CurrentNetwork>::from_str(&std::fs::read_to_string(output).unwrap()).unwrap();
+    assert_eq!(written.id(), transaction.id());
+}
+
+const COUNTER_PROGRAM: &str = "program counter.aleo;
+
+mapping counts:
+    key as u8.public;
+    value as u8.public;
+";
+
+#[test]
+pub fn local_ledger_persists_mappings() {
+    let package = package(&[(".leo/ledger/counter.aleo.json", r#"{ "counts": { "1u8": "2u8" } }"#)]);
+    let program = Program::<CurrentNetwork>::from_str(COUNTER_PROGRAM).unwrap();
+    let mapping = Identifier::from_str("counts").unwrap();
+    let entry = |key: &str, value: &str| (Plaintext::from_str(key).unwrap(), Value::from_str(value).unwrap());
+
+    // The entries in the ledger directory are loaded into the store.
+    let ledger = LocalLedger::open(package.root(), &[&program]).unwrap();
+    assert_eq!(ledger.store().get_mapping_confirmed(*program.id(), mapping).unwrap(), [entry("1u8", "2u8")]);
+
+    // The updates of the store are written back to the ledger directory.
+    let (key, value) = entry("3u8", "4u8");
+    ledger.store().update_key_value(*program.id(), mapping, key, value).unwrap();
+    ledger.save(&[&program]).unwrap();
+    let ledger = LocalLedger::open(package.root(), &[&program]).unwrap();
+    assert_eq!(ledger.store().get_mapping_confirmed(*program.id(), mapping).unwrap(), [
+        entry("1u8", "2u8"),
+        entry("3u8", "4u8")
+    ]);
+}
+
+const POINTS_PROGRAM: &str = "program points.aleo;
+
+struct point:
+    x as u8;
+    y as u8;
+
+mapping points:
+    key as point.public;
+    value as [u8; 2u32].public;
+";
+
+#[test]
+pub fn query_parses_keys() {
+    let program = Program::<CurrentNetwork>::from_str(POINTS_PROGRAM).unwrap();
+    let parse = |key: &str, type_: &str| parse_key(&program, &PlaintextType::from_str(type_).unwrap(), key).ok();
+    let plaintext = |string: &str| Some(Plaintext::from_str(string).unwrap());
+
+    // Literal keys may omit their type suffix, but must be of the declared type.
+    assert_eq!(parse("1", "u8"), plaintext("1u8"));
+    assert_eq!(parse("1u8", "u8"), plaintext("1u8"));
+    assert_eq!(parse("1u16", "u8"), None);
+    assert_eq!(parse("one", "u8"), None);
+
+    // Struct keys must have the declared members, in order.
+    assert_eq!(parse("{ x: 1u8, y: 2u8 }", "point"), plaintext("{ x: 1u8, y: 2u8 }"));
+    assert_eq!(parse("{ y: 2u8, x: 1u8 }", "point"), None);
+    assert_eq!(parse("{ x: 1u8 }", "point"), None);
+    assert_eq!(parse("{ x: 1u8, y: 2u16 }", "point"), None);
+    assert_eq!(parse("1u8", "point"), None);
+}
+
+#[test]
+pub fn query_checks_types() {
+    let program = Program::<CurrentNetwork>::from_str(POINTS_PROGRAM).unwrap();
+    let check = |plaintext: &str, type_: &str| {
+        check_type(&program, &Plaintext::from_str(plaintext).unwrap(), &PlaintextType::from_str(type_).unwrap())
+    };
+
+    assert!(check("[1u8, 2u8]", "[u8; 2u32]").is_ok());
+    assert!(check("[1u8]", "[u8; 2u32]").is_err());
+    assert!(check("[1u8, 2u16]", "[u8; 2u32]").is_err());
+    assert!(check("[{ x: 1u8, y: 2u8 }]", "[point; 1u32]").is_ok());
+    assert!(check("[{ x: 1u8, z: 2u8 }]", "[point; 1u32]").is_err());
+    assert!(check("1u8", "[u8; 1u32]").is_err());
+}
+
+#[test]
+pub fn query_reads_local_ledger() {
+    let package = package(&[
+        ("build/main.aleo", COUNTER_PROGRAM),
+        (".leo/ledger/counter.aleo.json", r#"{ "counts": { "1u8": "2u8", "3u8": "4u8" } }"#),
+    ]);
+    let program_id = ProgramID::from_str("counter.aleo").unwrap();
+    let source = Source::Local { path: package.root().to_path_buf(), main: program_id };
+    let mapping = Identifier::from_str("counts").unwrap();
+    let plaintext = |string: &str| Plaintext::<CurrentNetwork>::from_str(string).unwrap();
+    let label = "counter.aleo/counts";
+
+    // The programs are read from the build directory.
+    assert_eq!(source.program(&program_id, label).unwrap().id(), &program_id);
+    assert!(source.program(&ProgramID::from_str("missing.aleo").unwrap(), label).is_err());
+
+    // The entries are read from the ledger directory.
+    assert_eq!(source.value(&program_id, &mapping, &plaintext("1u8"), label).unwrap(), Some(plaintext("2u8")));
+    assert_eq!(source.value(&program_id, &mapping, &plaintext("2u8"), label).unwrap(), None);
+    assert_eq!(source.entries(&program_id, &mapping, label).unwrap(), [
+        (plaintext("1u8"), plaintext("2u8")),
+        (plaintext("3u8"), plaintext("4u8"))
+    ]);
+
+    // Mappings without entries in the ledger are empty.
+    let empty = Identifier::from_str("empty").unwrap();
+    assert!(source.entries(&program_id, &empty, label).unwrap().is_empty());
+}
+
+#[test]
+pub fn query_reads_local_imports() {
+    let package = package(&[
+        ("build/main.aleo", MAIN_PROGRAM),
+        ("build/imports/child.aleo", CHILD_PROGRAM),
+        ("build/imports/grandchild.aleo", GRANDCHILD_PROGRAM),
+        ("build/imports/counter.aleo", COUNTER_PROGRAM),
+    ]);
+    let main = ProgramID::from_str("main_program.aleo").unwrap();
+    let source = Source::Local { path: package.root().to_path_buf(), main };
+    let program = |name: &str| source.program(&ProgramID::from_str(name).unwrap(), "label").map(|program| *program.id());
+
+    // The main program is read from the manifest's file, and its imports from the build/imports/ directory.
+    assert_eq!(program("main_program.aleo").unwrap(), main);
+    assert_eq!(program("child.aleo").unwrap().to_string(), "child.aleo");
+    assert_eq!(program("grandchild.aleo").unwrap().to_string(), "grandchild.aleo");
+
+    // Programs the main program does not depend on are not executed locally.
+    assert!(program("counter.aleo").is_err());
+}
+
+/// Creates a temporary directory containing the given files, which is deleted when the returned value is dropped.
+fn package(files: &[(&str, &str)]) -> TestDir {
+    let package = TestDir::temp();
+    for (path, content) in files {
+        let path = package.root().join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+    package
+}
+
 // todo (collin): uncomment after refactor
 // #[test]
 // pub fn build_pedersen_hash() -> Result<()> {
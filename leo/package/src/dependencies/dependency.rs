@@ -0,0 +1,225 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The dependencies declared in the `dependencies` section of the `program.json` manifest.
+
+use crate::{
+    parse_file_paths,
+    source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+    ALEO_FILE_EXTENSION,
+    LEO_FILE_EXTENSION,
+};
+use leo_errors::{PackageError, Result};
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub static MANIFEST_FILENAME: &str = "program.json";
+
+/// A dependency declared in the manifest of a package.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Dependency {
+    /// The path to the dependency, relative to the package.
+    /// It is either the directory of a Leo package or a pre-compiled `.aleo` file.
+    pub path: PathBuf,
+}
+
+/// The kind of a dependency.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DependencyKind {
+    /// A Leo package, which is compiled along with the packages depending on it.
+    Leo,
+    /// A program which is already compiled into Aleo instructions.
+    Aleo,
+}
+
+/// A dependency which has been located on disk and checksummed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolvedDependency {
+    /// The kind of the dependency.
+    pub kind: DependencyKind,
+    /// The path to the dependency, relative to the root package.
+    pub path: PathBuf,
+    /// The path to the dependency, joined onto the path of the root package.
+    pub location: PathBuf,
+    /// The SHA256 checksum of the content of the dependency.
+    pub checksum: String,
+}
+
+impl ResolvedDependency {
+    /// Returns the path to the file to compile against: `src/main.leo` for a Leo package, or the `.aleo` file itself.
+    pub fn source_file(&self) -> PathBuf {
+        match self.kind {
+            DependencyKind::Leo => self.location.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME),
+            DependencyKind::Aleo => self.location.clone(),
+        }
+    }
+}
+
+pub struct Dependencies;
+
+impl Dependencies {
+    /// Returns the program id of a dependency, which may be named with or without its `.aleo` suffix.
+    pub fn program_id(name: &str) -> String {
+        match name.ends_with(ALEO_FILE_EXTENSION) {
+            true => name.to_string(),
+            false => format!("{name}{ALEO_FILE_EXTENSION}"),
+        }
+    }
+
+    /// Reads the dependencies declared in the manifest of the package at the given path, by program id.
+    pub fn read_from(package_path: &Path) -> Result<IndexMap<String, Dependency>> {
+        let manifest_path = package_path.join(MANIFEST_FILENAME);
+        match read_manifest(&manifest_path)?.swap_remove("dependencies") {
+            None => Ok(IndexMap::new()),
+            Some(dependencies) => serde_json::from_value(dependencies)
+                .map_err(|err| PackageError::failed_to_parse_manifest(manifest_path.display(), err).into()),
+        }
+    }
+
+    /// Writes the given dependencies into the manifest of the package at the given path.
+    /// The other fields of the manifest are left untouched.
+    pub fn write_to(package_path: &Path, dependencies: &IndexMap<String, Dependency>) -> Result<()> {
+        let manifest_path = package_path.join(MANIFEST_FILENAME);
+        let mut manifest = read_manifest(&manifest_path)?;
+        match dependencies.is_empty() {
+            true => {
+                manifest.shift_remove("dependencies");
+            }
+            false => {
+                let dependencies =
+                    serde_json::to_value(dependencies).map_err(PackageError::failed_to_write_manifest)?;
+                manifest.insert("dependencies".to_string(), dependencies);
+            }
+        }
+
+        let manifest = serde_json::to_string_pretty(&manifest).map_err(PackageError::failed_to_write_manifest)?;
+        fs::write(&manifest_path, manifest + "\n").map_err(PackageError::failed_to_write_manifest)?;
+        Ok(())
+    }
+
+    /// Resolves the dependencies of the package at the given path, by program id.
+    /// The dependencies of the Leo packages it depends on are resolved as well, transitively.
+    pub fn resolve(package_path: &Path) -> Result<IndexMap<String, ResolvedDependency>> {
+        let mut resolved: IndexMap<String, ResolvedDependency> = IndexMap::new();
+
+        // The packages whose dependencies are still to be resolved, relative to the root package.
+        let mut packages = vec![PathBuf::new()];
+        while let Some(package) = packages.pop() {
+            for (name, dependency) in Self::read_from(&package_path.join(&package))? {
+                let path = package.join(&dependency.path);
+                let location = package_path.join(&path);
+                let kind = Self::locate(&name, &location)?;
+
+                // Two packages may depend on the same program, as long as they agree on where it is.
+                if let Some(previous) = resolved.get(&name) {
+                    if !is_same_file(&previous.location, &location) {
+                        return Err(PackageError::conflicting_dependency(
+                            name,
+                            previous.path.display(),
+                            path.display(),
+                        )
+                        .into());
+                    }
+                    continue;
+                }
+
+                if kind == DependencyKind::Leo {
+                    packages.push(path.clone());
+                }
+                let checksum = checksum(kind, &location)?;
+                resolved.insert(name, ResolvedDependency { kind, path, location, checksum });
+            }
+        }
+
+        Ok(resolved)
+    }
+
+    /// Checks that the program `name` is found at the given location, and returns the kind of the dependency.
+    pub fn locate(name: &str, location: &Path) -> Result<DependencyKind> {
+        if !location.exists() {
+            return Err(PackageError::dependency_not_found(name, location.display()).into());
+        }
+
+        if location.is_dir() {
+            // The program id of a Leo package is declared in its manifest.
+            let manifest_path = location.join(MANIFEST_FILENAME);
+            let program = read_manifest(&manifest_path)?.swap_remove("program");
+            match program {
+                Some(Value::String(program)) if program == name => Ok(DependencyKind::Leo),
+                _ => Err(PackageError::dependency_name_does_not_match(name, location.display()).into()),
+            }
+        } else if location
+            .extension()
+            .map_or(false, |extension| extension == ALEO_FILE_EXTENSION.trim_start_matches('.'))
+        {
+            // The program id of a pre-compiled program is the name of its file.
+            match location.file_name().map_or(false, |file_name| file_name == name) {
+                true => Ok(DependencyKind::Aleo),
+                false => Err(PackageError::dependency_name_does_not_match(name, location.display()).into()),
+            }
+        } else {
+            Err(PackageError::invalid_dependency(name, location.display()).into())
+        }
+    }
+}
+
+/// Reads the fields of the manifest at the given path, in order.
+pub(crate) fn read_manifest(manifest_path: &Path) -> Result<IndexMap<String, Value>> {
+    let manifest = fs::read_to_string(manifest_path)
+        .map_err(|err| PackageError::failed_to_read_file(manifest_path.display(), err))?;
+    serde_json::from_str(&manifest)
+        .map_err(|err| PackageError::failed_to_parse_manifest(manifest_path.display(), err).into())
+}
+
+/// Returns true if both paths exist and point to the same file or directory.
+fn is_same_file(first: &Path, second: &Path) -> bool {
+    matches!((fs::canonicalize(first), fs::canonicalize(second)), (Ok(first), Ok(second)) if first == second)
+}
+
+/// Computes the SHA256 checksum of a dependency.
+/// The checksum of a Leo package covers its manifest and the Leo files in its `src/` directory.
+fn checksum(kind: DependencyKind, location: &Path) -> Result<String> {
+    let mut hasher = Sha256::new();
+    let mut file_paths = Vec::new();
+    match kind {
+        DependencyKind::Leo => {
+            let source_directory = location.join(SOURCE_DIRECTORY_NAME);
+            let directory = fs::read_dir(&source_directory)
+                .map_err(|err| PackageError::failed_to_read_file(source_directory.display(), err))?;
+            parse_file_paths(directory, &[LEO_FILE_EXTENSION], &mut file_paths)?;
+            file_paths.sort();
+            file_paths.insert(0, location.join(MANIFEST_FILENAME));
+        }
+        DependencyKind::Aleo => file_paths.push(location.to_path_buf()),
+    }
+
+    for file_path in file_paths {
+        let content =
+            fs::read(&file_path).map_err(|err| PackageError::failed_to_read_file(file_path.display(), err))?;
+        // The relative path of each file is hashed along with its content, so that renaming a file changes the checksum.
+        hasher.update(file_path.strip_prefix(location).unwrap_or(&file_path).to_string_lossy().as_bytes());
+        hasher.update(content);
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The `leo.lock` file, which pins the checksum of each dependency of a package.
+
+use crate::dependencies::ResolvedDependency;
+use leo_errors::{PackageError, Result};
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub static LOCK_FILENAME: &str = "leo.lock";
+
+/// The dependencies of a package, pinned to the checksum of their content.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockFile {
+    /// The pinned dependencies, including the dependencies of dependencies.
+    #[serde(default, rename = "package")]
+    pub packages: Vec<LockedDependency>,
+}
+
+/// A dependency pinned in the lock file.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedDependency {
+    /// The program id of the dependency, e.g. `token.aleo`.
+    pub name: String,
+    /// The path to the dependency, relative to the package.
+    pub path: PathBuf,
+    /// The SHA256 checksum of the content of the dependency.
+    pub checksum: String,
+}
+
+impl LockFile {
+    pub fn exists_at(package_path: &Path) -> bool {
+        package_path.join(LOCK_FILENAME).exists()
+    }
+
+    /// Reads the lock file of the package at the given path, or returns an empty lock file if there is none.
+    pub fn read_from(package_path: &Path) -> Result<Self> {
+        let path = package_path.join(LOCK_FILENAME);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let string = fs::read_to_string(&path).map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
+        toml::from_str(&string).map_err(|err| PackageError::failed_to_parse_lock_file(path.display(), err).into())
+    }
+
+    /// Writes the lock file into the package at the given path.
+    pub fn write_to(&self, package_path: &Path) -> Result<()> {
+        let string = toml::to_string_pretty(self).map_err(PackageError::io_error_lock_file)?;
+        fs::write(package_path.join(LOCK_FILENAME), format!("{}\n{string}", Self::header()))
+            .map_err(PackageError::io_error_lock_file)?;
+        Ok(())
+    }
+
+    /// Returns the lock file pinning the given dependencies.
+    /// A dependency whose checksum differs from the one pinned for the same path has drifted, which is an error,
+    /// unless the dependency is one of those to pin again.
+    pub fn update(&self, dependencies: &IndexMap<String, ResolvedDependency>, repin: &[&str]) -> Result<Self> {
+        let mut packages = Vec::with_capacity(dependencies.len());
+        for (name, dependency) in dependencies {
+            let pinned = self.packages.iter().find(|locked| locked.name == *name && locked.path == dependency.path);
+            if let Some(pinned) = pinned {
+                if pinned.checksum != dependency.checksum && !repin.contains(&name.as_str()) {
+                    return Err(PackageError::dependency_drift(name, dependency.path.display()).into());
+                }
+            }
+            packages.push(LockedDependency {
+                name: name.clone(),
+                path: dependency.path.clone(),
+                checksum: dependency.checksum.clone(),
+            });
+        }
+
+        Ok(Self { packages })
+    }
+
+    fn header() -> &'static str {
+        "# This file is generated by Leo. It is not intended for manual editing."
+    }
+}
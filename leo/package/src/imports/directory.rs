@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::parse_file_paths;
+use crate::{parse_file_paths, ALEO_FILE_EXTENSION, LEO_FILE_EXTENSION};
 use leo_errors::{PackageError, Result};
 
 use std::{
@@ -71,7 +71,7 @@ impl ImportsDirectory {
             .is_none())
     }
 
-    /// Returns a list of files in the imports directory.
+    /// Returns a list of the Leo and Aleo files in the imports directory.
     pub fn files(path: &Path) -> Result<Vec<PathBuf>> {
         let mut path = Cow::from(path);
         if path.is_dir() && !path.ends_with(IMPORTS_DIRECTORY_NAME) {
@@ -81,7 +81,7 @@ impl ImportsDirectory {
         let directory = fs::read_dir(&path).map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
         let mut file_paths = Vec::new();
 
-        parse_file_paths(directory, &mut file_paths)?;
+        parse_file_paths(directory, &[LEO_FILE_EXTENSION, ALEO_FILE_EXTENSION], &mut file_paths)?;
 
         Ok(file_paths)
     }
@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The file storing the mapping entries of a program in the local ledger.
+
+use crate::ledger::LEDGER_DIRECTORY_NAME;
+use leo_errors::{PackageError, Result};
+
+use indexmap::IndexMap;
+use std::{
+    borrow::Cow,
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub static MAPPINGS_FILE_EXTENSION: &str = ".json";
+
+/// The entries of each mapping of a program, as the string representations of their keys and values.
+pub type MappingEntries = IndexMap<String, IndexMap<String, String>>;
+
+pub struct MappingsFile {
+    pub program_id: String,
+}
+
+impl MappingsFile {
+    pub fn new(program_id: &str) -> Self {
+        Self { program_id: program_id.to_string() }
+    }
+
+    pub fn exists_at(&self, path: &Path) -> bool {
+        let path = self.setup_file_path(path);
+        path.exists()
+    }
+
+    /// Reads the mapping entries from the given file path.
+    /// Returns no entries if the file does not exist.
+    pub fn read_from(&self, path: &Path) -> Result<MappingEntries> {
+        let path = self.setup_file_path(path);
+        if !path.exists() {
+            return Ok(MappingEntries::new());
+        }
+
+        let string = fs::read_to_string(&path).map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
+        serde_json::from_str(&string)
+            .map_err(|err| PackageError::failed_to_parse_ledger_file(path.display(), err).into())
+    }
+
+    /// Writes the given mapping entries to a file.
+    pub fn write_to(&self, path: &Path, entries: &MappingEntries) -> Result<PathBuf> {
+        let path = self.setup_file_path(path);
+        let string = serde_json::to_string_pretty(entries)
+            .map_err(|err| PackageError::failed_to_write_ledger_file(path.display(), err))?;
+        fs::write(&path, string).map_err(|err| PackageError::failed_to_write_ledger_file(path.display(), err))?;
+        Ok(path.into_owned())
+    }
+
+    fn setup_file_path<'a>(&self, path: &'a Path) -> Cow<'a, Path> {
+        let mut path = Cow::from(path);
+        if path.is_dir() {
+            if !path.ends_with(LEDGER_DIRECTORY_NAME) {
+                path.to_mut().push(LEDGER_DIRECTORY_NAME);
+            }
+            path.to_mut().push(format!("{}{MAPPINGS_FILE_EXTENSION}", self.program_id));
+        }
+        path
+    }
+}
@@ -18,20 +18,26 @@
 #![doc = include_str!("../README.md")]
 
 pub mod build;
+pub mod dependencies;
 pub mod imports;
 pub mod inputs;
+pub mod ledger;
 pub mod outputs;
 pub mod package;
 pub mod root;
 pub mod source;
+pub mod tests;
+pub mod workspace;
 
 use leo_errors::{PackageError, Result};
 
 use std::{fs, fs::ReadDir, path::PathBuf};
 
 pub static LEO_FILE_EXTENSION: &str = ".leo";
+pub static ALEO_FILE_EXTENSION: &str = ".aleo";
 
-pub(crate) fn parse_file_paths(directory: ReadDir, file_paths: &mut Vec<PathBuf>) -> Result<()> {
+/// Collects the paths to the files in the directory and its subdirectories, which must have one of the given extensions.
+pub(crate) fn parse_file_paths(directory: ReadDir, extensions: &[&str], file_paths: &mut Vec<PathBuf>) -> Result<()> {
     for file_entry in directory {
         let file_entry = file_entry.map_err(PackageError::failed_to_get_leo_file_entry)?;
         let file_path = file_entry.path();
@@ -41,14 +47,14 @@ pub(crate) fn parse_file_paths(directory: ReadDir, file_paths: &mut Vec<PathBuf>
             let directory =
                 fs::read_dir(&file_path).map_err(|err| PackageError::failed_to_read_file(file_path.display(), err))?;
 
-            parse_file_paths(directory, file_paths)?;
+            parse_file_paths(directory, extensions, file_paths)?;
             continue;
         } else {
-            // Verify that the file has the Leo file extension
+            // Verify that the file has one of the expected extensions
             let file_extension = file_path
                 .extension()
                 .ok_or_else(|| PackageError::failed_to_get_leo_file_extension(file_path.as_os_str().to_owned()))?;
-            if file_extension != LEO_FILE_EXTENSION.trim_start_matches('.') {
+            if !extensions.iter().any(|extension| file_extension == extension.trim_start_matches('.')) {
                 return Err(PackageError::invalid_leo_file_extension(
                     file_path.as_os_str().to_owned(),
                     file_extension.to_owned(),
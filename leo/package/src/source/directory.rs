@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::parse_file_paths;
+use crate::{parse_file_paths, LEO_FILE_EXTENSION};
 
 use leo_errors::{PackageError, Result};
 
@@ -51,17 +51,20 @@ impl SourceDirectory {
         let directory = fs::read_dir(&path).map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
         let mut file_paths = Vec::new();
 
-        parse_file_paths(directory, &mut file_paths)?;
+        parse_file_paths(directory, &[LEO_FILE_EXTENSION], &mut file_paths)?;
 
         Ok(file_paths)
     }
 
-    /// Check that the files in the source directory are valid.
-    pub fn check_files(paths: &[PathBuf]) -> Result<()> {
-        match paths.len() {
-            0 => Err(PackageError::empty_source_directory().into()),
-            1 if paths[0].as_path().ends_with(MAIN_FILENAME) => Ok(()),
-            _ => Err(PackageError::source_directory_can_contain_only_one_file().into()),
+    /// Check that the files in the source directory are valid, and returns the path to the main file among them.
+    /// Note that the other files are the modules of the program.
+    pub fn check_files(paths: &[PathBuf]) -> Result<PathBuf> {
+        if paths.is_empty() {
+            return Err(PackageError::empty_source_directory().into());
+        }
+        match paths.iter().find(|path| path.ends_with(MAIN_FILENAME)) {
+            Some(path) => Ok(path.clone()),
+            None => Err(PackageError::missing_main_file().into()),
         }
     }
 }
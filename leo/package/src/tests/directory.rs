@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{parse_file_paths, LEO_FILE_EXTENSION};
+use leo_errors::{PackageError, Result};
+
+use std::{
+    borrow::Cow,
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub static TESTS_DIRECTORY_NAME: &str = "tests/";
+
+pub struct TestsDirectory;
+
+impl TestsDirectory {
+    /// Creates a directory at the provided path with the default directory name if it does not exist.
+    pub fn create(path: &Path) -> Result<PathBuf> {
+        let mut path = Cow::from(path);
+        if path.is_dir() && !path.ends_with(TESTS_DIRECTORY_NAME) {
+            path.to_mut().push(TESTS_DIRECTORY_NAME);
+        }
+
+        if !path.exists() {
+            fs::create_dir_all(&path)
+                .map_err(|err| PackageError::failed_to_create_directory(TESTS_DIRECTORY_NAME, err))?;
+        }
+
+        Ok(path.to_path_buf())
+    }
+
+    /// Returns true if the tests directory does not exist or does not contain files.
+    pub fn is_empty(path: &Path) -> Result<bool> {
+        let tests_path = path.join(Path::new(TESTS_DIRECTORY_NAME));
+        if !tests_path.exists() {
+            return Ok(true);
+        }
+
+        Ok(tests_path
+            .read_dir()
+            .map_err(|err| PackageError::failed_to_read_file(TESTS_DIRECTORY_NAME, err))?
+            .next()
+            .is_none())
+    }
+
+    /// Returns a list of files in the tests directory.
+    pub fn files(path: &Path) -> Result<Vec<PathBuf>> {
+        let mut path = Cow::from(path);
+        if path.is_dir() && !path.ends_with(TESTS_DIRECTORY_NAME) {
+            path.to_mut().push(TESTS_DIRECTORY_NAME);
+        }
+
+        let directory = fs::read_dir(&path).map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
+        let mut file_paths = Vec::new();
+
+        parse_file_paths(directory, &[LEO_FILE_EXTENSION], &mut file_paths)?;
+
+        Ok(file_paths)
+    }
+}
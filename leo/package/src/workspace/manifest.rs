@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::dependencies::{read_manifest, MANIFEST_FILENAME};
+use leo_errors::{PackageError, Result};
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub static WORKSPACE_FILENAME: &str = "workspace.json";
+
+/// The manifest of a workspace, which gathers several Leo packages of one repository.
+/// The members of a workspace import each other without being declared as dependencies,
+/// and share the `outputs/` directory at the root of the workspace.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Workspace {
+    /// The paths to the member packages, relative to the root of the workspace.
+    pub members: Vec<PathBuf>,
+}
+
+impl Workspace {
+    /// Returns the root of the workspace containing the given directory, if any.
+    /// It is the closest directory, from the given one upwards, which contains a workspace manifest.
+    pub fn find_root(path: &Path) -> Option<PathBuf> {
+        let path = fs::canonicalize(path).ok()?;
+        path.ancestors().find(|ancestor| ancestor.join(WORKSPACE_FILENAME).exists()).map(Path::to_path_buf)
+    }
+
+    /// Reads the manifest of the workspace at the given root.
+    pub fn read_from(root: &Path) -> Result<Self> {
+        let path = root.join(WORKSPACE_FILENAME);
+        let manifest =
+            fs::read_to_string(&path).map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
+        serde_json::from_str(&manifest)
+            .map_err(|err| PackageError::failed_to_parse_workspace(path.display(), err).into())
+    }
+
+    /// Returns the paths to the member packages, joined onto the root of the workspace, by program id.
+    pub fn members(&self, root: &Path) -> Result<IndexMap<String, PathBuf>> {
+        let mut members = IndexMap::new();
+        for member in self.members.iter() {
+            let location = root.join(member);
+            let manifest_path = location.join(MANIFEST_FILENAME);
+            let program = match read_manifest(&manifest_path)?.swap_remove("program") {
+                Some(Value::String(program)) => program,
+                _ => {
+                    return Err(
+                        PackageError::failed_to_parse_manifest(manifest_path.display(), "missing `program`").into()
+                    );
+                }
+            };
+
+            // Two members may not be the same program, since they are imported by name.
+            if let Some(previous) = members.insert(program.clone(), location.clone()) {
+                return Err(
+                    PackageError::duplicate_workspace_member(program, previous.display(), location.display()).into()
+                );
+            }
+        }
+        Ok(members)
+    }
+}
@@ -102,6 +102,7 @@ fn new_compiler(handler: &Handler) -> Compiler<'_> {
                 flattened_ast: false,
                 destructured_ast: false,
                 inlined_ast: false,
+                cse_ast: false,
                 dce_ast: false,
             },
         }),